@@ -28,6 +28,13 @@ pub const FAST_MODE: bool = false;
 pub const CLOSURE_START: char = '(';
 pub const CLOSURE_END: char = ')';
 
+// DECORATOR_START and DECORATOR_END defines characters that will begin/end AVX-512 decorators
+// like broadcast (`(%rax){1to16}`) or rounding (`{rn-sae}`)
+// -------------------------------------
+// default : DECORATOR_START = '{', DECORATOR_END = '}'
+pub const DECORATOR_START: char = '{';
+pub const DECORATOR_END: char = '}';
+
 // COMMENT_S or in other words: comment start
 // ------------------------------------------
 // default = ';'
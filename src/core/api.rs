@@ -32,11 +32,12 @@ pub const EXT_FLGS2: u8 = 0xE; // addt is BoolTable16
 // - [...]: reserved
 
 use crate::{
-    core::{disp, modrm, rex, sib, vex},
+    core::{disp, evex, modrm, rex, sib, vex},
     shr::{
         ast::{Instruction, Operand},
         booltable::BoolTable16,
         error::RASMError,
+        pseudo::Pseudo,
         reg::Register,
         size::Size,
     },
@@ -50,6 +51,40 @@ pub struct VexDetails {
     vlength: MegaBool,
 }
 
+#[repr(C)]
+pub struct EvexDetails {
+    pp: u8,
+    map_select: u8,
+    evex_we: bool,
+    // EVEX.L'L; if None, then it is deduced from operands
+    vlength: Option<u8>,
+    tuple: Tuple,
+}
+
+// EVEX tuple type - used to get N in compressed displacement (disp8*N)
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+pub enum Tuple {
+    // N = vector length (or element size if broadcast is used)
+    Full = 0b0000,
+    // N = vector length
+    FullMem = 0b0001,
+    // N = half of vector length (or element size if broadcast is used)
+    Half = 0b0010,
+    // N = half/quarter/eighth of vector length
+    HalfMem = 0b0011,
+    QuarterMem = 0b0100,
+    EighthMem = 0b0101,
+    // N = size of memory operand
+    Tuple1Scalar = 0b0110,
+    // N = 2/4/8 elements
+    Tuple2 = 0b0111,
+    Tuple4 = 0b1000,
+    Tuple8 = 0b1001,
+    // N = 16
+    Mem128 = 0b1010,
+}
+
 #[derive(Debug, PartialEq)]
 #[repr(transparent)]
 pub struct MegaBool {
@@ -105,6 +140,7 @@ pub struct GenAPI {
     // depending on flags:
     // - IMM_ATIDX, OBY_CONST, TBY_CONST - immediate + metadata,
     // - VEX_PFX - first byte (last 2 bits) is reserved for vlength (and is cleared during .assemble()
+    // - EVEX_PFX - first byte is 0bTTTT_XYY, where TTTT = tuple type, X = vlength is set, YY = L'L
     // otherwise unused
     addt: u16,
 }
@@ -163,10 +199,35 @@ impl GenAPI {
         self.flags.set(CAN_H66O, h66);
         self
     }
-    pub const fn evex(mut self, evex: bool) -> Self {
-        self.flags.set(EVEX_PFX, evex);
+    pub const fn evex(mut self, evex_details: EvexDetails) -> Self {
+        self.flags.set(EVEX_PFX, true);
+        self.prefix = {
+            (evex_details.evex_we as u8) << 7
+                | map_select(evex_details.map_select) << 2
+                | pp(evex_details.pp)
+        };
+        let vlength = if let Some(l) = evex_details.vlength {
+            0b100 | l & 0b11
+        } else {
+            0b000
+        };
+        let byte = (evex_details.tuple as u8) << 3 | vlength;
+        self.addt = (byte as u16) << 0x08 | self.addt & 0x00FF;
         self
     }
+    // for instructions that have both VEX and EVEX forms
+    pub const fn vex_evex(
+        self,
+        evex: bool,
+        vex_details: VexDetails,
+        evex_details: EvexDetails,
+    ) -> Self {
+        if evex {
+            self.evex(evex_details)
+        } else {
+            self.vex(vex_details)
+        }
+    }
     pub const fn rex(mut self, rex: bool) -> Self {
         self.flags.set(REX_PFX, rex);
         self
@@ -278,6 +339,9 @@ impl GenAPI {
             };
 
             if !(vex_flag_set || evex_flag_set) {
+                if let Some(pfx) = ins.addt.and_then(|a| a.prefix_byte()) {
+                    base.push(pfx);
+                }
                 if let Some(segm) = gen_segm_pref(ins) {
                     base.push(segm);
                 }
@@ -302,6 +366,8 @@ impl GenAPI {
 
             if rex_flag_set && rex != 0x00 {
                 base.push(rex);
+            } else if ins.pseudo(Pseudo::Rex) && !(vex_flag_set || evex_flag_set) {
+                base.push(0x40);
             }
             if vex_flag_set {
                 if let Some(vex) = vex::vex(ins, self) {
@@ -309,7 +375,9 @@ impl GenAPI {
                 }
             }
             if evex_flag_set {
-                panic!("EVEX prefix is not availiable!");
+                if let Some(evex) = evex::evex(ins, self) {
+                    base.extend(evex);
+                }
             }
             base
         };
@@ -317,12 +385,12 @@ impl GenAPI {
         let (opc, sz) = self.opcode.collect();
         base.extend(&opc[..sz]);
         if self.flags.get(USE_MODRM).unwrap() {
-            base.push(modrm::modrm(ins, self));
+            base.push(modrm::modrm(ins, self, bits));
 
             if let Some(sib) = sib::gen_sib_ins(ins) {
                 base.push(sib);
             }
-            if let Some(disp) = disp::gen_disp_ins(ins) {
+            if let Some(disp) = disp::gen_disp_ins(ins, disp::disp8n(ins, self), bits) {
                 base.extend(disp);
             }
         }
@@ -413,25 +481,25 @@ impl GenAPI {
         self.flags.set(STRICT_PFX, true);
         self
     }
-    // fails if VEX flag is not set
+    // fails if (E)VEX flag is not set
     pub const fn get_pp(&self) -> Option<u8> {
-        if self.flags.get(VEX_PFX).unwrap() {
+        if self.flags.get(VEX_PFX).unwrap() || self.flags.get(EVEX_PFX).unwrap() {
             Some(self.prefix & 0b11)
         } else {
             None
         }
     }
-    // fails if VEX flag is not set
+    // fails if (E)VEX flag is not set
     pub const fn get_map_select(&self) -> Option<u8> {
-        if self.flags.get(VEX_PFX).unwrap() {
+        if self.flags.get(VEX_PFX).unwrap() || self.flags.get(EVEX_PFX).unwrap() {
             Some((self.prefix & 0b0111_1100) >> 2)
         } else {
             None
         }
     }
-    // fails if VEX flag is not set
+    // fails if (E)VEX flag is not set
     pub const fn get_vex_we(&self) -> Option<bool> {
-        if self.flags.get(VEX_PFX).unwrap() {
+        if self.flags.get(VEX_PFX).unwrap() || self.flags.get(EVEX_PFX).unwrap() {
             Some(self.prefix & 0b1000_0000 == 0b1000_0000)
         } else {
             None
@@ -445,8 +513,29 @@ impl GenAPI {
             None
         }
     }
+    // fails if EVEX flag is not set or L'L was not fixed
+    pub const fn get_evex_tuple(&self) -> Option<Tuple> {
+        if !self.flags.get(EVEX_PFX).unwrap() {
+            return None;
+        }
+        let tuple = ((self.addt & 0xFF00) >> 11) as u8 & 0b1111;
+        if tuple <= Tuple::Mem128 as u8 {
+            Some(unsafe { std::mem::transmute::<u8, Tuple>(tuple) })
+        } else {
+            None
+        }
+    }
+    pub const fn get_evex_vlength(&self) -> Option<u8> {
+        let vlength = ((self.addt & 0xFF00) >> 8) as u8;
+        if self.flags.get(EVEX_PFX).unwrap() && vlength & 0b100 == 0b100 {
+            Some(vlength & 0b11)
+        } else {
+            None
+        }
+    }
 }
 
+#[allow(clippy::collapsible_match)]
 fn gen_size_ovr(ins: &Instruction, bits: u8, rexw: bool) -> Option<[Option<u8>; 2]> {
     let mut arr = [None; 2];
     if ins.dst().is_some() && ins.src().is_none() {
@@ -481,6 +570,7 @@ fn gen_size_ovr(ins: &Instruction, bits: u8, rexw: bool) -> Option<[Option<u8>;
         match (m.addrsize().unwrap_or(Size::Unknown), bits) {
             (Size::Dword, 16) => arr[1] = Some(0x67),
             (Size::Dword, 64) => arr[1] = Some(0x67),
+            (Size::Word, 32) => arr[1] = Some(0x67),
             _ => {}
         }
     }
@@ -570,6 +660,44 @@ impl VexDetails {
     }
 }
 
+impl Default for EvexDetails {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EvexDetails {
+    pub const fn new() -> Self {
+        Self {
+            pp: 0,
+            map_select: 0,
+            evex_we: false,
+            vlength: None,
+            tuple: Tuple::Full,
+        }
+    }
+    pub const fn vlength(mut self, l: Option<u8>) -> Self {
+        self.vlength = l;
+        self
+    }
+    pub const fn tuple(mut self, t: Tuple) -> Self {
+        self.tuple = t;
+        self
+    }
+    pub const fn evex_we(mut self, b: bool) -> Self {
+        self.evex_we = b;
+        self
+    }
+    pub const fn map_select(mut self, u: u8) -> Self {
+        self.map_select = u;
+        self
+    }
+    pub const fn pp(mut self, u: u8) -> Self {
+        self.pp = u;
+        self
+    }
+}
+
 impl MegaBool {
     pub const fn from_byte(b: u8) -> Self {
         Self { data: b }
@@ -688,6 +816,23 @@ impl Opcode {
     }
 }
 
+const fn pp(v: u8) -> u8 {
+    match v {
+        0x66 => 0b01,
+        0xF3 => 0b10,
+        0xF2 => 0b11,
+        _ => 0,
+    }
+}
+const fn map_select(v: u8) -> u8 {
+    match v {
+        0x0F => 0b00001,
+        0x38 => 0b00010,
+        0x3A => 0b00011,
+        _ => 0b00000,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -697,7 +842,7 @@ mod tests {
     }
     #[test]
     fn mbool() {
-        use crate::shr::num::Number;
+        use crate::shr::{booltable::BoolTable8, num::Number};
         use OpOrd::*;
         let mb = MegaBool::from_byte(3);
         assert_eq!(mb.get(), Some(true));
@@ -711,7 +856,7 @@ mod tests {
                 .vlength(Some(true)),
         );
         assert_eq!(api.get_vex_vlength(), Some(MegaBool::set(Some(true))));
-        assert_eq!(api.get_vex_vlength().unwrap().get().unwrap_or(false), true);
+        assert!(api.get_vex_vlength().unwrap().get().unwrap_or(false));
         let api = GenAPI::new()
             .opcode(&[0x19])
             .modrm(true, None, None)
@@ -725,7 +870,7 @@ mod tests {
             )
             .ord(&[MODRM_RM, MODRM_REG]);
         assert_eq!(api.addt, 0b0000_0011_0001_0010);
-        assert_eq!(api.get_vex_vlength().unwrap().get().unwrap_or(false), true);
+        assert!(api.get_vex_vlength().unwrap().get().unwrap_or(false));
         let ins = Instruction {
             mnem: crate::Mnemonic::CMP,
             addt: None,
@@ -737,6 +882,11 @@ mod tests {
                 None,
             ],
             line: 0,
+            mask: None,
+            rounding: None,
+            symbol: None,
+            pseudo: BoolTable8::new(),
+            cpu: None,
         };
         assert_eq!(ins.size(), Size::Word);
         assert_eq!(gen_size_ovr(&ins, 64, false), Some([Some(0x66), None]));
@@ -762,20 +912,3 @@ mod tests {
         assert!(ord.deserialize()[0..3] == [MODRM_RM, MODRM_REG, VEX_VVVV]);
     }
 }
-
-const fn pp(v: u8) -> u8 {
-    match v {
-        0x66 => 0b01,
-        0xF3 => 0b10,
-        0xF2 => 0b11,
-        _ => 0,
-    }
-}
-const fn map_select(v: u8) -> u8 {
-    match v {
-        0x0F => 0b00001,
-        0x38 => 0b00010,
-        0x3A => 0b00011,
-        _ => 0b00000,
-    }
-}
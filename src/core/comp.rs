@@ -4,15 +4,17 @@
 // licensed under MPL 2.0
 
 use crate::{
-    core::api::*,
+    core::{api::*, disp},
     shr::{
         ast::{IVariant, Instruction, Label, Operand},
+        error::RASMError,
         ins::Mnemonic as Ins,
         num::Number,
+        pseudo::Pseudo,
         reg::{Purpose as RPurpose, Register},
         reloc::RelType,
         reloc::Relocation,
-        segment::Segment,
+        segment::{FarPtr, Segment},
         size::Size,
         symbol::{Symbol, SymbolType, Visibility},
     },
@@ -32,7 +34,7 @@ pub fn make_globals(symbols: &mut [Symbol], globals: &[String]) {
     }
 }
 #[inline]
-pub fn extern_trf(externs: &Vec<String>) -> Vec<Symbol> {
+pub fn extern_trf(externs: &Vec<String>) -> Vec<Symbol<'_>> {
     let mut symbols = Vec::new();
     for extern_ in externs {
         symbols.push(Symbol {
@@ -48,34 +50,483 @@ pub fn extern_trf(externs: &Vec<String>) -> Vec<Symbol> {
     symbols
 }
 
-pub fn compile_label(lbl: &Label, offset: usize) -> (Vec<u8>, Vec<Relocation>) {
+// short (rel8) branch, which target is (or might be) known at assembly time
+struct ShortBranch {
+    lbl: usize,
+    ins: usize,
+    // index of target label (if it is in the same section)
+    target: Option<usize>,
+    addend: i32,
+    // can be changed into near (rel32) form
+    relaxable: bool,
+    short: bool,
+}
+
+// bytes, relocations and (offset, size) of every label in section
+pub type CompiledSection<'a> = (Vec<u8>, Vec<Relocation<'a>>, Vec<(u32, u32)>);
+
+// compiles every label in section, choosing shortest form of branches;
+// label offsets are relative to section. With `opt` (-O) instructions with
+// immediates also use their shortest encoding
+pub fn compile_section(
+    labels: &[Label],
+    offset: usize,
+    exec: bool,
+    opt: bool,
+) -> Result<CompiledSection<'_>, RASMError> {
+    let shorts = relax_section(labels, offset, opt)?;
+
     let mut bytes = Vec::new();
     let mut reallocs = Vec::new();
-    let lbl_bits = lbl.bits;
-    let lbl_align = lbl.align;
-    for ins in &lbl.inst {
-        let res = compile_instruction(ins, lbl_bits);
-        // we do not want situation, where label is entry and we place padding before it -
-        // preventing UB
-        if offset != 0 && lbl_align != 0 {
-            let align = lbl_align as usize;
-            let mut padding = align - (offset % align);
-            while padding > 0 {
-                bytes.push(0x0);
-                padding -= 1;
-            }
-        }
-
-        if let Some(mut rl) = res.1 {
-            rl.offset += bytes.len() as u32;
-            reallocs.push(rl);
-        }
-        bytes.extend(res.0);
+    let mut layout = Vec::new();
+    // relative references to labels in the same section are resolved by us
+    let mut local = Vec::new();
+    for (lbl, shorts) in labels.iter().zip(&shorts) {
+        let padding = lbl_padding(lbl, offset + bytes.len());
+        bytes.extend(lbl_fill(lbl, padding, exec));
+        let start = bytes.len();
+        for (ins, short) in lbl.inst.iter().zip(shorts) {
+            let res = if *short {
+                ins_shrtjmp(ins, lbl.bits)
+            } else {
+                compile_instruction(ins, lbl.bits, opt)
+            };
+            if let Some(mut rl) = res.1 {
+                rl.offset += bytes.len() as u32;
+                let target = labels.iter().position(|l| &l.name == rl.symbol);
+                match (rl.reltype, target) {
+                    (RelType::REL32 | RelType::REL8, Some(t)) => local.push((rl, t)),
+                    _ => reallocs.push(rl),
+                }
+            }
+            bytes.extend(res.0);
+        }
+        layout.push((start as u32, (bytes.len() - start) as u32));
+    }
+
+    for (rl, target) in local {
+        let disp = rl.lea(layout[target].0) as i32;
+        let offset = rl.offset as usize;
+        let size = rl.reltype.size();
+        bytes[offset..offset + size].copy_from_slice(&disp.to_le_bytes()[..size]);
+    }
+    Ok((bytes, reallocs, layout))
+}
+
+// we do not want situation, where label is entry and we place padding before it -
+// preventing UB
+fn lbl_padding(lbl: &Label, offset: usize) -> usize {
+    if offset == 0 || lbl.align == 0 {
+        return 0;
+    }
+    let align = lbl.align as usize;
+    (align - (offset % align)) % align
+}
+
+// padding in executable sections is made of NOPs (code can fall through it),
+// in other sections of zeros; label can also use custom fill byte
+fn lbl_fill(lbl: &Label, padding: usize, exec: bool) -> Vec<u8> {
+    match (lbl.fill, exec) {
+        (Some(b), _) => vec![b; padding],
+        (None, true) => nops(padding, lbl.bits),
+        (None, false) => vec![0x00; padding],
+    }
+}
+
+// recommended multi-byte NOP sequences (66 90 and 0F 1F /0)
+const NOPS: [&[u8]; 9] = [
+    &[0x90],
+    &[0x66, 0x90],
+    &[0x0F, 0x1F, 0x00],
+    &[0x0F, 0x1F, 0x40, 0x00],
+    &[0x0F, 0x1F, 0x44, 0x00, 0x00],
+    &[0x66, 0x0F, 0x1F, 0x44, 0x00, 0x00],
+    &[0x0F, 0x1F, 0x80, 0x00, 0x00, 0x00, 0x00],
+    &[0x0F, 0x1F, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00],
+    &[0x66, 0x0F, 0x1F, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00],
+];
+
+// 16-bit ModRM has other lengths (and 0F 1F isn't supported by older CPUs),
+// so in 16-bit mode only single-byte NOPs are used
+fn nops(len: usize, bits: u8) -> Vec<u8> {
+    if bits == 16 {
+        return vec![0x90; len];
+    }
+    let mut bytes = Vec::with_capacity(len);
+    let mut left = len;
+    while left != 0 {
+        let n = left.min(NOPS.len());
+        bytes.extend(NOPS[n - 1]);
+        left -= n;
+    }
+    bytes
+}
+
+// decides which branches can use short (rel8) form: every branch to label in
+// the same section starts as short and is grown into near (rel32) form
+// until all of them are in range.
+fn relax_section(labels: &[Label], offset: usize, opt: bool) -> Result<Vec<Vec<bool>>, RASMError> {
+    let mut sizes: Vec<Vec<usize>> = Vec::with_capacity(labels.len());
+    let mut branches: Vec<ShortBranch> = Vec::new();
+    for (lidx, lbl) in labels.iter().enumerate() {
+        let mut lsizes = Vec::with_capacity(lbl.inst.len());
+        for (iidx, ins) in lbl.inst.iter().enumerate() {
+            lsizes.push(compile_instruction(ins, lbl.bits, opt).0.len());
+            if ins.mnem.rel8_opcode().is_none() {
+                continue;
+            }
+            let (name, addend, relaxable) = match ins.dst() {
+                Some(Operand::SymbolRef(s)) => (s, 0, !ins.mnem.is_rel8_only()),
+                Some(Operand::SymbolRefExt(s)) if s.reltype == RelType::REL8 => {
+                    (&s.symbol, s.addend, false)
+                }
+                _ => continue,
+            };
+            let target = labels.iter().position(|l| &l.name == name);
+            // near branch to symbol outside of section stays as it is
+            if target.is_none() && relaxable {
+                continue;
+            }
+            branches.push(ShortBranch {
+                lbl: lidx,
+                ins: iidx,
+                target,
+                addend,
+                relaxable,
+                short: true,
+            });
+        }
+        sizes.push(lsizes);
+    }
+    for b in &branches {
+        sizes[b.lbl][b.ins] = ins_shrtjmp(&labels[b.lbl].inst[b.ins], labels[b.lbl].bits)
+            .0
+            .len();
+    }
+
+    loop {
+        let mut lbl_offs = Vec::with_capacity(labels.len());
+        let mut ins_offs: Vec<Vec<usize>> = Vec::with_capacity(labels.len());
+        let mut off = offset;
+        for (lbl, lsizes) in labels.iter().zip(&sizes) {
+            off += lbl_padding(lbl, off);
+            lbl_offs.push(off);
+            let mut offs = Vec::with_capacity(lsizes.len());
+            for s in lsizes {
+                offs.push(off);
+                off += s;
+            }
+            ins_offs.push(offs);
+        }
+
+        let mut changed = false;
+        for b in &mut branches {
+            let target = if let (true, Some(t)) = (b.short, b.target) {
+                t
+            } else {
+                continue;
+            };
+            let disp = lbl_offs[target] as i64 + b.addend as i64
+                - (ins_offs[b.lbl][b.ins] + sizes[b.lbl][b.ins]) as i64;
+            if (i8::MIN as i64..=i8::MAX as i64).contains(&disp) {
+                continue;
+            }
+            let ins = &labels[b.lbl].inst[b.ins];
+            if !b.relaxable {
+                return Err(RASMError::with_tip(
+                    Some(ins.line),
+                    Some(format!(
+                        "Short jump to label `{}` is out of range ({disp} bytes)",
+                        labels[target].name
+                    )),
+                    Some(
+                        "Short (rel8) jump can only reach targets in range from -128 to 127 bytes",
+                    ),
+                ));
+            }
+            b.short = false;
+            sizes[b.lbl][b.ins] = compile_instruction(ins, labels[b.lbl].bits, opt).0.len();
+            changed = true;
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    let mut shorts: Vec<Vec<bool>> = labels.iter().map(|l| vec![false; l.inst.len()]).collect();
+    for b in branches {
+        shorts[b.lbl][b.ins] = b.short;
+    }
+    Ok(shorts)
+}
+
+pub fn compile_instruction(
+    ins: &'_ Instruction,
+    bits: u8,
+    opt: bool,
+) -> (Vec<u8>, Option<Relocation<'_>>) {
+    let (mut bytes, reloc) = match ins_direction(ins, bits).or_else(|| ins_shortest(ins, bits, opt))
+    {
+        Some(bytes) => (bytes, None),
+        None => compile_ins(ins, bits),
+    };
+    // instruction can also make relocation for memory symbol by itself (movabs)
+    if let (Some(symbol), None) = (&ins.symbol, &reloc) {
+        let mem = ins.get_mem().expect("symbol without memory operand");
+        let offset = disp::disp_idx(&bytes, bits).expect("memory operand without displacement");
+        let addend = symbol.addend + mem.offset().unwrap_or(0);
+        // RIP-relative disp is relative to the end of instruction, not to the disp itself;
+        // 16-bit addressing uses absolute disp16 and other memory absolute disp32
+        // (sign-extended in long mode)
+        let (reltype, addend) = if bits == 64 && mem.base() == Some(Register::RIP) {
+            (RelType::REL32, addend - (bytes.len() - offset) as i32)
+        } else if mem.is_addr16(bits) {
+            (RelType::ABS16, addend)
+        } else {
+            (RelType::ABS32, addend)
+        };
+        bytes[offset..offset + reltype.size()].fill(0);
+        return (
+            bytes,
+            Some(Relocation {
+                reltype,
+                symbol: &symbol.symbol,
+                offset: offset as u32,
+                addend,
+                shidx: 0,
+            }),
+        );
+    }
+    (bytes, reloc)
+}
+
+// reg-reg moves with both load (ModRM.reg = destination) and store (ModRM.rm = destination)
+// opcode: (mnemonic, prefix/pp, load opcode, store opcode, EVEX.W if EVEX form exists)
+#[rustfmt::skip]
+const DIRECTIONS: [(Ins, u8, u8, u8, Option<bool>); 16] = [
+    (Ins::MOVAPS   , 0x00, 0x28, 0x29, None)       , (Ins::MOVUPS   , 0x00, 0x10, 0x11, None),
+    (Ins::MOVAPD   , 0x66, 0x28, 0x29, None)       , (Ins::MOVUPD   , 0x66, 0x10, 0x11, None),
+    (Ins::MOVSS    , 0xF3, 0x10, 0x11, None)       , (Ins::MOVSD    , 0xF2, 0x10, 0x11, None),
+    (Ins::MOVDQA   , 0x66, 0x6F, 0x7F, None)       , (Ins::VMOVDQA  , 0x66, 0x6F, 0x7F, None),
+    (Ins::VMOVAPS  , 0x00, 0x28, 0x29, Some(false)), (Ins::VMOVUPS  , 0x00, 0x10, 0x11, Some(false)),
+    (Ins::VMOVAPD  , 0x66, 0x28, 0x29, Some(true)) , (Ins::VMOVUPD  , 0x66, 0x10, 0x11, Some(true)),
+    (Ins::VMOVDQA32, 0x66, 0x6F, 0x7F, Some(false)), (Ins::VMOVDQA64, 0x66, 0x6F, 0x7F, Some(true)),
+    (Ins::VMOVDQU32, 0xF3, 0x6F, 0x7F, Some(false)), (Ins::VMOVDQU64, 0xF3, 0x6F, 0x7F, Some(true)),
+];
+
+// `{load}`/`{store}`: opcode direction of reg-reg move. None means that default
+// encoding is used
+fn ins_direction(ins: &Instruction, bits: u8) -> Option<Vec<u8>> {
+    let load = match (ins.pseudo(Pseudo::Load), ins.pseudo(Pseudo::Store)) {
+        (true, false) => true,
+        (false, true) => false,
+        _ => return None,
+    };
+    let size = match (ins.dst()?, ins.src()?) {
+        (Operand::Reg(d), Operand::Reg(_)) => d.size(),
+        _ => return None,
+    };
+    let api = if let Some(&(_, pfx, ld, st, evex_w)) = DIRECTIONS.iter().find(|d| d.0 == ins.mnem) {
+        let opc = if load { ld } else { st };
+        // VMOVDQA32/64 and VMOVDQU32/64 only have EVEX form
+        let evex_only = matches!(
+            ins.mnem,
+            Ins::VMOVDQA32 | Ins::VMOVDQA64 | Ins::VMOVDQU32 | Ins::VMOVDQU64
+        );
+        if !ins.mnem.is_avx() {
+            GenAPI::new().opcode(&[0x0F, opc]).prefix(pfx).rex(true)
+        } else if !ins.needs_evex() && !evex_only {
+            GenAPI::new()
+                .opcode(&[opc])
+                .vex(VexDetails::new().pp(pfx).map_select(0x0F).vex_we(false))
+        } else {
+            GenAPI::new()
+                .opcode(&[opc])
+                .evex(EvexDetails::new().pp(pfx).map_select(0x0F).evex_we(evex_w?))
+        }
+    } else {
+        let base = match ins.mnem {
+            Ins::MOV => 0x88,
+            m => alu_ext(m)? << 3,
+        };
+        let opc = base | (load as u8) << 1 | (size != Size::Byte) as u8;
+        GenAPI::new().opcode(&[opc]).rex(true)
+    };
+    let api = api.modrm(true, None, None);
+    Some(if load {
+        api.ord(&[MODRM_REG, MODRM_RM]).assemble(ins, bits)
+    } else if size == Size::Xword && !ins.mnem.is_avx() {
+        // REX assumes that XMM destination is in ModRM.reg, so store form
+        // is made by swapping operands of the load one
+        let mut swapped = ins.clone();
+        swapped.oprs.swap(0, 1);
+        api.ord(&[MODRM_REG, MODRM_RM]).assemble(&swapped, bits)
+    } else {
+        api.assemble(ins, bits)
+    })
+}
+
+// -O: shortest encoding of instruction with immediate, that has the same effect
+// as the default one. None means that default encoding is used
+fn ins_shortest(ins: &Instruction, bits: u8, opt: bool) -> Option<Vec<u8>> {
+    if !opt || ins.oprs[2].is_some() {
+        return None;
+    }
+    let (dst, n) = match (ins.dst()?, ins.src()?) {
+        (d @ (Operand::Reg(_) | Operand::Mem(_) | Operand::Segment(_)), Operand::Imm(n)) => (d, n),
+        _ => return None,
+    };
+    let size = dst.size();
+    if !matches!(size, Size::Word | Size::Dword | Size::Qword) {
+        return None;
     }
-    (bytes, reallocs)
+    let val = imm_value(n, size)?;
+    match ins.mnem {
+        Ins::TEST => opt_test(ins, dst, val, bits),
+        _ => opt_alu(ins, alu_ext(ins.mnem)?, dst, val, bits),
+    }
+}
+
+// add, or, adc, sbb, and, sub, xor, cmp: 83 /n ib if immediate is sign-extended
+// byte, else accumulator form (no ModRM) or 81 /n iw/id
+fn opt_alu(ins: &Instruction, ext: u8, dst: &Operand, val: u64, bits: u8) -> Option<Vec<u8>> {
+    let mut dst = dst.clone();
+    // and with positive imm32 zeroes upper half of r64 just like and r32 does
+    if let (4, Operand::Reg(r)) = (ext, &dst) {
+        if r.size() == Size::Qword && val < 0x8000_0000 {
+            dst = Operand::Reg(gpr_resize(*r, Size::Dword)?);
+        }
+    }
+    let size = dst.size();
+    let imm = if fits_simm(val, 8, size) {
+        1
+    } else if !fits_simm(val, 32, size) {
+        return None;
+    } else if size == Size::Word {
+        2
+    } else {
+        4
+    };
+    let acc = matches!(
+        dst,
+        Operand::Reg(Register::AX | Register::EAX | Register::RAX)
+    );
+    let ins = with_operands(ins, dst, val);
+    let api = if imm == 1 {
+        GenAPI::new().opcode(&[0x83]).modrm(true, Some(ext), None)
+    } else if acc {
+        GenAPI::new().opcode(&[ext << 3 | 0x05])
+    } else {
+        GenAPI::new().opcode(&[0x81]).modrm(true, Some(ext), None)
+    };
+    Some(api.rex(true).imm_atindex(1, imm).assemble(&ins, bits))
+}
+
+// flags of test with register depend only on bits set in immediate, so it can use
+// the smallest register that holds all of them (sign bit included)
+fn opt_test(ins: &Instruction, dst: &Operand, val: u64, bits: u8) -> Option<Vec<u8>> {
+    let r = match dst {
+        Operand::Reg(r) => *r,
+        _ => return None,
+    };
+    let byte = gpr_resize(r, Size::Byte).filter(|r8| bits == 64 || !r8.needs_rex());
+    let (r, imm) = match byte {
+        Some(r8) if val < 0x80 => (r8, 1),
+        _ if r.size() == Size::Qword && val < 0x8000_0000 => (gpr_resize(r, Size::Dword)?, 4),
+        _ if !fits_simm(val, 32, r.size()) => return None,
+        _ if r.size() == Size::Word => (r, 2),
+        _ => (r, 4),
+    };
+    let ins = with_operands(ins, Operand::Reg(r), val);
+    let api = match (r.to_byte(), r.needs_rex(), imm) {
+        (0, false, 1) => GenAPI::new().opcode(&[0xA8]),
+        (0, false, _) => GenAPI::new().opcode(&[0xA9]),
+        (_, _, 1) => GenAPI::new().opcode(&[0xF6]).modrm(true, Some(0), None),
+        _ => GenAPI::new().opcode(&[0xF7]).modrm(true, Some(0), None),
+    };
+    Some(api.rex(true).imm_atindex(1, imm).assemble(&ins, bits))
+}
+
+const fn alu_ext(mnem: Ins) -> Option<u8> {
+    match mnem {
+        Ins::ADD => Some(0),
+        Ins::OR => Some(1),
+        Ins::ADC => Some(2),
+        Ins::SBB => Some(3),
+        Ins::AND => Some(4),
+        Ins::SUB => Some(5),
+        Ins::XOR => Some(6),
+        Ins::CMP => Some(7),
+        _ => None,
+    }
+}
+
+fn with_operands(ins: &Instruction, dst: Operand, val: u64) -> Instruction {
+    let mut ins = ins.clone();
+    ins.oprs[0] = Some(dst);
+    ins.oprs[1] = Some(Operand::Imm(Number::uint64(val)));
+    ins
+}
+
+// value of immediate truncated to operand size (None if it doesn't fit)
+fn imm_value(n: &Number, size: Size) -> Option<u64> {
+    if n.is_float() || n.is_double() {
+        return None;
+    }
+    let (val, width) = (n.get_raw(), u8::from(size) as u32 * 8);
+    if width == 64 {
+        Some(val)
+    } else if val >> width == 0 || sext(val, width) == val {
+        Some(val & ((1 << width) - 1))
+    } else {
+        None
+    }
+}
+
+// can value of operand be encoded as sign-extended immediate of `from` bits
+fn fits_simm(val: u64, from: u32, size: Size) -> bool {
+    let width = u8::from(size) as u32 * 8;
+    sext(val, from) & (u64::MAX >> (64 - width)) == val
+}
+
+fn sext(val: u64, from: u32) -> u64 {
+    let shift = 64 - from;
+    (((val << shift) as i64) >> shift) as u64
+}
+
+#[rustfmt::skip]
+const GPRS: [[Register; 4]; 16] = [
+    [Register::AL  , Register::AX  , Register::EAX , Register::RAX],
+    [Register::CL  , Register::CX  , Register::ECX , Register::RCX],
+    [Register::DL  , Register::DX  , Register::EDX , Register::RDX],
+    [Register::BL  , Register::BX  , Register::EBX , Register::RBX],
+    [Register::SPL , Register::SP  , Register::ESP , Register::RSP],
+    [Register::BPL , Register::BP  , Register::EBP , Register::RBP],
+    [Register::SIL , Register::SI  , Register::ESI , Register::RSI],
+    [Register::DIL , Register::DI  , Register::EDI , Register::RDI],
+    [Register::R8B , Register::R8W , Register::R8D , Register::R8 ],
+    [Register::R9B , Register::R9W , Register::R9D , Register::R9 ],
+    [Register::R10B, Register::R10W, Register::R10D, Register::R10],
+    [Register::R11B, Register::R11W, Register::R11D, Register::R11],
+    [Register::R12B, Register::R12W, Register::R12D, Register::R12],
+    [Register::R13B, Register::R13W, Register::R13D, Register::R13],
+    [Register::R14B, Register::R14W, Register::R14D, Register::R14],
+    [Register::R15B, Register::R15W, Register::R15D, Register::R15],
+];
+
+// same general purpose register with other size
+fn gpr_resize(r: Register, size: Size) -> Option<Register> {
+    let col = match size {
+        Size::Byte => 0,
+        Size::Word => 1,
+        Size::Dword => 2,
+        Size::Qword => 3,
+        _ => return None,
+    };
+    GPRS.iter().find(|g| g.contains(&r)).map(|g| g[col])
 }
 
-pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<Relocation<'_>>) {
+fn compile_ins(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<Relocation<'_>>) {
     match ins.mnem {
         Ins::BYTE | Ins::BYTELE | Ins::BYTEBE => (
             GenAPI::new()
@@ -122,6 +573,7 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
         Ins::PUSH => (ins_push(ins, bits), None),
         Ins::POP => (ins_pop(ins, bits), None),
         Ins::MOV => (ins_mov(ins, bits), None),
+        Ins::MOVABS => ins_movabs(ins, bits),
         Ins::ADD => (
             add_like_ins(
                 ins,
@@ -214,7 +666,7 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
         Ins::JNS => ins_jmplike(ins, [vec![0x0F, 0x89], vec![]], 0, bits),
         Ins::JPE => ins_jmplike(ins, [vec![0x0F, 0x8A], vec![]], 0, bits),
         Ins::JPO => ins_jmplike(ins, [vec![0x0F, 0x8B], vec![]], 0, bits),
-        Ins::JNE | Ins::JNZ => ins_jmplike(ins, [vec![0xFF, 0x85], vec![]], 0, bits),
+        Ins::JNE | Ins::JNZ => ins_jmplike(ins, [vec![0x0F, 0x85], vec![]], 0, bits),
         Ins::JLE => ins_jmplike(ins, [vec![0x0F, 0x8E], vec![]], 0, bits),
         Ins::JGE => ins_jmplike(ins, [vec![0x0F, 0x8D], vec![]], 0, bits),
         Ins::JNAE => ins_jmplike(ins, [vec![0x0F, 0x82], vec![]], 0, bits),
@@ -2367,9 +2819,11 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
             None,
         ),
         Ins::VMOVAPS => {
-            let mut api = GenAPI::new()
-                .modrm(true, None, None)
-                .vex(VexDetails::new().pp(0).map_select(0x0F).vex_we(false));
+            let mut api = GenAPI::new().modrm(true, None, None).vex_evex(
+                ins.needs_evex(),
+                VexDetails::new().pp(0).map_select(0x0F).vex_we(false),
+                EvexDetails::new().pp(0).map_select(0x0F).evex_we(false),
+            );
             if ins.dst().unwrap().is_mem() {
                 api = api.opcode(&[0x29]);
             } else {
@@ -2378,9 +2832,11 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
             (api.assemble(ins, bits), None)
         }
         Ins::VMOVAPD => {
-            let mut api = GenAPI::new()
-                .modrm(true, None, None)
-                .vex(VexDetails::new().pp(0x66).map_select(0x0F).vex_we(false));
+            let mut api = GenAPI::new().modrm(true, None, None).vex_evex(
+                ins.needs_evex(),
+                VexDetails::new().pp(0x66).map_select(0x0F).vex_we(false),
+                EvexDetails::new().pp(0x66).map_select(0x0F).evex_we(true),
+            );
             if ins.dst().unwrap().is_mem() {
                 api = api.opcode(&[0x29]);
             } else {
@@ -2389,9 +2845,11 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
             (api.assemble(ins, bits), None)
         }
         Ins::VMOVUPS => {
-            let mut api = GenAPI::new()
-                .modrm(true, None, None)
-                .vex(VexDetails::new().pp(0).map_select(0x0F).vex_we(false));
+            let mut api = GenAPI::new().modrm(true, None, None).vex_evex(
+                ins.needs_evex(),
+                VexDetails::new().pp(0).map_select(0x0F).vex_we(false),
+                EvexDetails::new().pp(0).map_select(0x0F).evex_we(false),
+            );
             if ins.dst().unwrap().is_mem() {
                 api = api.opcode(&[0x11]);
             } else {
@@ -2400,9 +2858,11 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
             (api.assemble(ins, bits), None)
         }
         Ins::VMOVUPD => {
-            let mut api = GenAPI::new()
-                .modrm(true, None, None)
-                .vex(VexDetails::new().pp(0x66).map_select(0x0F).vex_we(false));
+            let mut api = GenAPI::new().modrm(true, None, None).vex_evex(
+                ins.needs_evex(),
+                VexDetails::new().pp(0x66).map_select(0x0F).vex_we(false),
+                EvexDetails::new().pp(0x66).map_select(0x0F).evex_we(true),
+            );
             if ins.dst().unwrap().is_mem() {
                 api = api.opcode(&[0x11]);
             } else {
@@ -2413,7 +2873,11 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
         Ins::VADDPS => (
             GenAPI::new()
                 .opcode(&[0x58])
-                .vex(VexDetails::new().map_select(0x0F).pp(0).vex_we(false))
+                .vex_evex(
+                    ins.needs_evex(),
+                    VexDetails::new().map_select(0x0F).pp(0).vex_we(false),
+                    EvexDetails::new().map_select(0x0F).pp(0).evex_we(false),
+                )
                 .modrm(true, None, None)
                 .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
                 .assemble(ins, bits),
@@ -2476,7 +2940,11 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
         Ins::VADDPD => (
             GenAPI::new()
                 .opcode(&[0x58])
-                .vex(VexDetails::new().map_select(0x0F).pp(0x66).vex_we(false))
+                .vex_evex(
+                    ins.needs_evex(),
+                    VexDetails::new().map_select(0x0F).pp(0x66).vex_we(false),
+                    EvexDetails::new().map_select(0x0F).pp(0x66).evex_we(true),
+                )
                 .modrm(true, None, None)
                 .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
                 .assemble(ins, bits),
@@ -2503,7 +2971,11 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
         Ins::VSUBPS => (
             GenAPI::new()
                 .opcode(&[0x5C])
-                .vex(VexDetails::new().map_select(0x0F).pp(0).vex_we(false))
+                .vex_evex(
+                    ins.needs_evex(),
+                    VexDetails::new().map_select(0x0F).pp(0).vex_we(false),
+                    EvexDetails::new().map_select(0x0F).pp(0).evex_we(false),
+                )
                 .modrm(true, None, None)
                 .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
                 .assemble(ins, bits),
@@ -2512,7 +2984,11 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
         Ins::VSUBPD => (
             GenAPI::new()
                 .opcode(&[0x5C])
-                .vex(VexDetails::new().map_select(0x0F).pp(0x66).vex_we(false))
+                .vex_evex(
+                    ins.needs_evex(),
+                    VexDetails::new().map_select(0x0F).pp(0x66).vex_we(false),
+                    EvexDetails::new().map_select(0x0F).pp(0x66).evex_we(true),
+                )
                 .modrm(true, None, None)
                 .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
                 .assemble(ins, bits),
@@ -2540,7 +3016,11 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
         Ins::VMULPS => (
             GenAPI::new()
                 .opcode(&[0x59])
-                .vex(VexDetails::new().map_select(0x0F).pp(0).vex_we(false))
+                .vex_evex(
+                    ins.needs_evex(),
+                    VexDetails::new().map_select(0x0F).pp(0).vex_we(false),
+                    EvexDetails::new().map_select(0x0F).pp(0).evex_we(false),
+                )
                 .modrm(true, None, None)
                 .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
                 .assemble(ins, bits),
@@ -2549,7 +3029,11 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
         Ins::VMULPD => (
             GenAPI::new()
                 .opcode(&[0x59])
-                .vex(VexDetails::new().map_select(0x0F).pp(0x66).vex_we(false))
+                .vex_evex(
+                    ins.needs_evex(),
+                    VexDetails::new().map_select(0x0F).pp(0x66).vex_we(false),
+                    EvexDetails::new().map_select(0x0F).pp(0x66).evex_we(true),
+                )
                 .modrm(true, None, None)
                 .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
                 .assemble(ins, bits),
@@ -2576,7 +3060,11 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
         Ins::VDIVPS => (
             GenAPI::new()
                 .opcode(&[0x5E])
-                .vex(VexDetails::new().map_select(0x0F).pp(0).vex_we(false))
+                .vex_evex(
+                    ins.needs_evex(),
+                    VexDetails::new().map_select(0x0F).pp(0).vex_we(false),
+                    EvexDetails::new().map_select(0x0F).pp(0).evex_we(false),
+                )
                 .modrm(true, None, None)
                 .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
                 .assemble(ins, bits),
@@ -2585,7 +3073,11 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
         Ins::VDIVPD => (
             GenAPI::new()
                 .opcode(&[0x5E])
-                .vex(VexDetails::new().map_select(0x0F).pp(0x66).vex_we(false))
+                .vex_evex(
+                    ins.needs_evex(),
+                    VexDetails::new().map_select(0x0F).pp(0x66).vex_we(false),
+                    EvexDetails::new().map_select(0x0F).pp(0x66).evex_we(true),
+                )
                 .modrm(true, None, None)
                 .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
                 .assemble(ins, bits),
@@ -2632,7 +3124,11 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
         Ins::VSQRTPS => (
             GenAPI::new()
                 .opcode(&[0x51])
-                .vex(VexDetails::new().map_select(0x0F).pp(0).vex_we(false))
+                .vex_evex(
+                    ins.needs_evex(),
+                    VexDetails::new().map_select(0x0F).pp(0).vex_we(false),
+                    EvexDetails::new().map_select(0x0F).pp(0).evex_we(false),
+                )
                 .modrm(true, None, None)
                 .ord(&[MODRM_REG, MODRM_RM])
                 .assemble(ins, bits),
@@ -2641,7 +3137,11 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
         Ins::VSQRTPD => (
             GenAPI::new()
                 .opcode(&[0x51])
-                .vex(VexDetails::new().map_select(0x0F).pp(0x66).vex_we(false))
+                .vex_evex(
+                    ins.needs_evex(),
+                    VexDetails::new().map_select(0x0F).pp(0x66).vex_we(false),
+                    EvexDetails::new().map_select(0x0F).pp(0x66).evex_we(true),
+                )
                 .modrm(true, None, None)
                 .ord(&[MODRM_REG, MODRM_RM])
                 .assemble(ins, bits),
@@ -2695,7 +3195,11 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
         Ins::VPMULLD => (
             GenAPI::new()
                 .opcode(&[0x40])
-                .vex(VexDetails::new().map_select(0x38).pp(0x66).vex_we(false))
+                .vex_evex(
+                    ins.needs_evex(),
+                    VexDetails::new().map_select(0x38).pp(0x66).vex_we(false),
+                    EvexDetails::new().map_select(0x38).pp(0x66).evex_we(false),
+                )
                 .modrm(true, None, None)
                 .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
                 .assemble(ins, bits),
@@ -2777,7 +3281,11 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
         Ins::VMINPS => (
             GenAPI::new()
                 .opcode(&[0x5D])
-                .vex(VexDetails::new().map_select(0x0F).pp(0).vex_we(false))
+                .vex_evex(
+                    ins.needs_evex(),
+                    VexDetails::new().map_select(0x0F).pp(0).vex_we(false),
+                    EvexDetails::new().map_select(0x0F).pp(0).evex_we(false),
+                )
                 .modrm(true, None, None)
                 .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
                 .assemble(ins, bits),
@@ -2786,7 +3294,11 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
         Ins::VMINPD => (
             GenAPI::new()
                 .opcode(&[0x5D])
-                .vex(VexDetails::new().map_select(0x0F).pp(0x66).vex_we(false))
+                .vex_evex(
+                    ins.needs_evex(),
+                    VexDetails::new().map_select(0x0F).pp(0x66).vex_we(false),
+                    EvexDetails::new().map_select(0x0F).pp(0x66).evex_we(true),
+                )
                 .modrm(true, None, None)
                 .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
                 .assemble(ins, bits),
@@ -2813,7 +3325,11 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
         Ins::VMAXPS => (
             GenAPI::new()
                 .opcode(&[0x5F])
-                .vex(VexDetails::new().map_select(0x0F).pp(0).vex_we(false))
+                .vex_evex(
+                    ins.needs_evex(),
+                    VexDetails::new().map_select(0x0F).pp(0).vex_we(false),
+                    EvexDetails::new().map_select(0x0F).pp(0).evex_we(false),
+                )
                 .modrm(true, None, None)
                 .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
                 .assemble(ins, bits),
@@ -2822,7 +3338,11 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
         Ins::VMAXPD => (
             GenAPI::new()
                 .opcode(&[0x5F])
-                .vex(VexDetails::new().map_select(0x0F).pp(0x66).vex_we(false))
+                .vex_evex(
+                    ins.needs_evex(),
+                    VexDetails::new().map_select(0x0F).pp(0x66).vex_we(false),
+                    EvexDetails::new().map_select(0x0F).pp(0x66).evex_we(true),
+                )
                 .modrm(true, None, None)
                 .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
                 .assemble(ins, bits),
@@ -3494,7 +4014,11 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
         Ins::VPADDD => (
             GenAPI::new()
                 .opcode(&[0xFE])
-                .vex(VexDetails::new().map_select(0x0F).pp(0x66).vex_we(false))
+                .vex_evex(
+                    ins.needs_evex(),
+                    VexDetails::new().map_select(0x0F).pp(0x66).vex_we(false),
+                    EvexDetails::new().map_select(0x0F).pp(0x66).evex_we(false),
+                )
                 .modrm(true, None, None)
                 .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
                 .assemble(ins, bits),
@@ -3503,7 +4027,11 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
         Ins::VPADDQ => (
             GenAPI::new()
                 .opcode(&[0xD4])
-                .vex(VexDetails::new().map_select(0x0F).pp(0x66).vex_we(false))
+                .vex_evex(
+                    ins.needs_evex(),
+                    VexDetails::new().map_select(0x0F).pp(0x66).vex_we(false),
+                    EvexDetails::new().map_select(0x0F).pp(0x66).evex_we(true),
+                )
                 .modrm(true, None, None)
                 .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
                 .assemble(ins, bits),
@@ -3530,7 +4058,11 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
         Ins::VPSUBD => (
             GenAPI::new()
                 .opcode(&[0xFA])
-                .vex(VexDetails::new().map_select(0x0F).pp(0x66).vex_we(false))
+                .vex_evex(
+                    ins.needs_evex(),
+                    VexDetails::new().map_select(0x0F).pp(0x66).vex_we(false),
+                    EvexDetails::new().map_select(0x0F).pp(0x66).evex_we(false),
+                )
                 .modrm(true, None, None)
                 .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
                 .assemble(ins, bits),
@@ -3539,7 +4071,11 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
         Ins::VPSUBQ => (
             GenAPI::new()
                 .opcode(&[0xFB])
-                .vex(VexDetails::new().map_select(0x0F).pp(0x66).vex_we(false))
+                .vex_evex(
+                    ins.needs_evex(),
+                    VexDetails::new().map_select(0x0F).pp(0x66).vex_we(false),
+                    EvexDetails::new().map_select(0x0F).pp(0x66).evex_we(true),
+                )
                 .modrm(true, None, None)
                 .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
                 .assemble(ins, bits),
@@ -4351,6 +4887,82 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
             }
             (api.assemble(ins, bits), None)
         }
+        // part2d
+        Ins::VPERMD => (ins_avx2(ins, 0x38, 0x36, false, bits), None),
+        Ins::VPERMPS => (ins_avx2(ins, 0x38, 0x16, false, bits), None),
+        Ins::VPERMQ => (
+            GenAPI::new()
+                .opcode(&[0x00])
+                .vex(VexDetails::new().pp(0x66).map_select(0x3A).vex_we(true))
+                .modrm(true, None, None)
+                .imm_atindex(2, 1)
+                .ord(&[MODRM_REG, MODRM_RM])
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::VPERMPD => (
+            GenAPI::new()
+                .opcode(&[0x01])
+                .vex(VexDetails::new().pp(0x66).map_select(0x3A).vex_we(true))
+                .modrm(true, None, None)
+                .imm_atindex(2, 1)
+                .ord(&[MODRM_REG, MODRM_RM])
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::VPBROADCASTB => (ins_vpbroadcast(ins, 0x78, bits), None),
+        Ins::VPBROADCASTW => (ins_vpbroadcast(ins, 0x79, bits), None),
+        Ins::VPBROADCASTD => (ins_vpbroadcast(ins, 0x58, bits), None),
+        Ins::VPBROADCASTQ => (ins_vpbroadcast(ins, 0x59, bits), None),
+        Ins::VBROADCASTI128 => (
+            GenAPI::new()
+                .opcode(&[0x5A])
+                .vex(
+                    VexDetails::new()
+                        .pp(0x66)
+                        .map_select(0x38)
+                        .vex_we(false)
+                        .vlength(Some(true)),
+                )
+                .modrm(true, None, None)
+                .ord(&[MODRM_REG, MODRM_RM])
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::VINSERTI128 => (
+            GenAPI::new()
+                .opcode(&[0x38])
+                .vex(VexDetails::new().pp(0x66).map_select(0x3A).vex_we(false))
+                .modrm(true, None, None)
+                .imm_atindex(3, 1)
+                .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::VEXTRACTI128 => (
+            GenAPI::new()
+                .opcode(&[0x39])
+                .vex(
+                    VexDetails::new()
+                        .map_select(0x3A)
+                        .pp(0x66)
+                        .vex_we(false)
+                        .vlength(Some(true)),
+                )
+                .modrm(true, None, None)
+                .imm_atindex(2, 1)
+                .ord(&[MODRM_RM, MODRM_REG])
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::VPSLLVD => (ins_avx2(ins, 0x38, 0x47, false, bits), None),
+        Ins::VPSLLVQ => (ins_avx2(ins, 0x38, 0x47, true, bits), None),
+        Ins::VPSRLVD => (ins_avx2(ins, 0x38, 0x45, false, bits), None),
+        Ins::VPSRLVQ => (ins_avx2(ins, 0x38, 0x45, true, bits), None),
+        Ins::VPSRAVD => (ins_avx2(ins, 0x38, 0x46, false, bits), None),
+        Ins::VPMASKMOVD => (ins_maskmov(ins, 0x8C, false, bits), None),
+        Ins::VPMASKMOVQ => (ins_maskmov(ins, 0x8C, true, bits), None),
+        Ins::VMASKMOVPS => (ins_maskmov(ins, 0x2C, false, bits), None),
         // fma-part1
         Ins::VFMADD132PS => (
             GenAPI::new()
@@ -5277,6 +5889,32 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
                 .assemble(ins, bits),
             None,
         ),
+        // F16C
+        Ins::VCVTPH2PS => (
+            GenAPI::new()
+                .opcode(&[0x13])
+                .vex(VexDetails::new().map_select(0x38).pp(0x66).vex_we(false))
+                .modrm(true, None, None)
+                .ord(&[MODRM_REG, MODRM_RM])
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::VCVTPS2PH => (
+            GenAPI::new()
+                .opcode(&[0x1D])
+                .vex(
+                    VexDetails::new()
+                        .map_select(0x3A)
+                        .pp(0x66)
+                        .vex_we(false)
+                        .vlength(Some(ins.src().unwrap().size() == Size::Yword)),
+                )
+                .modrm(true, None, None)
+                .ord(&[MODRM_RM, MODRM_REG])
+                .imm_atindex(2, 1)
+                .assemble(ins, bits),
+            None,
+        ),
         Ins::VCVTSD2SI => (
             GenAPI::new()
                 .opcode(&[0x2D])
@@ -5611,7 +6249,7 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
             None,
         ),
         // part c
-        Ins::CMPSTRB => (vec![0xA6], None),
+        Ins::CMPSTRB => (GenAPI::new().opcode(&[0xA6]).assemble(ins, bits), None),
         Ins::CMPSTRW => (
             GenAPI::new()
                 .opcode(&[0xA7])
@@ -5626,7 +6264,12 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
                 .assemble(ins, bits),
             None,
         ),
-        Ins::CMPSTRQ => (vec![0b0100_1000, 0xA7], None),
+        Ins::CMPSTRQ => (
+            GenAPI::new()
+                .opcode(&[0b0100_1000, 0xA7])
+                .assemble(ins, bits),
+            None,
+        ),
         Ins::ENDBR64 => (vec![0xF3, 0x0F, 0x1E, 0xFA], None),
         Ins::ENDBR32 => (vec![0xF3, 0x0F, 0x1E, 0xFB], None),
         Ins::CMPXCHG => (
@@ -5718,9 +6361,21 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
                 .assemble(ins, bits),
             None,
         ),
-        Ins::INSB => (vec![0x6C], None),
-        Ins::INSW => (vec![0x66, 0x6D], None),
-        Ins::INSD => (vec![0x6D], None),
+        Ins::INSB => (GenAPI::new().opcode(&[0x6C]).assemble(ins, bits), None),
+        Ins::INSW => (
+            GenAPI::new()
+                .opcode(&[0x6D])
+                .fixed_size(Size::Word)
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::INSD => (
+            GenAPI::new()
+                .opcode(&[0x6D])
+                .fixed_size(Size::Dword)
+                .assemble(ins, bits),
+            None,
+        ),
 
         Ins::INT => (
             vec![
@@ -5776,7 +6431,7 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
                 .assemble(ins, bits),
             None,
         ),
-        Ins::LODSB => (vec![0xAC], None),
+        Ins::LODSB => (GenAPI::new().opcode(&[0xAC]).assemble(ins, bits), None),
         Ins::LODSW => (
             GenAPI::new()
                 .fixed_size(Size::Word)
@@ -5791,12 +6446,14 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
                 .assemble(ins, bits),
             None,
         ),
-        Ins::LODSQ => (vec![0x48, 0xAD], None),
+        Ins::LODSQ => (
+            GenAPI::new().opcode(&[0x48, 0xAD]).assemble(ins, bits),
+            None,
+        ),
 
         // part 3
-        //Ins::LOOP => ins_shrtjmp(ins, vec![0xE2]),
-        //Ins::LOOPE => ins_shrtjmp(ins, vec![0xE1]),
-        //Ins::LOOPNE => ins_shrtjmp(ins, vec![0xE0]),
+        Ins::LOOP | Ins::LOOPE | Ins::LOOPNE => ins_shrtjmp(ins, bits),
+        Ins::JECXZ | Ins::JRCXZ => ins_shrtjmp(ins, bits),
         Ins::LSL => (
             GenAPI::new()
                 .opcode(&[0x0F, 0x03])
@@ -5824,6 +6481,16 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
                 .assemble(ins, bits),
             None,
         ),
+        Ins::TZCNT => (
+            GenAPI::new()
+                .opcode(&[0x0F, 0xBC])
+                .prefix(0xF3)
+                .modrm(true, None, None)
+                .ord(&[MODRM_REG, MODRM_RM])
+                .rex(true)
+                .assemble(ins, bits),
+            None,
+        ),
         Ins::MOVBE => (
             {
                 let mut api = GenAPI::new().modrm(true, None, None).rex(true);
@@ -5849,16 +6516,37 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
                 .assemble(ins, bits),
             None,
         ),
-        Ins::MOVDIRI => (
+        Ins::MOVSX => (
             GenAPI::new()
-                .opcode(&[0x0F, 0x38, 0xF9])
+                .opcode(&[
+                    0x0F,
+                    (0xBE + ((ins.src().unwrap().size() == Size::Word) as u8)),
+                ])
                 .modrm(true, None, None)
+                .ord(&[MODRM_REG, MODRM_RM])
                 .rex(true)
                 .assemble(ins, bits),
             None,
         ),
-        Ins::MOVSTRB => (vec![0xA4], None),
-        Ins::MOVSTRW => (
+        Ins::MOVSXD => (
+            GenAPI::new()
+                .opcode(&[0x63])
+                .modrm(true, None, None)
+                .ord(&[MODRM_REG, MODRM_RM])
+                .rex(true)
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::MOVDIRI => (
+            GenAPI::new()
+                .opcode(&[0x0F, 0x38, 0xF9])
+                .modrm(true, None, None)
+                .rex(true)
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::MOVSTRB => (GenAPI::new().opcode(&[0xA4]).assemble(ins, bits), None),
+        Ins::MOVSTRW => (
             GenAPI::new()
                 .opcode(&[0xA5])
                 .fixed_size(Size::Word)
@@ -5872,7 +6560,10 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
                 .assemble(ins, bits),
             None,
         ),
-        Ins::MOVSTRQ => (vec![0x48, 0xA5], None),
+        Ins::MOVSTRQ => (
+            GenAPI::new().opcode(&[0x48, 0xA5]).assemble(ins, bits),
+            None,
+        ),
         Ins::MULX => (
             GenAPI::new()
                 .vex(
@@ -5930,7 +6621,7 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
                 .assemble(ins, bits),
             None,
         ),
-        Ins::OUTSB => (vec![0x6E], None),
+        Ins::OUTSB => (GenAPI::new().opcode(&[0x6E]).assemble(ins, bits), None),
         Ins::OUTSW => (
             GenAPI::new()
                 .opcode(&[0x6F])
@@ -6142,7 +6833,7 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
             ),
             None,
         ),
-        Ins::SCASB => (vec![0xAE], None),
+        Ins::SCASB => (GenAPI::new().opcode(&[0xAE]).assemble(ins, bits), None),
         Ins::SCASW => (
             GenAPI::new()
                 .fixed_size(Size::Word)
@@ -6157,7 +6848,10 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
                 .assemble(ins, bits),
             None,
         ),
-        Ins::SCASQ => (vec![0x48, 0xAF], None),
+        Ins::SCASQ => (
+            GenAPI::new().opcode(&[0x48, 0xAF]).assemble(ins, bits),
+            None,
+        ),
         Ins::SENDUIPI => (
             GenAPI::new()
                 .prefix(0xF3)
@@ -6320,13 +7014,64 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
         Ins::STD => (vec![0xFD], None),
         Ins::STI => (vec![0xFB], None),
         Ins::STUI => (vec![0xF3, 0x0F, 0x01, 0xEF], None),
-        Ins::STOSB => (vec![0xAA], None),
-        Ins::STOSW => (vec![0x66, 0xAB], None),
-        Ins::STOSD => (vec![0xAB], None),
-        Ins::STOSQ => (vec![0x48, 0xAB], None),
+        Ins::STOSB => (GenAPI::new().opcode(&[0xAA]).assemble(ins, bits), None),
+        Ins::STOSW => (
+            GenAPI::new()
+                .opcode(&[0xAB])
+                .fixed_size(Size::Word)
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::STOSD => (
+            GenAPI::new()
+                .opcode(&[0xAB])
+                .fixed_size(Size::Dword)
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::STOSQ => (
+            GenAPI::new().opcode(&[0x48, 0xAB]).assemble(ins, bits),
+            None,
+        ),
         Ins::SYSENTER => (vec![0x0F, 0x34], None),
         Ins::SYSEXIT => (vec![0x0F, 0x35], None),
         Ins::SYSRET => (vec![0x0F, 0x07], None),
+        Ins::SWAPGS => (vec![0x0F, 0x01, 0xF8], None),
+        Ins::SGDT => (ins_systable(ins, 0, bits), None),
+        Ins::SIDT => (ins_systable(ins, 1, bits), None),
+        Ins::LGDT => (ins_systable(ins, 2, bits), None),
+        Ins::LIDT => (ins_systable(ins, 3, bits), None),
+        Ins::RDFSBASE => (ins_fsgsbase(ins, 0, bits), None),
+        Ins::RDGSBASE => (ins_fsgsbase(ins, 1, bits), None),
+        Ins::WRFSBASE => (ins_fsgsbase(ins, 2, bits), None),
+        Ins::WRGSBASE => (ins_fsgsbase(ins, 3, bits), None),
+        Ins::PUSHA => (vec![0x60], None),
+        Ins::POPA => (vec![0x61], None),
+        Ins::PUSHAD => (
+            GenAPI::new()
+                .opcode(&[0x60])
+                .fixed_size(Size::Dword)
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::POPAD => (
+            GenAPI::new()
+                .opcode(&[0x61])
+                .fixed_size(Size::Dword)
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::RETF => (
+            if ins.dst().is_some() {
+                GenAPI::new()
+                    .opcode(&[0xCA])
+                    .imm_atindex(0, 2)
+                    .assemble(ins, bits)
+            } else {
+                vec![0xCB]
+            },
+            None,
+        ),
         Ins::TESTUI => (vec![0xF3, 0x0F, 0x01, 0xED], None),
         Ins::UD2 => (vec![0x0F, 0x0B], None),
         Ins::UD0 => (
@@ -6555,6 +7300,518 @@ pub fn compile_instruction(ins: &'_ Instruction, bits: u8) -> (Vec<u8>, Option<R
                 .assemble(ins, bits),
             None,
         ),
+        // AVX2 gathers
+        Ins::VPGATHERDD => (ins_gather(ins, 0x90, false, bits), None),
+        Ins::VPGATHERDQ => (ins_gather(ins, 0x90, true, bits), None),
+        Ins::VPGATHERQD => (ins_gather(ins, 0x91, false, bits), None),
+        Ins::VPGATHERQQ => (ins_gather(ins, 0x91, true, bits), None),
+        Ins::VGATHERDPS => (ins_gather(ins, 0x92, false, bits), None),
+        Ins::VGATHERDPD => (ins_gather(ins, 0x92, true, bits), None),
+        Ins::VGATHERQPS => (ins_gather(ins, 0x93, false, bits), None),
+        Ins::VGATHERQPD => (ins_gather(ins, 0x93, true, bits), None),
+        // AVX-512F
+        Ins::VMOVDQA32 => {
+            let mut api = GenAPI::new()
+                .modrm(true, None, None)
+                .evex(EvexDetails::new().pp(0x66).map_select(0x0F).evex_we(false));
+            if ins.dst().unwrap().is_mem() {
+                api = api.opcode(&[0x7F]);
+            } else {
+                api = api.opcode(&[0x6F]).ord(&[MODRM_REG, MODRM_RM]);
+            }
+            (api.assemble(ins, bits), None)
+        }
+        Ins::VMOVDQA64 => {
+            let mut api = GenAPI::new()
+                .modrm(true, None, None)
+                .evex(EvexDetails::new().pp(0x66).map_select(0x0F).evex_we(true));
+            if ins.dst().unwrap().is_mem() {
+                api = api.opcode(&[0x7F]);
+            } else {
+                api = api.opcode(&[0x6F]).ord(&[MODRM_REG, MODRM_RM]);
+            }
+            (api.assemble(ins, bits), None)
+        }
+        Ins::VMOVDQU32 => {
+            let mut api = GenAPI::new()
+                .modrm(true, None, None)
+                .evex(EvexDetails::new().pp(0xF3).map_select(0x0F).evex_we(false));
+            if ins.dst().unwrap().is_mem() {
+                api = api.opcode(&[0x7F]);
+            } else {
+                api = api.opcode(&[0x6F]).ord(&[MODRM_REG, MODRM_RM]);
+            }
+            (api.assemble(ins, bits), None)
+        }
+        Ins::VMOVDQU64 => {
+            let mut api = GenAPI::new()
+                .modrm(true, None, None)
+                .evex(EvexDetails::new().pp(0xF3).map_select(0x0F).evex_we(true));
+            if ins.dst().unwrap().is_mem() {
+                api = api.opcode(&[0x7F]);
+            } else {
+                api = api.opcode(&[0x6F]).ord(&[MODRM_REG, MODRM_RM]);
+            }
+            (api.assemble(ins, bits), None)
+        }
+        Ins::VPANDD => (
+            GenAPI::new()
+                .opcode(&[0xDB])
+                .evex(EvexDetails::new().map_select(0x0F).pp(0x66).evex_we(false))
+                .modrm(true, None, None)
+                .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::VPANDQ => (
+            GenAPI::new()
+                .opcode(&[0xDB])
+                .evex(EvexDetails::new().map_select(0x0F).pp(0x66).evex_we(true))
+                .modrm(true, None, None)
+                .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::VPANDND => (
+            GenAPI::new()
+                .opcode(&[0xDF])
+                .evex(EvexDetails::new().map_select(0x0F).pp(0x66).evex_we(false))
+                .modrm(true, None, None)
+                .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::VPANDNQ => (
+            GenAPI::new()
+                .opcode(&[0xDF])
+                .evex(EvexDetails::new().map_select(0x0F).pp(0x66).evex_we(true))
+                .modrm(true, None, None)
+                .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::VPORD => (
+            GenAPI::new()
+                .opcode(&[0xEB])
+                .evex(EvexDetails::new().map_select(0x0F).pp(0x66).evex_we(false))
+                .modrm(true, None, None)
+                .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::VPORQ => (
+            GenAPI::new()
+                .opcode(&[0xEB])
+                .evex(EvexDetails::new().map_select(0x0F).pp(0x66).evex_we(true))
+                .modrm(true, None, None)
+                .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::VPXORD => (
+            GenAPI::new()
+                .opcode(&[0xEF])
+                .evex(EvexDetails::new().map_select(0x0F).pp(0x66).evex_we(false))
+                .modrm(true, None, None)
+                .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::VPXORQ => (
+            GenAPI::new()
+                .opcode(&[0xEF])
+                .evex(EvexDetails::new().map_select(0x0F).pp(0x66).evex_we(true))
+                .modrm(true, None, None)
+                .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::VPERMT2D => (
+            GenAPI::new()
+                .opcode(&[0x7E])
+                .evex(EvexDetails::new().map_select(0x38).pp(0x66).evex_we(false))
+                .modrm(true, None, None)
+                .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::VPERMT2Q => (
+            GenAPI::new()
+                .opcode(&[0x7E])
+                .evex(EvexDetails::new().map_select(0x38).pp(0x66).evex_we(true))
+                .modrm(true, None, None)
+                .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::VPERMT2PS => (
+            GenAPI::new()
+                .opcode(&[0x7F])
+                .evex(EvexDetails::new().map_select(0x38).pp(0x66).evex_we(false))
+                .modrm(true, None, None)
+                .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::VPERMT2PD => (
+            GenAPI::new()
+                .opcode(&[0x7F])
+                .evex(EvexDetails::new().map_select(0x38).pp(0x66).evex_we(true))
+                .modrm(true, None, None)
+                .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::VPERMI2D => (
+            GenAPI::new()
+                .opcode(&[0x76])
+                .evex(EvexDetails::new().map_select(0x38).pp(0x66).evex_we(false))
+                .modrm(true, None, None)
+                .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::VPERMI2Q => (
+            GenAPI::new()
+                .opcode(&[0x76])
+                .evex(EvexDetails::new().map_select(0x38).pp(0x66).evex_we(true))
+                .modrm(true, None, None)
+                .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::VPERMI2PS => (
+            GenAPI::new()
+                .opcode(&[0x77])
+                .evex(EvexDetails::new().map_select(0x38).pp(0x66).evex_we(false))
+                .modrm(true, None, None)
+                .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::VPERMI2PD => (
+            GenAPI::new()
+                .opcode(&[0x77])
+                .evex(EvexDetails::new().map_select(0x38).pp(0x66).evex_we(true))
+                .modrm(true, None, None)
+                .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::VPTERNLOGD => (
+            GenAPI::new()
+                .opcode(&[0x25])
+                .evex(EvexDetails::new().map_select(0x3A).pp(0x66).evex_we(false))
+                .modrm(true, None, None)
+                .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+                .imm_atindex(3, 1)
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::VPTERNLOGQ => (
+            GenAPI::new()
+                .opcode(&[0x25])
+                .evex(EvexDetails::new().map_select(0x3A).pp(0x66).evex_we(true))
+                .modrm(true, None, None)
+                .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+                .imm_atindex(3, 1)
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::VALIGND => (
+            GenAPI::new()
+                .opcode(&[0x03])
+                .evex(EvexDetails::new().map_select(0x3A).pp(0x66).evex_we(false))
+                .modrm(true, None, None)
+                .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+                .imm_atindex(3, 1)
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::VALIGNQ => (
+            GenAPI::new()
+                .opcode(&[0x03])
+                .evex(EvexDetails::new().map_select(0x3A).pp(0x66).evex_we(true))
+                .modrm(true, None, None)
+                .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+                .imm_atindex(3, 1)
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::VPMAXSQ => (
+            GenAPI::new()
+                .opcode(&[0x3D])
+                .evex(EvexDetails::new().map_select(0x38).pp(0x66).evex_we(true))
+                .modrm(true, None, None)
+                .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::VPMINSQ => (
+            GenAPI::new()
+                .opcode(&[0x39])
+                .evex(EvexDetails::new().map_select(0x38).pp(0x66).evex_we(true))
+                .modrm(true, None, None)
+                .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::VPMAXUQ => (
+            GenAPI::new()
+                .opcode(&[0x3F])
+                .evex(EvexDetails::new().map_select(0x38).pp(0x66).evex_we(true))
+                .modrm(true, None, None)
+                .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::VPMINUQ => (
+            GenAPI::new()
+                .opcode(&[0x3B])
+                .evex(EvexDetails::new().map_select(0x38).pp(0x66).evex_we(true))
+                .modrm(true, None, None)
+                .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::VPABSQ => (
+            GenAPI::new()
+                .opcode(&[0x1F])
+                .evex(EvexDetails::new().map_select(0x38).pp(0x66).evex_we(true))
+                .modrm(true, None, None)
+                .ord(&[MODRM_REG, MODRM_RM])
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::VPCMPD => (ins_vpcmp(ins, bits, 0x1F, false), None),
+        Ins::VPCMPUD => (ins_vpcmp(ins, bits, 0x1E, false), None),
+        Ins::VPCMPQ => (ins_vpcmp(ins, bits, 0x1F, true), None),
+        Ins::VPCMPUQ => (ins_vpcmp(ins, bits, 0x1E, true), None),
+        Ins::KMOVW => {
+            let gpr =
+                |o: &Operand| matches!(o, Operand::Reg(r) if r.purpose() == RPurpose::General);
+            let api = GenAPI::new()
+                .modrm(true, None, None)
+                .vex(VexDetails::new().pp(0).map_select(0x0F).vex_we(false));
+            let (dst, src) = (ins.dst().unwrap(), ins.src().unwrap());
+            let api = if dst.is_mem() {
+                api.opcode(&[0x91]).ord(&[MODRM_RM, MODRM_REG])
+            } else if gpr(src) {
+                api.opcode(&[0x92]).ord(&[MODRM_REG, MODRM_RM])
+            } else if gpr(dst) {
+                api.opcode(&[0x93]).ord(&[MODRM_REG, MODRM_RM])
+            } else {
+                api.opcode(&[0x90]).ord(&[MODRM_REG, MODRM_RM])
+            };
+            (api.assemble(ins, bits), None)
+        }
+        // x87 FPU
+        Ins::F2XM1 => (vec![0xD9, 0xF0], None),
+        Ins::FABS => (vec![0xD9, 0xE1], None),
+        Ins::FCHS => (vec![0xD9, 0xE0], None),
+        Ins::FCOS => (vec![0xD9, 0xFF], None),
+        Ins::FDECSTP => (vec![0xD9, 0xF6], None),
+        Ins::FINCSTP => (vec![0xD9, 0xF7], None),
+        Ins::FLD1 => (vec![0xD9, 0xE8], None),
+        Ins::FLDL2T => (vec![0xD9, 0xE9], None),
+        Ins::FLDL2E => (vec![0xD9, 0xEA], None),
+        Ins::FLDPI => (vec![0xD9, 0xEB], None),
+        Ins::FLDLG2 => (vec![0xD9, 0xEC], None),
+        Ins::FLDLN2 => (vec![0xD9, 0xED], None),
+        Ins::FLDZ => (vec![0xD9, 0xEE], None),
+        Ins::FNOP => (vec![0xD9, 0xD0], None),
+        Ins::FPATAN => (vec![0xD9, 0xF3], None),
+        Ins::FPREM => (vec![0xD9, 0xF8], None),
+        Ins::FPREM1 => (vec![0xD9, 0xF5], None),
+        Ins::FPTAN => (vec![0xD9, 0xF2], None),
+        Ins::FRNDINT => (vec![0xD9, 0xFC], None),
+        Ins::FSCALE => (vec![0xD9, 0xFD], None),
+        Ins::FSIN => (vec![0xD9, 0xFE], None),
+        Ins::FSINCOS => (vec![0xD9, 0xFB], None),
+        Ins::FSQRT => (vec![0xD9, 0xFA], None),
+        Ins::FTST => (vec![0xD9, 0xE4], None),
+        Ins::FXAM => (vec![0xD9, 0xE5], None),
+        Ins::FXTRACT => (vec![0xD9, 0xF4], None),
+        Ins::FYL2X => (vec![0xD9, 0xF1], None),
+        Ins::FYL2XP1 => (vec![0xD9, 0xF9], None),
+        Ins::FCOMPP => (vec![0xDE, 0xD9], None),
+        Ins::FUCOMPP => (vec![0xDA, 0xE9], None),
+        Ins::FINIT => (vec![0x9B, 0xDB, 0xE3], None),
+        Ins::FNINIT => (vec![0xDB, 0xE3], None),
+        Ins::FCLEX => (vec![0x9B, 0xDB, 0xE2], None),
+        Ins::FNCLEX => (vec![0xDB, 0xE2], None),
+
+        Ins::FLD => (
+            ins_fpu(
+                ins,
+                bits,
+                &[
+                    (Size::Dword, 0xD9, 0),
+                    (Size::Qword, 0xDD, 0),
+                    (Size::Tword, 0xDB, 5),
+                ],
+                [0xD9, 0xC0],
+            ),
+            None,
+        ),
+        Ins::FST => (
+            ins_fpu(
+                ins,
+                bits,
+                &[(Size::Dword, 0xD9, 2), (Size::Qword, 0xDD, 2)],
+                [0xDD, 0xD0],
+            ),
+            None,
+        ),
+        Ins::FSTP => (
+            ins_fpu(
+                ins,
+                bits,
+                &[
+                    (Size::Dword, 0xD9, 3),
+                    (Size::Qword, 0xDD, 3),
+                    (Size::Tword, 0xDB, 7),
+                ],
+                [0xDD, 0xD8],
+            ),
+            None,
+        ),
+        Ins::FILD => (
+            ins_fpu_mem(
+                ins,
+                bits,
+                &[
+                    (Size::Word, 0xDF, 0),
+                    (Size::Dword, 0xDB, 0),
+                    (Size::Qword, 0xDF, 5),
+                ],
+            ),
+            None,
+        ),
+        Ins::FIST => (
+            ins_fpu_mem(ins, bits, &[(Size::Word, 0xDF, 2), (Size::Dword, 0xDB, 2)]),
+            None,
+        ),
+        Ins::FISTP => (
+            ins_fpu_mem(
+                ins,
+                bits,
+                &[
+                    (Size::Word, 0xDF, 3),
+                    (Size::Dword, 0xDB, 3),
+                    (Size::Qword, 0xDF, 7),
+                ],
+            ),
+            None,
+        ),
+        Ins::FISTTP => (
+            ins_fpu_mem(
+                ins,
+                bits,
+                &[
+                    (Size::Word, 0xDF, 1),
+                    (Size::Dword, 0xDB, 1),
+                    (Size::Qword, 0xDD, 1),
+                ],
+            ),
+            None,
+        ),
+        Ins::FBLD => (ins_fpu_mem(ins, bits, &[(Size::Tword, 0xDF, 4)]), None),
+        Ins::FBSTP => (ins_fpu_mem(ins, bits, &[(Size::Tword, 0xDF, 6)]), None),
+
+        Ins::FADD => (ins_fpu_arith(ins, bits, 0), None),
+        Ins::FMUL => (ins_fpu_arith(ins, bits, 1), None),
+        Ins::FSUB => (ins_fpu_arith(ins, bits, 4), None),
+        Ins::FSUBR => (ins_fpu_arith(ins, bits, 5), None),
+        Ins::FDIV => (ins_fpu_arith(ins, bits, 6), None),
+        Ins::FDIVR => (ins_fpu_arith(ins, bits, 7), None),
+        Ins::FADDP => (ins_fpu_st(ins, [0xDE, 0xC0]), None),
+        Ins::FMULP => (ins_fpu_st(ins, [0xDE, 0xC8]), None),
+        Ins::FSUBP => (ins_fpu_st(ins, [0xDE, 0xE8]), None),
+        Ins::FSUBRP => (ins_fpu_st(ins, [0xDE, 0xE0]), None),
+        Ins::FDIVP => (ins_fpu_st(ins, [0xDE, 0xF8]), None),
+        Ins::FDIVRP => (ins_fpu_st(ins, [0xDE, 0xF0]), None),
+
+        Ins::FIADD => (ins_fpu_int(ins, bits, 0), None),
+        Ins::FIMUL => (ins_fpu_int(ins, bits, 1), None),
+        Ins::FICOM => (ins_fpu_int(ins, bits, 2), None),
+        Ins::FICOMP => (ins_fpu_int(ins, bits, 3), None),
+        Ins::FISUB => (ins_fpu_int(ins, bits, 4), None),
+        Ins::FISUBR => (ins_fpu_int(ins, bits, 5), None),
+        Ins::FIDIV => (ins_fpu_int(ins, bits, 6), None),
+        Ins::FIDIVR => (ins_fpu_int(ins, bits, 7), None),
+
+        Ins::FCOM => (
+            ins_fpu(
+                ins,
+                bits,
+                &[(Size::Dword, 0xD8, 2), (Size::Qword, 0xDC, 2)],
+                [0xD8, 0xD0],
+            ),
+            None,
+        ),
+        Ins::FCOMP => (
+            ins_fpu(
+                ins,
+                bits,
+                &[(Size::Dword, 0xD8, 3), (Size::Qword, 0xDC, 3)],
+                [0xD8, 0xD8],
+            ),
+            None,
+        ),
+        Ins::FUCOM => (ins_fpu_st(ins, [0xDD, 0xE0]), None),
+        Ins::FUCOMP => (ins_fpu_st(ins, [0xDD, 0xE8]), None),
+        Ins::FCOMI => (ins_fpu_st(ins, [0xDB, 0xF0]), None),
+        Ins::FCOMIP => (ins_fpu_st(ins, [0xDF, 0xF0]), None),
+        Ins::FUCOMI => (ins_fpu_st(ins, [0xDB, 0xE8]), None),
+        Ins::FUCOMIP => (ins_fpu_st(ins, [0xDF, 0xE8]), None),
+        Ins::FCMOVB => (ins_fpu_st(ins, [0xDA, 0xC0]), None),
+        Ins::FCMOVE => (ins_fpu_st(ins, [0xDA, 0xC8]), None),
+        Ins::FCMOVBE => (ins_fpu_st(ins, [0xDA, 0xD0]), None),
+        Ins::FCMOVU => (ins_fpu_st(ins, [0xDA, 0xD8]), None),
+        Ins::FCMOVNB => (ins_fpu_st(ins, [0xDB, 0xC0]), None),
+        Ins::FCMOVNE => (ins_fpu_st(ins, [0xDB, 0xC8]), None),
+        Ins::FCMOVNBE => (ins_fpu_st(ins, [0xDB, 0xD0]), None),
+        Ins::FCMOVNU => (ins_fpu_st(ins, [0xDB, 0xD8]), None),
+        Ins::FXCH => (ins_fpu_st(ins, [0xD9, 0xC8]), None),
+        Ins::FFREE => (ins_fpu_st(ins, [0xDD, 0xC0]), None),
+
+        Ins::FLDCW => (ins_fpu_mem(ins, bits, &[(Size::Word, 0xD9, 5)]), None),
+        Ins::FNSTCW => (ins_fpu_mem(ins, bits, &[(Size::Word, 0xD9, 7)]), None),
+        Ins::FSTCW => (
+            [vec![0x9B], ins_fpu_mem(ins, bits, &[(Size::Word, 0xD9, 7)])].concat(),
+            None,
+        ),
+        Ins::FNSTSW => (ins_fpu_stsw(ins, bits), None),
+        Ins::FSTSW => ([vec![0x9B], ins_fpu_stsw(ins, bits)].concat(), None),
+        Ins::FLDENV => (ins_fpu_mem(ins, bits, &[(Size::Any, 0xD9, 4)]), None),
+        Ins::FNSTENV => (ins_fpu_mem(ins, bits, &[(Size::Any, 0xD9, 6)]), None),
+        Ins::FSTENV => (
+            [vec![0x9B], ins_fpu_mem(ins, bits, &[(Size::Any, 0xD9, 6)])].concat(),
+            None,
+        ),
+        Ins::FRSTOR => (ins_fpu_mem(ins, bits, &[(Size::Any, 0xDD, 4)]), None),
+        Ins::FNSAVE => (ins_fpu_mem(ins, bits, &[(Size::Any, 0xDD, 6)]), None),
+        Ins::FSAVE => (
+            [vec![0x9B], ins_fpu_mem(ins, bits, &[(Size::Any, 0xDD, 6)])].concat(),
+            None,
+        ),
+        Ins::FXSAVE | Ins::FXSAVE64 => (
+            GenAPI::new()
+                .opcode(&[0x0F, 0xAE])
+                .modrm(true, Some(0), None)
+                .rex(true)
+                .assemble(ins, bits),
+            None,
+        ),
+        Ins::FXRSTOR | Ins::FXRSTOR64 => (
+            GenAPI::new()
+                .opcode(&[0x0F, 0xAE])
+                .modrm(true, Some(1), None)
+                .rex(true)
+                .assemble(ins, bits),
+            None,
+        ),
         // other
         _ => todo!("Instruction unsupported in src/core/comp.rs: {:?}", ins),
     }
@@ -6634,7 +7891,7 @@ fn ins_xchg(ins: &Instruction) -> GenAPI {
     api
 }
 
-fn ins_xbegin(ins: &Instruction) -> (Vec<u8>, Option<Relocation>) {
+fn ins_xbegin(ins: &Instruction) -> (Vec<u8>, Option<Relocation<'_>>) {
     let (symbol, reltype, addend) = if let Some(Operand::SymbolRef(str)) = ins.dst() {
         (str, RelType::REL32, 0)
     } else if let Some(Operand::SymbolRefExt(s)) = ins.dst() {
@@ -6773,6 +8030,98 @@ fn ins_push(ins: &Instruction, bits: u8) -> Vec<u8> {
     }
 }
 
+// mov r64, imm uses shortest form, that preserves value of immediate:
+// - B8+r id (zero-extended) if immediate fits in u32,
+// - REX.W C7 /0 id (sign-extended) if immediate fits in i32,
+// - REX.W B8+r io otherwise
+fn ins_mov_imm64(ins: &Instruction, r: &Register, n: &Number, bits: u8) -> Vec<u8> {
+    let val = n.get_as_u64();
+    if !n.is_signed() && val <= u32::MAX as u64 {
+        let mut base = Vec::new();
+        if r.needs_rex() {
+            base.push(0x41);
+        }
+        base.push(0xB8 + r.to_byte());
+        base.extend(&val.to_le_bytes()[..4]);
+        return base;
+    }
+    if i32::try_from(val as i64).is_ok() {
+        return GenAPI::new()
+            .opcode(&[0xC7])
+            .modrm(true, Some(0), None)
+            .imm_atindex(1, 4)
+            .rex(true)
+            .assemble(ins, bits);
+    }
+    GenAPI::new()
+        .opcode(&[0xB8 + r.to_byte()])
+        .imm_atindex(1, 8)
+        .rex(true)
+        .assemble(ins, bits)
+}
+
+// movabs always uses 64-bit immediate (REX.W B8+r io) or 64-bit memory offset (A0-A3);
+// symbols are referenced with R_X86_64_64 relocation
+fn ins_movabs(ins: &Instruction, bits: u8) -> (Vec<u8>, Option<Relocation<'_>>) {
+    let src = ins.src().unwrap();
+    let (opc, mem) = match (ins.dst().unwrap(), src) {
+        (Operand::Reg(r), Operand::Mem(m)) => (0xA0 + (r.size() != Size::Byte) as u8, m),
+        (Operand::Mem(m), Operand::Reg(r)) => (0xA2 + (r.size() != Size::Byte) as u8, m),
+        (Operand::Reg(r), Operand::Imm(_)) => {
+            return (
+                GenAPI::new()
+                    .opcode(&[0xB8 + r.to_byte()])
+                    .imm_atindex(1, 8)
+                    .rex(true)
+                    .assemble(ins, bits),
+                None,
+            )
+        }
+        (Operand::Reg(r), Operand::SymbolRef(_) | Operand::SymbolRefExt(_)) => {
+            let (symbol, addend) = match src {
+                Operand::SymbolRef(s) => (s, 0),
+                Operand::SymbolRefExt(s) => (&s.symbol, s.addend),
+                _ => invalid(36),
+            };
+            let mut base = GenAPI::new()
+                .opcode(&[0xB8 + r.to_byte()])
+                .rex(true)
+                .assemble(ins, bits);
+            let offset = base.len() as u32;
+            base.extend([0x00; 8]);
+            return (
+                base,
+                Some(Relocation {
+                    reltype: RelType::ABS64,
+                    symbol,
+                    offset,
+                    addend,
+                    shidx: 0,
+                }),
+            );
+        }
+        _ => invalid(37),
+    };
+    let mut base = GenAPI::new().opcode(&[opc]).rex(true).assemble(ins, bits);
+    let offset = base.len() as u32;
+    let disp = mem.offset().unwrap_or(0);
+    if let Some(symbol) = &ins.symbol {
+        base.extend([0x00; 8]);
+        return (
+            base,
+            Some(Relocation {
+                reltype: RelType::ABS64,
+                symbol: &symbol.symbol,
+                offset,
+                addend: symbol.addend + disp,
+                shidx: 0,
+            }),
+        );
+    }
+    base.extend((disp as i64).to_le_bytes());
+    (base, None)
+}
+
 fn ins_mov(ins: &Instruction, bits: u8) -> Vec<u8> {
     let src = ins.src().unwrap();
     let dst = ins.dst().unwrap();
@@ -6793,17 +8142,20 @@ fn ins_mov(ins: &Instruction, bits: u8) -> Vec<u8> {
                 .modrm(true, None, None)
                 .rex(true)
                 .assemble(ins, bits),
-            Operand::Imm(_) => {
+            Operand::Imm(n) => {
                 let size = dst.size();
+                if size == Size::Qword {
+                    return ins_mov_imm64(ins, r, n, bits);
+                }
                 let opc = match size {
                     Size::Byte => 0xB0 + r.to_byte(),
-                    Size::Word | Size::Dword | Size::Qword => 0xB8 + r.to_byte(),
+                    Size::Word | Size::Dword => 0xB8 + r.to_byte(),
                     _ => invalid(29),
                 };
-                let size = if size == Size::Qword { 4 } else { size.into() };
                 GenAPI::new()
                     .opcode(&[opc])
-                    .imm_atindex(1, size as u16)
+                    .imm_atindex(1, u8::from(size) as u16)
+                    .rex(true)
                     .assemble(ins, bits)
             }
             Operand::Reg(_) => {
@@ -6897,7 +8249,7 @@ fn ins_mov(ins: &Instruction, bits: u8) -> Vec<u8> {
                     .opcode(&[opc])
                     .modrm(true, Some(0), None)
                     .rex(true)
-                    .imm_atindex(1, size as u16 + 1)
+                    .imm_atindex(1, u8::from(size).min(4) as u16)
                     .assemble(ins, bits)
             }
             _ => invalid(22),
@@ -7012,9 +8364,9 @@ fn add_like_ins(ins: &Instruction, opc: &[u8; 9], ovrreg: u8, bits: u8) -> Vec<u
                 .imm_atindex(1, size)
                 .assemble(ins, bits)
         }
-        (Operand::Reg(r), Operand::Segment(_) | Operand::Mem(_) | Operand::Reg(_)) => {
+        (Operand::Reg(r), Operand::Reg(_)) => {
             let opc = match r.size() {
-                Size::Byte => opc[7],
+                Size::Byte => opc[5],
                 Size::Word | Size::Dword | Size::Qword => opc[6],
                 _ => invalid(17),
             };
@@ -7024,9 +8376,22 @@ fn add_like_ins(ins: &Instruction, opc: &[u8; 9], ovrreg: u8, bits: u8) -> Vec<u
                 .rex(true)
                 .assemble(ins, bits)
         }
+        (Operand::Reg(r), Operand::Segment(_) | Operand::Mem(_)) => {
+            let opc = match r.size() {
+                Size::Byte => opc[7],
+                Size::Word | Size::Dword | Size::Qword => opc[8],
+                _ => invalid(17),
+            };
+            GenAPI::new()
+                .opcode(&[opc])
+                .modrm(true, None, None)
+                .ord(&[OpOrd::MODRM_REG, OpOrd::MODRM_RM])
+                .rex(true)
+                .assemble(ins, bits)
+        }
         (Operand::Segment(m), Operand::Reg(_)) => {
             let opc = match m.address.size().unwrap_or_default() {
-                Size::Byte => opc[7],
+                Size::Byte => opc[5],
                 Size::Word | Size::Dword | Size::Qword => opc[6],
                 _ => invalid(16),
             };
@@ -7038,7 +8403,7 @@ fn add_like_ins(ins: &Instruction, opc: &[u8; 9], ovrreg: u8, bits: u8) -> Vec<u
         }
         (Operand::Mem(m), Operand::Reg(_)) => {
             let opc = match m.size().unwrap_or_default() {
-                Size::Byte => opc[7],
+                Size::Byte => opc[5],
                 Size::Word | Size::Dword | Size::Qword => opc[6],
                 _ => invalid(15),
             };
@@ -7184,7 +8549,19 @@ fn ins_cmp(ins: &Instruction, bits: u8) -> Vec<u8> {
                 .imm_atindex(1, size)
                 .assemble(ins, bits)
         }
-        (Operand::Reg(r), Operand::Segment(_) | Operand::Mem(_) | Operand::Reg(_)) => {
+        (Operand::Reg(r), Operand::Reg(_)) => {
+            let opc = match r.size() {
+                Size::Byte => 0x38,
+                Size::Word | Size::Dword | Size::Qword => 0x39,
+                _ => invalid(10),
+            };
+            GenAPI::new()
+                .opcode(&[opc])
+                .modrm(true, None, None)
+                .rex(true)
+                .assemble(ins, bits)
+        }
+        (Operand::Reg(r), Operand::Segment(_) | Operand::Mem(_)) => {
             let opc = match r.size() {
                 Size::Byte => 0x3A,
                 Size::Word | Size::Dword | Size::Qword => 0x3B,
@@ -7193,6 +8570,7 @@ fn ins_cmp(ins: &Instruction, bits: u8) -> Vec<u8> {
             GenAPI::new()
                 .opcode(&[opc])
                 .modrm(true, None, None)
+                .ord(&[OpOrd::MODRM_REG, OpOrd::MODRM_RM])
                 .rex(true)
                 .assemble(ins, bits)
         }
@@ -7430,7 +8808,83 @@ fn ins_inclike(ins: &Instruction, opc: &[u8; 2], ovr: u8, bits: u8) -> Vec<u8> {
         .assemble(ins, bits)
 }
 
-fn ins_lea(ins: &Instruction, bits: u8) -> (Vec<u8>, Option<Relocation>) {
+// VEX.66 instruction in form: dst = ModRM.reg, src1 = VEX.vvvv, src2 = ModRM.rm
+fn ins_avx2(ins: &Instruction, map: u8, opc: u8, we: bool, bits: u8) -> Vec<u8> {
+    GenAPI::new()
+        .opcode(&[opc])
+        .vex(VexDetails::new().pp(0x66).map_select(map).vex_we(we))
+        .modrm(true, None, None)
+        .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+        .assemble(ins, bits)
+}
+
+// VEX.L is taken from destination (source is always xmm register or memory)
+fn ins_vpbroadcast(ins: &Instruction, opc: u8, bits: u8) -> Vec<u8> {
+    let ymm = ins.dst().unwrap().size() == Size::Yword;
+    GenAPI::new()
+        .opcode(&[opc])
+        .vex(
+            VexDetails::new()
+                .pp(0x66)
+                .map_select(0x38)
+                .vex_we(false)
+                .vlength(Some(ymm)),
+        )
+        .modrm(true, None, None)
+        .ord(&[MODRM_REG, MODRM_RM])
+        .assemble(ins, bits)
+}
+
+// opc = opcode of load form (store form is opc + 2)
+fn ins_maskmov(ins: &Instruction, opc: u8, we: bool, bits: u8) -> Vec<u8> {
+    let api = GenAPI::new()
+        .vex(VexDetails::new().pp(0x66).map_select(0x38).vex_we(we))
+        .modrm(true, None, None);
+    if let Some(Operand::Mem(_)) = ins.dst() {
+        api.opcode(&[opc + 2])
+            .ord(&[MODRM_RM, VEX_VVVV, MODRM_REG])
+            .assemble(ins, bits)
+    } else {
+        api.opcode(&[opc])
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+            .assemble(ins, bits)
+    }
+}
+
+// dst = ModRM.reg, VSIB memory = ModRM.rm, mask = VEX.vvvv;
+// VEX.L is set if any of them (including VSIB index) is ymm register
+fn ins_gather(ins: &Instruction, opc: u8, we: bool, bits: u8) -> Vec<u8> {
+    let ymm = ins.oprs.iter().flatten().any(|o| match o {
+        Operand::Reg(r) => r.purpose() == RPurpose::F256,
+        Operand::Mem(m) => m.index().map(|r| r.purpose()) == Some(RPurpose::F256),
+        _ => false,
+    });
+    GenAPI::new()
+        .opcode(&[opc])
+        .vex(
+            VexDetails::new()
+                .pp(0x66)
+                .map_select(0x38)
+                .vex_we(we)
+                .vlength(Some(ymm)),
+        )
+        .modrm(true, None, None)
+        .ord(&[MODRM_REG, MODRM_RM, VEX_VVVV])
+        .assemble(ins, bits)
+}
+
+fn ins_lea(ins: &Instruction, bits: u8) -> (Vec<u8>, Option<Relocation<'_>>) {
+    if let Some(Operand::Mem(_)) = ins.src() {
+        return (
+            GenAPI::new()
+                .opcode(&[0x8D])
+                .modrm(true, None, None)
+                .ord(&[OpOrd::MODRM_REG, OpOrd::MODRM_RM])
+                .rex(true)
+                .assemble(ins, bits),
+            None,
+        );
+    }
     let mut base = GenAPI::new()
         .opcode(&[0x8D])
         .modrm(
@@ -7465,6 +8919,56 @@ fn ins_lea(ins: &Instruction, bits: u8) -> (Vec<u8>, Option<Relocation>) {
     )
 }
 
+fn ins_shrtjmp(ins: &Instruction, bits: u8) -> (Vec<u8>, Option<Relocation<'_>>) {
+    let (symbol, addend) = match ins.dst().unwrap() {
+        Operand::SymbolRef(s) => (s, 0),
+        Operand::SymbolRefExt(s) => (&s.symbol, s.addend),
+        _ => invalid(35),
+    };
+    let mut opc = Vec::with_capacity(3);
+    // jecxz uses ecx only with 32-bit address size
+    if ins.mnem == Ins::JECXZ && bits != 32 {
+        opc.push(0x67);
+    }
+    opc.push(ins.mnem.rel8_opcode().unwrap());
+    let rel = Relocation {
+        reltype: RelType::REL8,
+        symbol,
+        offset: opc.len() as u32,
+        addend: addend - 1,
+        shidx: 0,
+    };
+    opc.push(0x00);
+    (opc, Some(rel))
+}
+
+// far jmp/call with ptr16:16 (in bits 16) or ptr16:32 operand;
+// offset of symbol is always 32-bit (in bits 16 with operand-size override)
+// in 16-bit mode ptr16:16 is used (like NASM does), unless offset doesn't fit in 16 bits
+fn ins_farptr(ptr: &FarPtr, opc: u8, bits: u8) -> (Vec<u8>, Option<Relocation<'_>>) {
+    let wide = bits != 16 || ptr.offset > u16::MAX as u32;
+    let mut base = Vec::with_capacity(8);
+    if bits == 16 && wide {
+        base.push(0x66);
+    }
+    base.push(opc);
+    let offset = base.len() as u32;
+    if wide {
+        base.extend(ptr.offset.to_le_bytes());
+    } else {
+        base.extend((ptr.offset as u16).to_le_bytes());
+    }
+    base.extend(ptr.selector.to_le_bytes());
+    let rel = ptr.symbol.as_ref().map(|s| Relocation {
+        reltype: if wide { RelType::ABS32 } else { RelType::ABS16 },
+        symbol: &s.symbol,
+        offset,
+        addend: s.addend,
+        shidx: 0,
+    });
+    (base, rel)
+}
+
 // opc = opcode ONLY for rel32
 // why? because i'm too lazy to implement other rel's
 //
@@ -7475,7 +8979,7 @@ fn ins_jmplike(
     opc: [Vec<u8>; 2],
     addt: u8,
     bits: u8,
-) -> (Vec<u8>, Option<Relocation>) {
+) -> (Vec<u8>, Option<Relocation<'_>>) {
     match ins.dst().unwrap() {
         Operand::SymbolRefExt(s) => {
             let rel = Relocation {
@@ -7511,10 +9015,42 @@ fn ins_jmplike(
                 .assemble(ins, bits),
             None,
         ),
+        Operand::FarPtr(p) => ins_farptr(p, if ins.mnem == Ins::JMP { 0xEA } else { 0x9A }, bits),
         _ => invalid(0),
     }
 }
 
+// sgdt (0), sidt (1), lgdt (2), lidt (3): 0F 01 /ovr (m16&32 or m16&64)
+fn ins_systable(ins: &Instruction, ovr: u8, bits: u8) -> Vec<u8> {
+    GenAPI::new()
+        .opcode(&[0x0F, 0x01])
+        .modrm(true, Some(ovr), None)
+        .can_h66(false)
+        .rex(true)
+        .assemble(ins, bits)
+}
+
+// rdfsbase (0), rdgsbase (1), wrfsbase (2), wrgsbase (3): F3 (REX.W) 0F AE /ovr
+fn ins_fsgsbase(ins: &Instruction, ovr: u8, bits: u8) -> Vec<u8> {
+    GenAPI::new()
+        .prefix(0xF3)
+        .opcode(&[0x0F, 0xAE])
+        .modrm(true, Some(ovr), None)
+        .rex(true)
+        .assemble(ins, bits)
+}
+
+// vpcmp[u]d (W0) and vpcmp[u]q (W1): EVEX.66.0F3A opc /r ib with opmask destination
+fn ins_vpcmp(ins: &Instruction, bits: u8, opc: u8, we: bool) -> Vec<u8> {
+    GenAPI::new()
+        .opcode(&[opc])
+        .evex(EvexDetails::new().map_select(0x3A).pp(0x66).evex_we(we))
+        .modrm(true, None, None)
+        .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM])
+        .imm_atindex(3, 1)
+        .assemble(ins, bits)
+}
+
 fn ins_divmul(ins: &Instruction, ovr: u8, bits: u8) -> Vec<u8> {
     let opc = match ins.dst().unwrap().size() {
         Size::Byte => [0xF6],
@@ -7546,9 +9082,339 @@ fn ins_str(ins: &Instruction) -> Vec<u8> {
     vector
 }
 
+// x87: st(i) is encoded in the second opcode byte; st(1) is used if none was specified
+fn ins_fpu_st(ins: &Instruction, opc: [u8; 2]) -> Vec<u8> {
+    let sti = match (ins.dst(), ins.src()) {
+        (Some(Operand::Reg(r)), Some(Operand::Reg(Register::ST0)) | None) => r.to_byte(),
+        (_, Some(Operand::Reg(r))) => r.to_byte(),
+        _ => 1,
+    };
+    vec![opc[0], opc[1] + sti]
+}
+
+// x87: memory operand, where opcode and ModRM.reg depend on its size
+fn ins_fpu_mem(ins: &Instruction, bits: u8, forms: &[(Size, u8, u8)]) -> Vec<u8> {
+    let size = ins.size();
+    let (_, opc, ext) = forms.iter().find(|f| f.0 == size).unwrap_or(&forms[0]);
+    GenAPI::new()
+        .opcode(&[*opc])
+        .modrm(true, Some(*ext), None)
+        .can_h66(false)
+        .rex(true)
+        .assemble(ins, bits)
+}
+
+fn ins_fpu(ins: &Instruction, bits: u8, forms: &[(Size, u8, u8)], opc: [u8; 2]) -> Vec<u8> {
+    if let Some(Operand::Reg(_)) | None = ins.dst() {
+        ins_fpu_st(ins, opc)
+    } else {
+        ins_fpu_mem(ins, bits, forms)
+    }
+}
+
+// FADD/FMUL/FSUB/FSUBR/FDIV/FDIVR; `ext` is ModRM.reg of m32fp/m64fp form
+fn ins_fpu_arith(ins: &Instruction, bits: u8, ext: u8) -> Vec<u8> {
+    match (ins.dst(), ins.src()) {
+        // st(i), st(0) form swaps FSUB with FSUBR and FDIV with FDIVR
+        (Some(Operand::Reg(r)), Some(Operand::Reg(Register::ST0))) if r != &Register::ST0 => {
+            let ext = if ext >= 4 { ext ^ 1 } else { ext };
+            vec![0xDC, 0xC0 + (ext << 3) + r.to_byte()]
+        }
+        _ => ins_fpu(
+            ins,
+            bits,
+            &[(Size::Dword, 0xD8, ext), (Size::Qword, 0xDC, ext)],
+            [0xD8, 0xC0 + (ext << 3)],
+        ),
+    }
+}
+
+// FIADD/FIMUL/FICOM/FICOMP/FISUB/FISUBR/FIDIV/FIDIVR
+fn ins_fpu_int(ins: &Instruction, bits: u8, ext: u8) -> Vec<u8> {
+    ins_fpu_mem(
+        ins,
+        bits,
+        &[(Size::Word, 0xDE, ext), (Size::Dword, 0xDA, ext)],
+    )
+}
+
+fn ins_fpu_stsw(ins: &Instruction, bits: u8) -> Vec<u8> {
+    if let Some(Operand::Reg(Register::AX)) = ins.dst() {
+        vec![0xDF, 0xE0]
+    } else {
+        ins_fpu_mem(ins, bits, &[(Size::Word, 0xDD, 7)])
+    }
+}
+
 // ==============================
 // Utils
 
 fn invalid(ctx: i32) -> ! {
     panic!("Unexpected thing that should not happen - code {ctx}")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pre::{lex::Lexer, par::Parser, tok::Tokenizer};
+
+    fn assemble(src: &str, opt: bool) -> Vec<u8> {
+        let toks = crate::pre::mac::expand(src, &[])
+            .0
+            .iter()
+            .map(|l| Tokenizer::tokenize_line(l))
+            .collect();
+        let mut ast = Parser::build_tree(Lexer::parse_file(toks)).expect("parsing failed");
+        crate::pre_core::post_process(&mut ast).unwrap();
+        let mut bytes = Vec::new();
+        for s in &ast.sections {
+            let code = compile_section(&s.content, bytes.len(), s.attributes.exec(), opt);
+            bytes.extend(code.unwrap().0);
+        }
+        bytes
+    }
+
+    // returns true if checker accepts source (`march` is CPU from `-march=`)
+    fn check(src: &str, march: &str) -> bool {
+        let toks = crate::pre::mac::expand(src, &[])
+            .0
+            .iter()
+            .map(|l| Tokenizer::tokenize_line(l))
+            .collect();
+        let mut ast = Parser::build_tree(Lexer::parse_file(toks)).expect("parsing failed");
+        crate::pre_core::post_process(&mut ast).unwrap();
+        crate::pre::chk::check_ast(&ast, march.parse().unwrap()).is_none()
+    }
+
+    #[test]
+    fn isa_checks() {
+        let c =
+            |cpu: &str, ins: &str| check(&format!(".bits $64\n.cpu {cpu}\n_start:\n{ins}"), "all");
+        assert!(c("x86-64-v3", "vpaddd %ymm0, %ymm1, %ymm2"));
+        assert!(!c("x86-64-v2", "vpaddd %ymm0, %ymm1, %ymm2"));
+        assert!(!c("x86-64-v2", "vfmadd132ps %xmm0, %xmm1, %xmm2"));
+        assert!(!c("x86-64", "fisttp .word (%rax)"));
+        assert!(c("x86-64", "fld .dword (%rax)"));
+        assert!(c("sse", "pavgb %mm0, %mm1"));
+        assert!(!c("sse", "pavgb %xmm0, %xmm1"));
+        // EVEX with xmm/ymm registers requires AVX512VL
+        assert!(c("avx512f", "vaddps %zmm0, %zmm1, %zmm2"));
+        assert!(!c("avx512f", "vpternlogd %xmm0, %xmm1, %xmm2, $0xFF"));
+        assert!(c("x86-64-v4", "vpternlogd %xmm0, %xmm1, %xmm2, $0xFF"));
+        assert!(c("avx512f", "kmovw %k1, %eax"));
+        assert!(!c("x86-64-v3", "kmovw %k1, %eax"));
+        // VEX.256 forms of AES and PCLMULQDQ
+        assert!(c("avx, aes", "vaesenc %xmm0, %xmm1, %xmm2"));
+        assert!(!c("avx, aes", "vaesenc %ymm0, %ymm1, %ymm2"));
+        assert!(c("avx, vaes", "vaesenc %ymm0, %ymm1, %ymm2"));
+        assert!(!c("avx, pclmul", "vpclmulqdq %ymm0, %ymm1, %ymm2, $0"));
+        assert!(c("avx, vpclmulqdq", "vpclmulqdq %ymm0, %ymm1, %ymm2, $0"));
+
+        // `-march=` applies until first `.cpu`, which applies to instructions after it
+        let ins = "vpaddd %ymm0, %ymm1, %ymm2";
+        assert!(!check(&format!("_start:\n{ins}"), "x86-64-v2"));
+        assert!(check(&format!("_start:\n.cpu all\n{ins}"), "x86-64-v2"));
+        assert!(!check(
+            &format!("_start:\n{ins}\n.cpu all\n{ins}"),
+            "x86-64-v2"
+        ));
+        assert!(!check(&format!("_start:\n.cpu x86-64-v2\n{ins}"), "all"));
+        assert!(check(
+            &format!("_start:\n.cpu x86-64-v2\n.cpu all\n{ins}"),
+            "all"
+        ));
+    }
+
+    #[test]
+    fn rip_outside_long_mode() {
+        let c = |bits: u8, ins: &str| check(&format!(".bits ${bits}\n_start:\n{ins}"), "all");
+        assert!(c(64, "mov %eax, .dword (%rip + $16)"));
+        assert!(!c(32, "mov %eax, .dword (%rip + $16)"));
+        assert!(!c(16, "lea %ax, (%rip + $16)"));
+        assert!(!c(32, "lea %eax, (%rip + @_start)"));
+        assert!(c(32, "mov %eax, .dword ($16)"));
+        assert!(c(16, "add %ax, .word ($0x7C00)"));
+    }
+
+    #[test]
+    fn opmask_instructions() {
+        let a = |ins: &str| assemble(&format!(".bits $64\n_start:\n{ins}"), false);
+        assert_eq!(a("kmovw %k1, %k2"), [0xC5, 0xF8, 0x90, 0xCA]);
+        assert_eq!(a("kmovw .word (%rax), %k7"), [0xC5, 0xF8, 0x91, 0x38]);
+        assert_eq!(a("kmovw %k3, %r9d"), [0xC4, 0xC1, 0x78, 0x92, 0xD9]);
+        assert_eq!(a("kmovw %r10d, %k4"), [0xC5, 0x78, 0x93, 0xD4]);
+        assert_eq!(
+            a("vpcmpd %k1:k2, %zmm30, .zword (%rax + $64), $4"),
+            [0x62, 0xF3, 0x0D, 0x42, 0x1F, 0x48, 0x01, 0x04]
+        );
+        assert_eq!(
+            a("vpcmpuq %k5, %zmm8, .qword (%rbx){1to8}, $6"),
+            [0x62, 0xF3, 0xBD, 0x58, 0x1E, 0x2B, 0x06]
+        );
+        let c = |ins: &str| check(&format!(".bits $64\n_start:\n{ins}"), "all");
+        assert!(c("vpcmpud %k3, %ymm1, %ymm17, $1"));
+        assert!(!c("vpcmpd %k1:k2:z, %zmm0, %zmm1, $0"));
+        assert!(!c("vpcmpd %zmm2, %zmm0, %zmm1, $0"));
+        assert!(!c("vpcmpd %k1, %zmm0, .dword (%rax){1to8}, $0"));
+        assert!(!c("kmovw %k1:k2, %k3"));
+        assert!(!c("kmovw %eax, %ebx"));
+        assert!(!c("kmovw %k1, .dword (%rax)"));
+    }
+
+    #[test]
+    fn operand_forms() {
+        use crate::{pre::chk::operand_forms, shr::ins::Mnemonic as Mnm};
+        let names = |f: &crate::pre::chk::OperandForm| -> Vec<String> {
+            f.iter().flat_map(|o| o.0.clone()).collect()
+        };
+        // far pointer can only be used outside of 64-bit mode
+        let (long, legacy) = operand_forms(Mnm::JMP).unwrap();
+        assert!(!long
+            .iter()
+            .any(|f| names(f).contains(&"ptr16:32".to_string())));
+        assert!(legacy
+            .iter()
+            .any(|f| names(f).contains(&"ptr16:32".to_string())));
+        assert!(operand_forms(Mnm::ADD).unwrap().1.is_empty());
+        assert_eq!(operand_forms(Mnm::CLC), Some((vec![vec![]], vec![])));
+        assert_eq!(
+            names(&operand_forms(Mnm::VGATHERQPS).unwrap().0[1]),
+            ["xmm", "vm64y", "xmm"]
+        );
+    }
+
+    #[test]
+    fn shortest_forms() {
+        let o = |ins: &str| assemble(&format!(".bits $64\n_start:\n{ins}"), true);
+        assert_eq!(o("add %ax, $1"), [0x66, 0x83, 0xC0, 0x01]);
+        assert_eq!(o("add .dword (%rax), $1"), [0x83, 0x00, 0x01]);
+        assert_eq!(o("sub %ecx, $-1"), [0x83, 0xE9, 0xFF]);
+        assert_eq!(
+            o("add %rbx, $-200"),
+            [0x48, 0x81, 0xC3, 0x38, 0xFF, 0xFF, 0xFF]
+        );
+        assert_eq!(o("and %rax, $0xFF"), [0x25, 0xFF, 0x00, 0x00, 0x00]);
+        assert_eq!(o("and %rax, $-2"), [0x48, 0x83, 0xE0, 0xFE]);
+        assert_eq!(o("and %r9, $1"), [0x41, 0x83, 0xE1, 0x01]);
+        assert_eq!(o("test %rax, $1"), [0xA8, 0x01]);
+        assert_eq!(o("test %rsi, $1"), [0x40, 0xF6, 0xC6, 0x01]);
+        assert_eq!(o("test %rax, $0x80"), [0xA9, 0x80, 0x00, 0x00, 0x00]);
+        let o32 = |ins: &str| assemble(&format!(".bits $32\n_start:\n{ins}"), true);
+        // esi has no low byte register outside of long mode
+        assert_eq!(o32("test %esi, $1"), [0xF7, 0xC6, 0x01, 0x00, 0x00, 0x00]);
+        assert_eq!(o32("add %ax, $0x1234"), [0x66, 0x05, 0x34, 0x12]);
+    }
+
+    #[test]
+    fn pseudo_prefixes() {
+        let p = |ins: &str| assemble(&format!(".bits $64\n_start:\n{ins}"), false);
+        assert_eq!(p("{vex} addps %xmm0, %xmm1"), [0xC5, 0xF8, 0x58, 0xC1]);
+        assert_eq!(
+            p("{vex3} vaddps %xmm0, %xmm1, %xmm2"),
+            [0xC4, 0xE1, 0x70, 0x58, 0xC2]
+        );
+        assert_eq!(p("{rex} add %eax, %ebx"), [0x40, 0x01, 0xD8]);
+        assert_eq!(p("{disp8} mov %eax, (%rax)"), [0x8B, 0x40, 0x00]);
+        assert_eq!(p("{disp32} mov %eax, (%rax)"), [0x8B, 0x80, 0, 0, 0, 0]);
+        assert_eq!(p("{load} mov %eax, %ebx"), [0x8B, 0xC3]);
+        assert_eq!(p("{store} movaps %xmm0, %xmm9"), [0x44, 0x0F, 0x29, 0xC8]);
+        assert_eq!(
+            p("{store} vmovdqa %ymm8, %ymm1"),
+            [0xC4, 0xC1, 0x7D, 0x7F, 0xC8]
+        );
+        assert_eq!(
+            p("{store} vmovaps %zmm0, %zmm1"),
+            [0x62, 0xF1, 0x7C, 0x48, 0x29, 0xC8]
+        );
+        assert_eq!(
+            p("{store} vmovdqu64 %xmm0:k1:z, %xmm17"),
+            [0x62, 0xE1, 0xFE, 0x89, 0x7F, 0xC8]
+        );
+        // EVEX displacement has to fit in compressed disp8*N (N = 64 here)
+        assert_eq!(
+            p("{disp8} vaddps %zmm0, %zmm1, .zword (%rax + $0x40)"),
+            [0x62, 0xF1, 0x74, 0x48, 0x58, 0x40, 0x01]
+        );
+        let c = |ins: &str| check(&format!(".bits $64\n_start:\n{ins}"), "all");
+        assert!(c("{disp8} vaddps %zmm0, %zmm1, .zword (%rax + $0x40)"));
+        assert!(!c("{disp8} vaddps %zmm0, %zmm1, .zword (%rax + $0x41)"));
+        assert!(!c("{disp8} vaddps %zmm0, %zmm1, .zword (%rax + $0x4000)"));
+        // `{rex}` is rejected if instruction is encoded without REX prefix
+        assert!(c("{rex} add %eax, %ebx"));
+        assert!(c("{rex} push %rax"));
+        assert!(!c("{rex} jmp @_start"));
+        assert!(!c("{rex} ret"));
+        assert!(!c("{rex} nop"));
+        assert!(!c("{rex} mov %rax, $1"));
+    }
+
+    #[test]
+    fn far_pointers() {
+        let f = |bits: u8, ins: &str| {
+            let src = format!(".bits ${bits}\n_start:\n{ins}\nentry:\nretf");
+            let toks = src.lines().map(Tokenizer::tokenize_line).collect();
+            let ast = Parser::build_tree(Lexer::parse_file(toks)).unwrap();
+            let (bytes, rels, _) = compile_section(&ast.sections[0].content, 0, true, false)
+                .map(|(b, r, o)| (b, r.iter().map(|r| (r.offset, r.reltype)).collect(), o))
+                .unwrap();
+            (bytes, rels)
+        };
+        // ptr16:16 with 16-bit relocation (like NASM)
+        assert_eq!(
+            f(16, "jmp .far $0x08:@entry"),
+            (
+                vec![0xEA, 0x00, 0x00, 0x08, 0x00, 0xCB],
+                vec![(1, RelType::ABS16)]
+            )
+        );
+        // offset that doesn't fit in 16 bits needs ptr16:32
+        assert_eq!(
+            f(16, "call .far $0x18:$0x12345678").0[..8],
+            [0x66, 0x9A, 0x78, 0x56, 0x34, 0x12, 0x18, 0x00]
+        );
+        assert_eq!(f(32, "jmp .far $0x08:@entry").1, vec![(1, RelType::ABS32)]);
+    }
+
+    #[test]
+    fn memory_symbols_16() {
+        let m = |ins: &str| {
+            let src = format!(".bits $16\n_start:\n{ins}\nx:\nretf");
+            let toks = src.lines().map(Tokenizer::tokenize_line).collect();
+            let ast = Parser::build_tree(Lexer::parse_file(toks)).unwrap();
+            let sec = compile_section(&ast.sections[0].content, 0, true, false).unwrap();
+            let rels: Vec<_> = sec.1.iter().map(|r| (r.offset, r.reltype)).collect();
+            (sec.0, rels)
+        };
+        // symbol-only memory uses rm = 110 with disp16
+        assert_eq!(
+            m("mov %ax, .word (@x)"),
+            (vec![0x8B, 0x06, 0, 0, 0xCB], vec![(2, RelType::ABS16)])
+        );
+        assert_eq!(
+            m("mov .word (@x), %bx"),
+            (vec![0x89, 0x1E, 0, 0, 0xCB], vec![(2, RelType::ABS16)])
+        );
+        // 32-bit registers need 0x67 and disp32
+        assert_eq!(
+            m("mov %ax, .word (%ebx + @x)"),
+            (
+                vec![0x67, 0x8B, 0x83, 0, 0, 0, 0, 0xCB],
+                vec![(3, RelType::ABS32)]
+            )
+        );
+    }
+
+    #[test]
+    fn shortest_corpus() {
+        let (mut def, mut opt) = (0, 0);
+        for f in std::fs::read_dir("tests/rasm").unwrap() {
+            let path = f.unwrap().path();
+            let src = std::fs::read_to_string(&path).unwrap();
+            let (d, o) = (assemble(&src, false).len(), assemble(&src, true).len());
+            assert!(o <= d, "{path:?}: -O made {o} bytes out of {d}");
+            def += d;
+            opt += o;
+        }
+        assert!(opt < def);
+    }
+}
@@ -3,49 +3,238 @@
 // made by matissoss
 // licensed under MPL 2.0
 
+use crate::core::{
+    api::{GenAPI, Tuple},
+    evex,
+};
 use crate::shr::{
-    ast::{Instruction, Operand as Op},
+    ast::Instruction,
+    mem::{self, Mem},
+    pseudo::Pseudo,
     reg::Register,
-    segment::Segment,
 };
 
-pub fn gen_disp_ins(ins: &Instruction) -> Option<Vec<u8>> {
-    if let Some(dst) = ins.dst() {
-        if let Some(disp) = gen_disp(dst) {
-            return Some(disp);
-        }
+// n is scale of compressed displacement (disp8*N) or 1 if instruction doesn't use it
+pub fn gen_disp_ins(ins: &Instruction, n: u8, bits: u8) -> Option<Vec<u8>> {
+    let (offs, sz) = disp_ins(ins, n, bits)?;
+    Some(offs[..sz].to_vec())
+}
+
+// displacement of instruction's memory operand with `{disp8}`/`{disp32}` applied
+pub fn disp_ins(ins: &Instruction, n: u8, bits: u8) -> Option<([u8; 4], usize)> {
+    let m = ins.get_mem()?;
+    let dsp = disp(m, n, bits);
+    // RIP-relative, index-only and offset-only memory have fixed displacement size
+    if m.base() == Some(Register::RIP)
+        || m.get_flag(mem::INDEX_ONLY).unwrap_or(false)
+        || m.get_flag(mem::OFFSET_ONLY).unwrap_or(false)
+    {
+        return dsp;
     }
-    if let Some(src) = ins.src() {
-        if let Some(disp) = gen_disp(src) {
-            return Some(disp);
+    match dsp {
+        // disp32 is never scaled (disp8*N)
+        _ if ins.pseudo(Pseudo::Disp32) && !m.is_addr16(bits) => {
+            Some((m.offset().unwrap_or(0).to_le_bytes(), 4))
         }
+        None if ins.pseudo(Pseudo::Disp8) => Some(([0; 4], 1)),
+        dsp => dsp,
     }
-    None
 }
 
-pub fn gen_disp(op: &Op) -> Option<Vec<u8>> {
-    match op {
-        Op::Mem(m)
-        | Op::Segment(Segment {
-            segment: _,
-            address: m,
-        }) => {
-            if let Some((offs, sz)) = m.offset_x86() {
-                if sz == 1 {
-                    Some(vec![offs[0]])
+// returns displacement and its size (1 = disp8, 2 = disp16, 4 = disp32)
+pub fn disp(m: &Mem, n: u8, bits: u8) -> Option<([u8; 4], usize)> {
+    if m.is_addr16(bits) {
+        return disp16(m);
+    }
+    let (offs, sz) = if let Some(o) = m.offset_x86() {
+        o
+    } else {
+        // rbp/r13 base always needs displacement (mod = 00 and base = 101 means no base)
+        return match m.base() {
+            Some(r) if r != Register::RIP && r.to_byte() == 0b101 => {
+                if m.get_flag(mem::INDEX_ONLY).unwrap_or(false) {
+                    Some(([0; 4], 4))
                 } else {
-                    Some(offs.to_vec())
+                    Some(([0; 4], 1))
                 }
+            }
+            _ => None,
+        };
+    };
+    if n <= 1 || m.base() == Some(Register::RIP) || m.get_flag(mem::INDEX_ONLY).unwrap_or(false) {
+        return Some((offs, sz));
+    }
+    // disp8*N: disp8 is only used if displacement is multiple of N
+    let off = m.offset().unwrap();
+    if off % n as i32 == 0 {
+        if let Ok(d) = i8::try_from(off / n as i32) {
+            return Some(([d as u8, 0, 0, 0], 1));
+        }
+    }
+    Some((off.to_le_bytes(), 4))
+}
+
+// 16-bit addressing uses disp8 or disp16 (offset-only memory always uses disp16)
+fn disp16(m: &Mem) -> Option<([u8; 4], usize)> {
+    match m.offset_x86() {
+        Some((offs, _)) if m.get_flag(mem::OFFSET_ONLY).unwrap_or(false) => Some((offs, 2)),
+        Some(o) => Some(o),
+        // bp without index always needs displacement (mod = 00 and rm = 110 means no base)
+        None if m.rm16() == Some(0b110) => Some(([0; 4], 1)),
+        None => None,
+    }
+}
+
+// returns index of displacement in encoded instruction
+pub fn disp_idx(bytes: &[u8], bits: u8) -> Option<usize> {
+    let (mut idx, addr16) = modrm_idx(bytes, bits)?;
+    let modrm = *bytes.get(idx)?;
+    idx += 1;
+    if !addr16 && modrm >> 6 != 0b11 && modrm & 0b111 == 0b100 {
+        idx += 1;
+    }
+    Some(idx)
+}
+
+// returns length of legacy prefixes in encoded instruction
+pub fn prefix_len(bytes: &[u8], bits: u8) -> usize {
+    prefixes(bytes, bits).0
+}
+
+// returns index of ModRM in encoded instruction and if it uses 16-bit addressing
+pub fn modrm_idx(bytes: &[u8], bits: u8) -> Option<(usize, bool)> {
+    let (mut idx, addr16) = prefixes(bytes, bits);
+    if bits == 64 {
+        if let Some(0x40..=0x4F) = bytes.get(idx) {
+            idx += 1;
+        }
+    }
+    // skip opcode (with VEX/EVEX prefix)
+    idx += match (bytes.get(idx)?, bytes.get(idx + 1)?) {
+        (0xC4, _) => 4,
+        (0xC5, _) => 3,
+        (0x62, _) => 5,
+        (0x0F, 0x38 | 0x3A) => 3,
+        (0x0F, _) => 2,
+        _ => 1,
+    };
+    Some((idx, addr16))
+}
+
+// skips legacy prefixes
+fn prefixes(bytes: &[u8], bits: u8) -> (usize, bool) {
+    let mut idx = 0;
+    // 16-bit addressing has no SIB byte (0x67 switches between 16-bit and 32-bit one)
+    let mut addr16 = bits == 16;
+    while let Some(
+        b @ (0x26 | 0x2E | 0x36 | 0x3E | 0x64 | 0x65 | 0x66 | 0x67 | 0xF0 | 0xF2 | 0xF3 | 0x9B),
+    ) = bytes.get(idx)
+    {
+        if *b == 0x67 && bits != 64 {
+            addr16 = !addr16;
+        }
+        idx += 1;
+    }
+    (idx, addr16)
+}
+
+// N for EVEX compressed displacement (disp8*N); 1 if instruction doesn't use EVEX
+pub fn disp8n(ins: &Instruction, ctx: &GenAPI) -> u8 {
+    let tuple = if let Some(t) = ctx.get_evex_tuple() {
+        t
+    } else {
+        return 1;
+    };
+    let mem = if let Some(m) = ins.get_mem() {
+        m
+    } else {
+        return 1;
+    };
+    let bcst = mem.bcst().is_some();
+    let vl = 16 << evex::vlength(ins, ctx);
+    let elem = if ctx.get_vex_we().unwrap_or(false) {
+        8
+    } else {
+        4
+    };
+    match tuple {
+        Tuple::Full => {
+            if bcst {
+                elem
             } else {
-                if let (Some(Register::RBP | Register::BP | Register::EBP), Some(_)) =
-                    (m.base(), m.index())
-                {
-                    Some(vec![0; 4])
-                } else {
-                    None
-                }
+                vl
             }
         }
-        _ => None,
+        Tuple::Half => {
+            if bcst {
+                elem
+            } else {
+                vl / 2
+            }
+        }
+        Tuple::FullMem => vl,
+        Tuple::HalfMem => vl / 2,
+        Tuple::QuarterMem => vl / 4,
+        Tuple::EighthMem => vl / 8,
+        Tuple::Tuple1Scalar => match u8::from(mem.size().unwrap_or_default()) {
+            0 => elem,
+            s => s,
+        },
+        Tuple::Tuple2 => elem * 2,
+        Tuple::Tuple4 => elem * 4,
+        Tuple::Tuple8 => elem * 8,
+        Tuple::Mem128 => 16,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shr::size::Size;
+    #[test]
+    fn disp8n_test() {
+        let mut mem = Mem::new("%rax + $64", Size::Zword).unwrap();
+        assert_eq!(disp(&mem, 1, 64), Some(([64, 0, 0, 0], 1)));
+        assert_eq!(disp(&mem, 64, 64), Some(([1, 0, 0, 0], 1)));
+        assert_eq!(disp(&mem, 16, 64), Some(([4, 0, 0, 0], 1)));
+        mem.set_offset(-8192);
+        assert_eq!(disp(&mem, 64, 64), Some(([0x80, 0, 0, 0], 1)));
+        mem.set_offset(8);
+        assert_eq!(disp(&mem, 64, 64), Some(([8, 0, 0, 0], 4)));
+        mem.set_offset(8192);
+        assert_eq!(disp(&mem, 64, 64), Some((8192i32.to_le_bytes(), 4)));
+    }
+    #[test]
+    fn disp16_test() {
+        let mem = Mem::new("%bp", Size::Word).unwrap();
+        assert_eq!(disp(&mem, 1, 16), Some(([0; 4], 1)));
+        let mem = Mem::new("%bp + %si", Size::Word).unwrap();
+        assert_eq!(disp(&mem, 1, 16), None);
+        let mem = Mem::new("%bx + $0x1234", Size::Word).unwrap();
+        assert_eq!(disp(&mem, 1, 32), Some(([0x34, 0x12, 0, 0], 2)));
+        let mem = Mem::new("$0x10", Size::Word).unwrap();
+        assert_eq!(disp(&mem, 1, 16), Some(([0x10, 0, 0, 0], 2)));
+        assert_eq!(disp(&mem, 1, 32), Some(([0x10, 0, 0, 0], 4)));
+    }
+    #[test]
+    fn disp_idx_test() {
+        // mov rax, [rip + 0]
+        assert_eq!(disp_idx(&[0x48, 0x8B, 0x05, 0, 0, 0, 0], 64), Some(3));
+        // mov word [rip + 0], 0x1234
+        assert_eq!(
+            disp_idx(&[0x66, 0xC7, 0x05, 0, 0, 0, 0, 0x34, 0x12], 64),
+            Some(3)
+        );
+        // mov ax, [si + 8] (16-bit addressing has no SIB)
+        assert_eq!(disp_idx(&[0x8B, 0x44, 0x08], 16), Some(2));
+        // mov ax, [esp + 8]
+        assert_eq!(disp_idx(&[0x67, 0x8B, 0x44, 0x24, 0x08], 16), Some(4));
+        // vaddps ymm0, ymm1, [rip + 0]
+        assert_eq!(disp_idx(&[0xC5, 0xF4, 0x58, 0x05, 0, 0, 0, 0], 64), Some(4));
+        // pshufb xmm0, [rax + rcx + 8]
+        assert_eq!(
+            disp_idx(&[0x66, 0x0F, 0x38, 0x00, 0x44, 0x08, 0x08], 64),
+            Some(6)
+        );
     }
 }
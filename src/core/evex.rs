@@ -0,0 +1,292 @@
+// rasmx86_64 - src/core/evex.rs
+// -----------------------------
+// made by matissoss
+// licensed under MPL 2.0
+
+use crate::core::api;
+use crate::shr::{
+    ast::{IVariant, Instruction, Operand},
+    size::Size,
+};
+
+const EVEX: u8 = 0x62;
+
+// EVEX prefix layout (4 bytes):
+//  0x62
+//  P0: R X B R' 0 m m m
+//  P1: W v v v v 1 p p
+//  P2: z L'L   b V'a a a
+// R, X, B, R', V' and vvvv are stored inverted (like in VEX)
+pub fn evex(ins: &Instruction, ctx: &api::GenAPI) -> Option<Vec<u8>> {
+    let [mut modrm_rm, mut modrm_reg, mut evex_opr] = ctx.get_ord_oprs(ins);
+
+    if let (None, None, None) = (modrm_reg, modrm_rm, evex_opr) {
+        modrm_reg = ins.src2();
+        modrm_rm = ins.dst();
+        evex_opr = ins.src();
+    }
+
+    let pp = ctx.get_pp().unwrap();
+    let map_select = ctx.get_map_select().unwrap();
+    let evex_we = ctx.get_vex_we().unwrap();
+
+    let mut vlength = vlength(ins, ctx);
+
+    // EVEX.b means broadcast for memory operand and static rounding/SAE for
+    // reg-only instructions (then EVEX.L'L is used as rounding control)
+    let mut bcst = false;
+    if let Some(rnd) = ins.rounding {
+        bcst = true;
+        vlength = rnd.rc();
+    } else if let Some(m) = modrm_rm.and_then(|o| o.get_mem()) {
+        bcst = m.bcst().is_some();
+    }
+
+    let (b, x) = ext_rm(modrm_rm);
+    let (r, r1) = ext_reg(modrm_reg);
+    let (vvvv, v1) = ext_vvvv(evex_opr);
+
+    let (z, aaa) = if let Some(m) = ins.mask {
+        (m.zeroing, m.aaa())
+    } else {
+        (false, 0b000)
+    };
+
+    Some(vec![
+        EVEX,
+        p0(r, x, b, r1, map_select),
+        p1(evex_we, vvvv, pp),
+        p2(z, vlength, bcst, v1, aaa),
+    ])
+}
+
+#[inline(always)]
+const fn p0(r: bool, x: bool, b: bool, r1: bool, mm: u8) -> u8 {
+    (!r as u8) << 7 | (!x as u8) << 6 | (!b as u8) << 5 | (!r1 as u8) << 4 | (mm & 0b111)
+}
+
+#[inline(always)]
+const fn p1(w: bool, vvvv: u8, pp: u8) -> u8 {
+    (w as u8) << 7 | (!vvvv & 0b1111) << 3 | 0b100 | (pp & 0b11)
+}
+
+#[inline(always)]
+const fn p2(z: bool, vlength: u8, b: bool, v1: bool, aaa: u8) -> u8 {
+    (z as u8) << 7 | (vlength & 0b11) << 5 | (b as u8) << 4 | (!v1 as u8) << 3 | (aaa & 0b111)
+}
+
+// EVEX.L'L
+pub fn vlength(ins: &Instruction, ctx: &api::GenAPI) -> u8 {
+    if let Some(l) = ctx.get_evex_vlength() {
+        l
+    } else {
+        gen_vlength(ins)
+    }
+}
+
+fn gen_vlength(ins: &Instruction) -> u8 {
+    let mut vlength = match ins.which_variant() {
+        IVariant::ZMM => 0b10,
+        IVariant::YMM => 0b01,
+        _ => 0b00,
+    };
+    // memory destination may not have size specified
+    for o in ins.oprs.iter().flatten() {
+        if let Operand::Reg(r) = o {
+            match r.size() {
+                Size::Zword => vlength = vlength.max(0b10),
+                Size::Yword => vlength = vlength.max(0b01),
+                _ => {}
+            }
+        }
+    }
+    vlength
+}
+
+// (EVEX.B, EVEX.X)
+// if modrm.rm is register, EVEX.X extends it to registers 16-31
+fn ext_rm(op: Option<&Operand>) -> (bool, bool) {
+    match op {
+        Some(Operand::Reg(r)) => (r.needs_rex(), r.needs_evex()),
+        Some(Operand::Mem(m)) => m.needs_rex(),
+        _ => (false, false),
+    }
+}
+
+// (EVEX.R, EVEX.R')
+fn ext_reg(op: Option<&Operand>) -> (bool, bool) {
+    match op {
+        Some(Operand::Reg(r)) => (r.needs_rex(), r.needs_evex()),
+        _ => (false, false),
+    }
+}
+
+// (EVEX.vvvv, EVEX.V')
+fn ext_vvvv(op: Option<&Operand>) -> (u8, bool) {
+    match op {
+        Some(Operand::Reg(r)) => ((r.needs_rex() as u8) << 3 | r.to_byte(), r.needs_evex()),
+        _ => (0b0000, false),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::api::{EvexDetails, GenAPI, OpOrd::*};
+    use crate::shr::{booltable::BoolTable8, ins::Mnemonic, mask::Mask, reg::Register};
+    #[test]
+    fn evex_payload() {
+        assert_eq!(p0(false, false, false, false, 0b01), 0b1111_0001);
+        assert_eq!(p0(true, true, true, true, 0b11), 0b0000_0011);
+        assert_eq!(p1(false, 0b0010, 0b00), 0b0110_1100);
+        assert_eq!(p1(true, 0b1111, 0b01), 0b1000_0101);
+        assert_eq!(p2(false, 0b00, false, false, 0b000), 0b0000_1000);
+        assert_eq!(p2(true, 0b10, true, true, 0b001), 0b1101_0001);
+    }
+    #[test]
+    fn evex_ins() {
+        let ins = Instruction {
+            mnem: Mnemonic::VADDPS,
+            addt: None,
+            oprs: [
+                Some(Operand::Reg(Register::XMM1)),
+                Some(Operand::Reg(Register::XMM2)),
+                Some(Operand::Reg(Register::XMM3)),
+                None,
+                None,
+            ],
+            line: 0,
+            mask: None,
+            rounding: None,
+            symbol: None,
+            pseudo: BoolTable8::new(),
+            cpu: None,
+        };
+        let api = GenAPI::new()
+            .opcode(&[0x58])
+            .evex(EvexDetails::new().map_select(0x0F).pp(0).evex_we(false))
+            .modrm(true, None, None)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM]);
+        assert_eq!(api.get_evex_vlength(), None);
+        assert_eq!(
+            api.assemble(&ins, 64),
+            vec![0x62, 0xF1, 0x6C, 0x08, 0x58, 0xCB]
+        );
+        let ins = Instruction {
+            mnem: Mnemonic::VADDPD,
+            addt: None,
+            oprs: [
+                Some(Operand::Reg(Register::YMM9)),
+                Some(Operand::Reg(Register::YMM2)),
+                Some(Operand::Reg(Register::YMM12)),
+                None,
+                None,
+            ],
+            line: 0,
+            mask: None,
+            rounding: None,
+            symbol: None,
+            pseudo: BoolTable8::new(),
+            cpu: None,
+        };
+        let api = GenAPI::new()
+            .opcode(&[0x58])
+            .evex(EvexDetails::new().map_select(0x0F).pp(0x66).evex_we(true))
+            .modrm(true, None, None)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM]);
+        assert_eq!(
+            api.assemble(&ins, 64),
+            vec![0x62, 0x51, 0xED, 0x28, 0x58, 0xCC]
+        );
+    }
+    #[test]
+    fn evex_mask() {
+        let mut ins = Instruction {
+            mnem: Mnemonic::VADDPS,
+            addt: None,
+            oprs: [
+                Some(Operand::Reg(Register::XMM1)),
+                Some(Operand::Reg(Register::XMM2)),
+                Some(Operand::Reg(Register::XMM3)),
+                None,
+                None,
+            ],
+            line: 0,
+            mask: Some(Mask::new(Register::K1, true)),
+            rounding: None,
+            symbol: None,
+            pseudo: BoolTable8::new(),
+            cpu: None,
+        };
+        let api = GenAPI::new()
+            .opcode(&[0x58])
+            .evex(EvexDetails::new().map_select(0x0F).pp(0).evex_we(false))
+            .modrm(true, None, None)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM]);
+        assert_eq!(
+            api.assemble(&ins, 64),
+            vec![0x62, 0xF1, 0x6C, 0x89, 0x58, 0xCB]
+        );
+        ins.mask = Some(Mask::new(Register::K7, false));
+        assert_eq!(
+            api.assemble(&ins, 64),
+            vec![0x62, 0xF1, 0x6C, 0x0F, 0x58, 0xCB]
+        );
+    }
+    #[test]
+    fn evex_zmm() {
+        let ins = Instruction {
+            mnem: Mnemonic::VADDPD,
+            addt: None,
+            oprs: [
+                Some(Operand::Reg(Register::ZMM8)),
+                Some(Operand::Reg(Register::ZMM17)),
+                Some(Operand::Reg(Register::ZMM31)),
+                None,
+                None,
+            ],
+            line: 0,
+            mask: None,
+            rounding: None,
+            symbol: None,
+            pseudo: BoolTable8::new(),
+            cpu: None,
+        };
+        assert!(ins.needs_evex());
+        let api = GenAPI::new()
+            .opcode(&[0x58])
+            .evex(EvexDetails::new().map_select(0x0F).pp(0x66).evex_we(true))
+            .modrm(true, None, None)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM]);
+        assert_eq!(
+            api.assemble(&ins, 64),
+            vec![0x62, 0x11, 0xF5, 0x40, 0x58, 0xC7]
+        );
+        let ins = Instruction {
+            mnem: Mnemonic::VADDPS,
+            addt: None,
+            oprs: [
+                Some(Operand::Reg(Register::XMM16)),
+                Some(Operand::Reg(Register::XMM1)),
+                Some(Operand::Reg(Register::XMM2)),
+                None,
+                None,
+            ],
+            line: 0,
+            mask: Some(Mask::new(Register::K1, true)),
+            rounding: None,
+            symbol: None,
+            pseudo: BoolTable8::new(),
+            cpu: None,
+        };
+        let api = GenAPI::new()
+            .opcode(&[0x58])
+            .evex(EvexDetails::new().map_select(0x0F).pp(0).evex_we(false))
+            .modrm(true, None, None)
+            .ord(&[MODRM_REG, VEX_VVVV, MODRM_RM]);
+        assert_eq!(
+            api.assemble(&ins, 64),
+            vec![0x62, 0xE1, 0x74, 0x89, 0x58, 0xC2]
+        );
+    }
+}
@@ -3,13 +3,15 @@
 // made by matissoss
 // licensed under MPL 2.0
 
-use crate::core::api;
+use crate::core::{api, disp};
 use crate::shr::{
     ast::{Instruction, Operand},
+    mem,
+    reg::Register,
     segment::Segment,
 };
 
-pub fn modrm(ins: &Instruction, ctx: &api::GenAPI) -> u8 {
+pub fn modrm(ins: &Instruction, ctx: &api::GenAPI, bits: u8) -> u8 {
     let [mut dst, mut src, _] = ctx.get_ord_oprs(ins);
 
     if let (None, None) = (dst, src) {
@@ -19,7 +21,14 @@ pub fn modrm(ins: &Instruction, ctx: &api::GenAPI) -> u8 {
 
     let (mut reg, mut rm) = ctx.get_modrm().deserialize();
     let mut mod_ = if let Some(m) = ins.get_mem() {
-        if let Some((_, sz)) = m.offset_x86() {
+        // 16-bit offset-only (rm = 110): mod = 00 and disp16,
+        // RIP-relative (rm = 101) and index-only (SIB.base = 101): mod = 00 and disp32
+        if (m.is_addr16(bits) && m.get_flag(mem::OFFSET_ONLY).unwrap_or(false))
+            || m.base() == Some(Register::RIP)
+            || m.get_flag(mem::INDEX_ONLY).unwrap_or(false)
+        {
+            0b00
+        } else if let Some((_, sz)) = disp::disp_ins(ins, disp::disp8n(ins, ctx), bits) {
             if sz == 1 {
                 0b01
             } else {
@@ -41,7 +50,13 @@ pub fn modrm(ins: &Instruction, ctx: &api::GenAPI) -> u8 {
     }
 
     if rm.is_none() {
-        rm = if ins.uses_sib() {
+        rm = if let Some(m) = ins.get_mem().filter(|m| m.is_addr16(bits)) {
+            if m.get_flag(mem::OFFSET_ONLY).unwrap_or(false) {
+                Some(0b110)
+            } else {
+                m.rm16()
+            }
+        } else if ins.uses_sib() {
             Some(0b100)
         } else {
             Some(gen_rmreg(&dst))
@@ -6,12 +6,12 @@
 use crate::shr::{
     ast::{Instruction, Operand},
     ins::Mnemonic as Mnm,
-    reg::Purpose as RPurpose,
+    reg::{Purpose as RPurpose, Register},
     size::Size,
 };
 
 fn needs_rex(ins: &Instruction) -> bool {
-    if matches!(ins.mnem, Mnm::CMPXCHG16B) {
+    if matches!(ins.mnem, Mnm::CMPXCHG16B | Mnm::FXSAVE64 | Mnm::FXRSTOR64) {
         return true;
     }
     let (size_d, size_s) = match (ins.dst(), ins.src()) {
@@ -20,6 +20,7 @@ fn needs_rex(ins: &Instruction) -> bool {
         (None, Some(s)) => (Size::Unknown, s.size()),
         _ => (Size::Unknown, Size::Unknown),
     };
+    #[allow(clippy::collapsible_match)]
     match (ins.dst(), ins.src()) {
         (Some(Operand::Reg(r)), Some(Operand::Reg(r1))) => {
             if r.needs_rex() || r1.needs_rex() {
@@ -38,6 +39,11 @@ fn needs_rex(ins: &Instruction) -> bool {
         }
         _ => {}
     };
+    if let Some(m) = ins.get_mem() {
+        if m.needs_rex() != (false, false) {
+            return true;
+        }
+    }
     match (size_d, size_s) {
         (Size::Qword, Size::Qword) | (Size::Qword, _) | (_, Size::Qword) => {}
         _ => return false,
@@ -60,7 +66,9 @@ fn needs_rex(ins: &Instruction) -> bool {
         | Mnm::SHRD
         | Mnm::SMSW
         | Mnm::WRFSBASE
-        | Mnm::WRGSBASE => true,
+        | Mnm::WRGSBASE
+        | Mnm::RDFSBASE
+        | Mnm::RDGSBASE => true,
 
         Mnm::ROL
         | Mnm::RDRAND
@@ -110,9 +118,12 @@ fn needs_rex(ins: &Instruction) -> bool {
         | Mnm::CMOVNLE
         | Mnm::CMOVNGE
         | Mnm::MOVZX
+        | Mnm::MOVSX
+        | Mnm::MOVSXD
         | Mnm::MOVDIRI
         | Mnm::MOVBE
         | Mnm::LZCNT
+        | Mnm::TZCNT
         | Mnm::LSL
         | Mnm::CMOVNAE => true,
         Mnm::MOVMSKPD => true,
@@ -123,8 +134,9 @@ fn needs_rex(ins: &Instruction) -> bool {
         Mnm::PEXTRW | Mnm::PEXTRQ => true,
         Mnm::POPCNT => true,
         Mnm::EXTRACTPS => true,
+        Mnm::MOVABS => true,
         Mnm::MOV => {
-            if let (Some(Operand::Reg(_)), Some(Operand::Reg(_)))
+            if let (Some(Operand::Reg(_)), Some(Operand::Reg(_) | Operand::Imm(_)))
             | (Some(Operand::Mem(_) | Operand::Segment(_)), _)
             | (_, Some(Operand::Mem(_) | Operand::Segment(_))) = (ins.dst(), ins.src())
             {
@@ -177,15 +189,24 @@ fn needs_rex(ins: &Instruction) -> bool {
     }
 }
 
+// sil, dil, bpl and spl need REX prefix, but are not extended (REX.R/REX.B) registers
+fn is_ext(reg: &Register) -> bool {
+    reg.needs_rex()
+        && !matches!(
+            reg,
+            Register::SIL | Register::DIL | Register::BPL | Register::SPL
+        )
+}
+
 fn get_wb(op: Option<&Operand>) -> bool {
     match op {
-        Some(Operand::Reg(reg) | Operand::CtrReg(reg) | Operand::DbgReg(reg)) => reg.needs_rex(),
+        Some(Operand::Reg(reg) | Operand::CtrReg(reg) | Operand::DbgReg(reg)) => is_ext(reg),
         _ => false,
     }
 }
 
 fn fix_rev(r: &mut bool, ins: &Instruction) {
-    #[allow(clippy::single_match)]
+    #[allow(clippy::single_match, clippy::collapsible_match)]
     match ins.dst() {
         Some(Operand::Reg(reg)) => {
             if reg.purpose() == RPurpose::F128 {
@@ -217,9 +238,10 @@ fn calc_rex(ins: &Instruction, modrm_reg_is_dst: bool) -> u8 {
     let mut modrm_reg_is_dst = modrm_reg_is_dst;
     fix_rev(&mut modrm_reg_is_dst, ins);
 
-    let w = (!(ins.uses_cr() || ins.uses_dr() || ins.mnem.defaults_to_64bit())
-        && (sized == Size::Qword || sizes == Size::Qword))
-        || matches!(ins.mnem, Mnm::CMPXCHG16B);
+    let w =
+        (!(ins.uses_cr() || ins.uses_dr() || ins.mnem.defaults_to_64bit() || ins.mnem.is_x87())
+            && (sized == Size::Qword || sizes == Size::Qword))
+            || matches!(ins.mnem, Mnm::CMPXCHG16B | Mnm::FXSAVE64 | Mnm::FXRSTOR64);
     let mut r = if !modrm_reg_is_dst { wbs } else { wbd };
     let mut b = if !modrm_reg_is_dst { wbd } else { wbs };
     let mut x = false;
@@ -232,7 +254,7 @@ fn calc_rex(ins: &Instruction, modrm_reg_is_dst: bool) -> u8 {
         _ => {}
     }
     if let Some(Operand::Reg(reg)) = ins.dst() {
-        if reg.needs_rex() {
+        if is_ext(reg) {
             if modrm_reg_is_dst {
                 r = true;
             } else {
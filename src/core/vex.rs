@@ -4,7 +4,10 @@
 // licensed under MPL 2.0
 
 use crate::core::api;
-use crate::shr::ast::{IVariant, Instruction, Operand};
+use crate::shr::{
+    ast::{IVariant, Instruction, Operand},
+    pseudo::Pseudo,
+};
 
 const TWO_BYTE_PFX: u8 = 0xC5;
 const THREE_BYTE_PFX: u8 = 0xC4;
@@ -39,7 +42,11 @@ pub fn vex(ins: &Instruction, ctx: &api::GenAPI) -> Option<Vec<u8>> {
     let vex_b = needs_vex3(modrm_rm);
     let vex_r = needs_vex3(modrm_reg).0;
 
-    if (vex_b.0 || vex_b.1) || (map_select == 0b00011 || map_select == 0b00010) || vex_we {
+    if (vex_b.0 || vex_b.1)
+        || (map_select == 0b00011 || map_select == 0b00010)
+        || vex_we
+        || ins.pseudo(Pseudo::Vex3)
+    {
         Some(vec![
             THREE_BYTE_PFX,
             (((!vex_r) as u8) << 7
@@ -56,6 +63,7 @@ pub fn vex(ins: &Instruction, ctx: &api::GenAPI) -> Option<Vec<u8>> {
 }
 fn needs_vex3(op: Option<&Operand>) -> (bool, bool) {
     if let Some(op) = op {
+        #[allow(clippy::collapsible_match)]
         match op {
             Operand::Reg(r) => {
                 if r.needs_rex() {
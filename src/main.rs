@@ -15,7 +15,9 @@ use std::{
     fs::{File, OpenOptions},
     io::Write,
     path::PathBuf,
-    process, time,
+    process,
+    str::FromStr,
+    time,
 };
 
 // local imports go here
@@ -33,6 +35,7 @@ use pre::par::Parser;
 use pre::tok::Tokenizer;
 use shr::ast::AST;
 use shr::error;
+use shr::isa::{Cpu, Extension};
 
 // rasmx86_64 helper utilities
 pub mod cli;
@@ -132,11 +135,53 @@ fn main() {
     }
 }
 
+// defines from `-D NAME=value` (or `-D=NAME=value`) flags used by conditional assembly;
+// define without value is set to 1
+fn defines() -> Vec<(String, String)> {
+    let mut defs = Vec::new();
+    let mut args = CLI.args.iter();
+    while let Some(a) = args.next() {
+        let def = match a.strip_prefix("-D") {
+            Some("") => args.next().map(|s| s.as_str()),
+            Some(d) => Some(d.strip_prefix('=').unwrap_or(d)),
+            None => None,
+        };
+        if let Some(d) = def {
+            let (name, val) = d.split_once('=').unwrap_or((d, "1"));
+            defs.push((name.to_string(), val.to_string()));
+        }
+    }
+    defs
+}
+
+// target CPU selected with `-march=` (every extension is allowed by default)
+fn march() -> Cpu {
+    if let Some(m) = CLI.get_kv_arg("-march") {
+        match Cpu::from_str(m) {
+            Ok(cpu) => cpu,
+            Err(e) => {
+                eprintln!("{e}");
+                CLI.exit("main.rs", "march", "Invalid target CPU in -march= flag!", 1);
+            }
+        }
+    } else {
+        Cpu::all()
+    }
+}
+
 fn parse_file(inpath: &PathBuf) -> AST {
     if let Ok(true) = fs::exists(inpath) {
         if let Ok(buf) = fs::read_to_string(inpath) {
+            let (lines, map, errs) = pre::mac::expand(&buf, &defines());
+            error::set_line_map(inpath, map);
+            if !errs.is_empty() {
+                for err in errs {
+                    error::print_error(err, inpath);
+                }
+                process::exit(1);
+            }
             let mut tokenized_file = Vec::new();
-            for line in buf.lines() {
+            for line in &lines {
                 tokenized_file.push(Tokenizer::tokenize_line(line));
             }
 
@@ -150,7 +195,7 @@ fn parse_file(inpath: &PathBuf) -> AST {
                     }
                     if conf::FAST_MODE {
                         return ast;
-                    } else if let Some(errs) = pre::chk::check_ast(&ast) {
+                    } else if let Some(errs) = pre::chk::check_ast(&ast, march()) {
                         let mut error_count: usize = 0;
                         for (name, errors) in errs {
                             eprintln!(
@@ -257,30 +302,43 @@ fn assemble_file(mut ast: AST, outpath: &PathBuf, form: &str) {
         for label in &mut section.content {
             label.shidx = idx;
         }
-        for label in &section.content {
-            let mut code = comp::compile_label(label, to_write.len());
-            let label_symbol = Symbol {
+        let code = comp::compile_section(
+            &section.content,
+            to_write.len(),
+            section.attributes.exec(),
+            CLI.has_arg("-O"),
+        );
+        if let Err(why) = code {
+            error::print_error(why, &ast.file);
+            process::exit(1);
+        }
+        let (code, code_relocs, layout) = code.unwrap();
+        for (label, (offset, size)) in section.content.iter().zip(layout) {
+            symbols.push(Symbol {
                 name: &label.name,
-                offset: to_write.len() as u32 - section.offset,
-                size: code.0.len() as u32,
+                offset,
+                size,
                 sindex: idx as u16 + 1,
                 visibility: label.visibility,
                 stype: SymbolType::Func,
                 is_extern: false,
-            };
-            for reloc in &mut code.1 {
-                reloc.shidx = idx as u16;
-                reloc.offset += to_write.len() as u32;
-            }
-            relocs.extend(code.1);
-            to_write.extend(code.0);
-            symbols.push(label_symbol);
+            });
+        }
+        for mut reloc in code_relocs {
+            reloc.shidx = idx as u16;
+            reloc.offset += to_write.len() as u32;
+            relocs.push(reloc);
         }
+        to_write.extend(code);
         section.size = (to_write.len() - prev_len) as u32;
         sections.push(section);
     }
     match form {
         "bin" => {
+            // in flat binary symbols are addressed from start of file
+            for s in &mut symbols {
+                s.offset += sections[s.sindex as usize - 1].offset;
+            }
             if let Err(err) = shr::reloc::relocate_addresses(&mut to_write, relocs, &symbols) {
                 eprintln!("{err}");
                 CLI.exit(
@@ -325,31 +383,137 @@ fn assemble_file(mut ast: AST, outpath: &PathBuf, form: &str) {
 }
 
 use crate::shr::ins::Mnemonic;
+use pre::chk;
+// `supported-instructions [--ext=EXT,...] [--json]`: mnemonics grouped by extension
+// with operand forms taken from checker (pre/chk.rs)
 fn print_supported_instructions() {
-    let ins_count = Mnemonic::__LAST as u16;
-    println!("This version of RASM supports {} mnemonics!", ins_count - 1);
-    println!("Here's a list of all of them:");
-    for idx in (2..ins_count).step_by(3) {
-        let ins2 = unsafe { std::mem::transmute::<u16, Mnemonic>(idx - 2).to_string() };
-        let ins1 = unsafe { std::mem::transmute::<u16, Mnemonic>(idx - 1).to_string() };
-        let ins0 = unsafe { std::mem::transmute::<u16, Mnemonic>(idx).to_string() };
-        println!(
-            "{ins2}{}{ins1}{}{ins0}",
-            " ".repeat(conf::LINE_WIDTH - ins2.len()),
-            " ".repeat(conf::LINE_WIDTH - ins1.len()),
-        )
+    let exts = if let Some(e) = CLI.get_kv_arg("--ext") {
+        let mut exts = Vec::new();
+        for e in e.split(',') {
+            match Extension::from_str(e.trim()) {
+                Ok(e) => exts.push(e),
+                Err(_) => CLI.exit(
+                    "main.rs",
+                    "print_supported_instructions",
+                    &format!("Unknown extension `{e}`"),
+                    1,
+                ),
+            }
+        }
+        exts
+    } else {
+        Extension::ALL.to_vec()
+    };
+    let mut groups = Vec::new();
+    for e in exts {
+        let mnems: Vec<Mnemonic> = mnemonics().filter(|m| m.extension() == e).collect();
+        if !mnems.is_empty() {
+            groups.push((e, mnems));
+        }
     }
-    let mut mod_ = ins_count % 3;
-    while mod_ != 0 {
-        let ins0 = unsafe { std::mem::transmute::<u16, Mnemonic>(ins_count - mod_).to_string() };
-        if mod_ - 1 == 0 {
-            print!("{ins0}")
-        } else {
-            print!("{ins0}{}", " ".repeat(conf::LINE_WIDTH - ins0.len()));
+
+    if CLI.has_arg("--json") {
+        let mut entries = Vec::new();
+        for (e, mnems) in &groups {
+            for m in mnems {
+                let (forms, legacy) = if let Some(forms) = chk::operand_forms(*m) {
+                    forms
+                } else {
+                    entries.push(format!(
+                        "{{\"mnemonic\":\"{}\",\"extension\":\"{}\",\"forms\":null,\"legacy_forms\":null}}",
+                        m.to_string(),
+                        e.to_string()
+                    ));
+                    continue;
+                };
+                entries.push(format!(
+                    "{{\"mnemonic\":\"{}\",\"extension\":\"{}\",\"forms\":[{}],\"legacy_forms\":[{}]}}",
+                    m.to_string(),
+                    e.to_string(),
+                    form_json(&forms),
+                    form_json(&legacy)
+                ));
+            }
         }
-        mod_ -= 1;
+        println!("[{}]", entries.join(",\n"));
+        return;
     }
+
+    let count: usize = groups.iter().map(|g| g.1.len()).sum();
+    println!("This version of RASM supports {count} mnemonics!");
+    for (e, mnems) in groups {
+        println!(
+            "\n{} ({} {}):",
+            e.to_string(),
+            mnems.len(),
+            if mnems.len() == 1 {
+                "mnemonic"
+            } else {
+                "mnemonics"
+            }
+        );
+        for m in mnems {
+            let name = m.to_string();
+            let pad = " ".repeat(conf::LINE_WIDTH.saturating_sub(name.len()));
+            match chk::operand_forms(m) {
+                Some((forms, legacy)) => {
+                    for f in forms {
+                        println!("    {name}{pad}{}", form_string(&f));
+                    }
+                    for f in legacy {
+                        println!("    {name}{pad}{} (16/32-bit mode)", form_string(&f));
+                    }
+                }
+                None if m.prefix_byte().is_some() => println!("    {name}{pad}(prefix)"),
+                None => println!("    {name}{pad}(not supported by checker yet)"),
+            }
+        }
+    }
+}
+
+// `r64/m64, [imm8]` (optional operands are in brackets)
+fn form_string(form: &chk::OperandForm) -> String {
+    if form.is_empty() {
+        return String::from("(no operands)");
+    }
+    let oprs: Vec<String> = form
+        .iter()
+        .map(|(types, optional)| {
+            if *optional {
+                format!("[{}]", types.join("/"))
+            } else {
+                types.join("/")
+            }
+        })
+        .collect();
+    oprs.join(", ")
+}
+
+// `[[{"types":["r64","m64"],"optional":false}],...]`
+fn form_json(forms: &[chk::OperandForm]) -> String {
+    let forms: Vec<String> = forms
+        .iter()
+        .map(|f| {
+            let oprs: Vec<String> = f
+                .iter()
+                .map(|(types, optional)| {
+                    let types: Vec<String> = types.iter().map(|t| format!("\"{t}\"")).collect();
+                    format!(
+                        "{{\"types\":[{}],\"optional\":{optional}}}",
+                        types.join(",")
+                    )
+                })
+                .collect();
+            format!("[{}]", oprs.join(","))
+        })
+        .collect();
+    forms.join(",")
 }
+
+fn mnemonics() -> impl Iterator<Item = Mnemonic> {
+    (0..Mnemonic::__LAST as u16).map(|idx| unsafe { std::mem::transmute::<u16, Mnemonic>(idx) })
+}
+
 fn print_supported_instructions_raw() {
     for idx in 0..Mnemonic::__LAST as u16 {
         if idx + 1 == Mnemonic::__LAST as u16 {
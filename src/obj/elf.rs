@@ -281,6 +281,13 @@ fn make_elf<'a>(
     elf.code = code;
     let reloc_symbol_off = sections.len() as u32 + 2;
     for reloc in relocs {
+        // there is no 64-bit relocation in 32-bit ELF
+        if !is_64bit && reloc.reltype == RelType::ABS64 {
+            return Err(Error::msg(format!(
+                "Symbol \"{}\" needs 64-bit relocation, which is not supported by elf32 format",
+                reloc.symbol
+            )));
+        }
         if let Some(symbol) = find_index(reloc, symbols) {
             elf.push_reloc(
                 &TmpRelocation {
@@ -406,11 +413,16 @@ fn sym_collect(symb: ElfSymbol, is_64bit: bool) -> Vec<u8> {
     b
 }
 
+// local symbols are placed before global ones (see Elf::push_symbols)
 fn find_index<'a>(reloc: &'a Relocation<'a>, symbols: &'a [Symbol<'a>]) -> Option<usize> {
-    for (idx, s) in symbols.iter().enumerate() {
+    let mut lidx = 0;
+    let mut gidx = symbols.iter().filter(|s| !s.is_global()).count();
+    for s in symbols {
+        let idx = if s.is_global() { &mut gidx } else { &mut lidx };
         if s.name == reloc.symbol {
-            return Some(idx);
+            return Some(*idx);
         }
+        *idx += 1;
     }
     None
 }
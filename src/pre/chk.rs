@@ -3,30 +3,107 @@
 // made by matissoss
 // licensed under MPL 2.0
 
-use crate::core::rex::gen_rex;
+use std::cell::RefCell;
+
+use crate::core::{comp, disp, rex::gen_rex};
 use crate::shr::{
-    ast::{Instruction, Operand, AST},
+    ast::{IVariant, Instruction, Operand, AST},
     atype::*,
+    booltable::BoolTable8,
     error::RASMError,
     ins::Mnemonic as Mnm,
+    isa::{Cpu, Extension},
+    mem::{self, OFFSET_ONLY},
+    pseudo::Pseudo,
     reg::{Purpose as RPurpose, Register},
+    reloc::RelType,
     size::Size,
 };
 
 use AType::*;
 
-pub fn check_ast(file: &AST) -> Option<Vec<(String, Vec<RASMError>)>> {
+// operand form: names of allowed types of every operand (and if operand is optional)
+pub type OperandForm = Vec<(Vec<String>, bool)>;
+
+thread_local! {
+    // if set, operand tables are recorded here instead of being checked
+    static FORMS: RefCell<Option<Vec<OperandForm>>> = const { RefCell::new(None) };
+}
+
+// operand forms of mnemonic, taken from operand tables used by checker
+// (used in `supported-instructions`): forms valid in 64-bit mode and forms
+// that are only valid in 16/32-bit mode; None if mnemonic has no table
+pub fn operand_forms(mnem: Mnm) -> Option<(Vec<OperandForm>, Vec<OperandForm>)> {
+    let ins = Instruction {
+        mnem,
+        addt: None,
+        oprs: [None, None, None, None, None],
+        line: 0,
+        mask: None,
+        rounding: None,
+        symbol: None,
+        pseudo: BoolTable8::new(),
+        cpu: None,
+    };
+    let record = |chk: fn(&Instruction) -> Option<RASMError>| {
+        // operand table can't describe VSIB memory
+        if mnem.is_gather() {
+            return gather_forms(mnem);
+        }
+        FORMS.with(|f| *f.borrow_mut() = Some(Vec::new()));
+        chk(&ins);
+        FORMS.with(|f| f.borrow_mut().take()).unwrap_or_default()
+    };
+    let long = record(check_ins64bit);
+    let legacy: Vec<OperandForm> = record(check_ins32bit)
+        .into_iter()
+        .filter(|f| !long.iter().any(|l| covers(l, f)))
+        .collect();
+    if long.is_empty() && legacy.is_empty() {
+        None
+    } else {
+        Some((long, legacy))
+    }
+}
+
+// true if every operand of `form` is allowed by `by`
+fn covers(by: &OperandForm, form: &OperandForm) -> bool {
+    by.len() == form.len()
+        && by
+            .iter()
+            .zip(form)
+            .all(|(b, f)| b.1 == f.1 && f.0.iter().all(|t| b.0.contains(t)))
+}
+
+// returns true if operand table was recorded (so instruction should not be checked)
+fn record_forms(ops: &[(&[AType], Optional)]) -> bool {
+    FORMS.with(|f| {
+        if let Some(forms) = f.borrow_mut().as_mut() {
+            forms.push(
+                ops.iter()
+                    .map(|o| {
+                        let types = o.0.iter().map(|t| t.short_name()).collect();
+                        (types, o.1 == Optional::Optional)
+                    })
+                    .collect(),
+            );
+            true
+        } else {
+            false
+        }
+    })
+}
+
+// `cpu` is target CPU selected with `-march=` (used for instructions before first `.cpu` directive)
+pub fn check_ast(file: &AST, cpu: Cpu) -> Option<Vec<(String, Vec<RASMError>)>> {
     let mut errors: Vec<(String, Vec<RASMError>)> = Vec::new();
 
     for section in &file.sections {
         for label in &section.content {
-            let chk_ins: fn(&Instruction) -> Option<RASMError> = match label.bits {
-                64 => check_ins64bit,
-                _ => check_ins32bit,
-            };
             let mut errs = Vec::new();
             for inst in &label.inst {
-                if let Some(mut err) = chk_ins(inst) {
+                let cpu = inst.cpu.unwrap_or(cpu);
+                if let Some(mut err) = check_ins(inst, label.bits).or_else(|| isa_chk(inst, cpu)) {
                     err.set_line(inst.line);
                     errs.push(err);
                 }
@@ -44,6 +121,25 @@ pub fn check_ast(file: &AST) -> Option<Vec<(String, Vec<RASMError>)>> {
     }
 }
 
+pub fn check_ins(inst: &Instruction, bits: u8) -> Option<RASMError> {
+    let chk_ins: fn(&Instruction) -> Option<RASMError> = match bits {
+        64 => check_ins64bit,
+        _ => check_ins32bit,
+    };
+    mask_chk(inst)
+        .or_else(|| evex_chk(inst))
+        .or_else(|| decor_chk(inst))
+        .or_else(|| prefix_chk(inst))
+        .or_else(|| pseudo_chk(inst, bits))
+        .or_else(|| rel8_chk(inst))
+        .or_else(|| memsym_chk(inst))
+        .or_else(|| vsib_chk(inst))
+        .or_else(|| rip_chk(inst, bits))
+        .or_else(|| addr16_chk(inst, bits))
+        .or_else(|| chk_ins(inst))
+        .or_else(|| encoding_chk(inst, bits))
+}
+
 fn check_ins32bit(ins: &Instruction) -> Option<RASMError> {
     use Mnm::*;
     if gen_rex(ins, false).is_some() {
@@ -52,6 +148,19 @@ fn check_ins32bit(ins: &Instruction) -> Option<RASMError> {
             Some("Instruction needs rex prefix, which is forbidden in protected/compat. mode (bits 32)"),
         ));
     }
+    for o in ins.oprs.iter().flatten() {
+        if let Operand::Reg(r) = o {
+            if r.needs_evex() {
+                return Some(RASMError::no_tip(
+                    Some(ins.line),
+                    Some(format!(
+                        "Register {} can only be used in long mode (bits 64)",
+                        r.to_string()
+                    )),
+                ));
+            }
+        }
+    }
     match ins.mnem {
         Mnm::CMOVA
         | Mnm::CMOVB
@@ -210,7 +319,10 @@ fn check_ins32bit(ins: &Instruction) -> Option<RASMError> {
         ),
         Mnm::JMP | Mnm::CALL => ot_chk(
             ins,
-            &[(&[AType::Symbol, R32, R16, M32, M16], Optional::Needed)],
+            &[(
+                &[AType::Symbol, R32, R16, M32, M16, AType::FarPtr],
+                Optional::Needed,
+            )],
             &[],
             &[],
         ),
@@ -218,7 +330,7 @@ fn check_ins32bit(ins: &Instruction) -> Option<RASMError> {
             ins,
             &[
                 (&[R16, R32], Optional::Needed),
-                (&[AType::Symbol], Optional::Needed),
+                (&[AType::Symbol, MA, M16, M32, M64], Optional::Needed),
             ],
             &[],
             &[],
@@ -244,7 +356,7 @@ fn check_ins32bit(ins: &Instruction) -> Option<RASMError> {
             &[],
             &[],
         ),
-        CBW | CMC | CWD | CDQ | CQO | CLD | CLI => ot_chk(ins, &[], &[], &[]),
+        CBW | CMC | CWD | CDQ | CQO | CLC | CLD | CLI => ot_chk(ins, &[], &[], &[]),
         AAD | AAM => ot_chk(ins, &[(&[I8], Optional::Optional)], &[], &[]),
 
         // 32-bit only
@@ -309,7 +421,7 @@ fn check_ins32bit(ins: &Instruction) -> Option<RASMError> {
             &[],
             &[],
         ),
-        MOVZX => ot_chk(
+        MOVZX | MOVSX => ot_chk(
             ins,
             &[
                 (&[R16, R32], Optional::Needed),
@@ -334,7 +446,7 @@ fn check_ins32bit(ins: &Instruction) -> Option<RASMError> {
             &[(RA, RA), (MA, MA)],
             &[],
         ),
-        LZCNT => ot_chk(
+        LZCNT | TZCNT => ot_chk(
             ins,
             &[
                 (&[R16, R32], Optional::Needed),
@@ -348,6 +460,9 @@ fn check_ins32bit(ins: &Instruction) -> Option<RASMError> {
         RDPID => ot_chk(ins, &[(&[R32], Optional::Needed)], &[], &[]),
         RDSSPD => ot_chk(ins, &[(&[R32], Optional::Needed)], &[], &[]),
         RDSSPQ => ot_chk(ins, &[(&[R64], Optional::Needed)], &[], &[]),
+        LGDT | LIDT | SGDT | SIDT => ot_chk(ins, &[(&[MA, M32, M64], Optional::Needed)], &[], &[]),
+        PUSHA | PUSHAD | POPA | POPAD => ot_chk(ins, &[], &[], &[]),
+        JECXZ => ot_chk(ins, &[(&[AType::Symbol], Optional::Needed)], &[], &[]),
         RORX => ot_chk(
             ins,
             &[
@@ -403,7 +518,16 @@ fn check_ins32bit(ins: &Instruction) -> Option<RASMError> {
             &[(M32, M32), (R32, R32), (MMX, MMX), (XMM, MMX), (MMX, XMM)],
             &[],
         ),
-        Mnm::MOVQ | MOVSTRQ | SCASQ | STOSQ => Some(RASMError::no_tip(
+        Mnm::MOVQ
+        | MOVSTRQ
+        | SCASQ
+        | STOSQ
+        | JRCXZ
+        | SWAPGS
+        | RDFSBASE
+        | RDGSBASE
+        | WRFSBASE
+        | WRGSBASE => Some(RASMError::no_tip(
             Some(ins.line),
             Some("Instruction unsupported in 32-bit mode"),
         )),
@@ -510,6 +634,33 @@ fn check_ins64bit(ins: &Instruction) -> Option<RASMError> {
             ],
             &[],
         ),
+        MOVABS => ot_chk(
+            ins,
+            &[
+                (&[R8, R16, R32, R64, M8, M16, M32, M64], Optional::Needed),
+                (
+                    &[
+                        R8,
+                        R16,
+                        R32,
+                        R64,
+                        M8,
+                        M16,
+                        M32,
+                        M64,
+                        I8,
+                        I16,
+                        I32,
+                        I64,
+                        AType::Symbol,
+                    ],
+                    Optional::Needed,
+                ),
+            ],
+            &[(MA, MA), (RA, RA)],
+            &[],
+        )
+        .or_else(|| movabs_chk(ins)),
         XCHG => ot_chk(
             ins,
             &[
@@ -575,7 +726,7 @@ fn check_ins64bit(ins: &Instruction) -> Option<RASMError> {
             ins,
             &[
                 (&[R16, R32, R64], Optional::Needed),
-                (&[AType::Symbol], Optional::Needed),
+                (&[AType::Symbol, MA, M16, M32, M64], Optional::Needed),
             ],
             &[],
             &[],
@@ -601,7 +752,7 @@ fn check_ins64bit(ins: &Instruction) -> Option<RASMError> {
             &[],
             &[],
         ),
-        CBW | CMC | CWD | CDQ | CQO | CLD | CLI => ot_chk(ins, &[], &[], &[]),
+        CBW | CMC | CWD | CDQ | CQO | CLC | CLD | CLI => ot_chk(ins, &[], &[], &[]),
 
         // part b
         CWDE | CDQE | CLAC | CLTS | CLUI => ot_chk(ins, &[], &[], &[]),
@@ -661,7 +812,7 @@ fn check_ins64bit(ins: &Instruction) -> Option<RASMError> {
             &[],
             &[],
         ),
-        MOVZX => ot_chk(
+        MOVZX | MOVSX => ot_chk(
             ins,
             &[
                 (&[R16, R32, R64], Optional::Needed),
@@ -670,6 +821,12 @@ fn check_ins64bit(ins: &Instruction) -> Option<RASMError> {
             &[(R16, M16), (R16, R16)],
             &[],
         ),
+        MOVSXD => ot_chk(
+            ins,
+            &[(&[R64], Optional::Needed), (&[R32, M32], Optional::Needed)],
+            &[],
+            &[],
+        ),
         MOVSTRB | MOVSTRW | MOVSTRD | MOVSTRQ => ot_chk(ins, &[], &[], &[]),
         MOVDIRI => ot_chk(
             ins,
@@ -689,7 +846,7 @@ fn check_ins64bit(ins: &Instruction) -> Option<RASMError> {
             &[(RA, RA), (MA, MA)],
             &[],
         ),
-        LZCNT => ot_chk(
+        LZCNT | TZCNT => ot_chk(
             ins,
             &[
                 (&[R16, R32, R64], Optional::Needed),
@@ -725,7 +882,16 @@ fn check_ins64bit(ins: &Instruction) -> Option<RASMError> {
             &[],
         ),
         RDPID => ot_chk(ins, &[(&[R64], Optional::Needed)], &[], &[]),
-        WRFSBASE | WRGSBASE => ot_chk(ins, &[(&[R32, R64], Optional::Needed)], &[], &[]),
+        WRFSBASE | WRGSBASE | RDFSBASE | RDGSBASE => {
+            ot_chk(ins, &[(&[R32, R64], Optional::Needed)], &[], &[])
+        }
+        LGDT | LIDT | SGDT | SIDT => ot_chk(ins, &[(&[MA, M80], Optional::Needed)], &[], &[]),
+        SWAPGS => ot_chk(ins, &[], &[], &[]),
+        JECXZ | JRCXZ => ot_chk(ins, &[(&[AType::Symbol], Optional::Needed)], &[], &[]),
+        PUSHA | PUSHAD | POPA | POPAD => Some(RASMError::no_tip(
+            Some(ins.line),
+            Some("Instruction unsupported in 64-bit mode"),
+        )),
         SHLD | SHRD => ot_chk(
             ins,
             &[
@@ -777,6 +943,7 @@ pub fn shr_chk(ins: &Instruction) -> Option<RASMError> {
         ),
         OUTIB | OUTIW | OUTID => ot_chk(ins, &[(&[I8], Optional::Needed)], &[], &[]),
         OUTRB | OUTRW | OUTRD | OUTSB | OUTSW | OUTSD => ot_chk(ins, &[], &[], &[]),
+        RETF => ot_chk(ins, &[(&[I8, I16], Optional::Optional)], &[], &[]),
 
         SFENCE | STAC | STC | STD | STI | STOSB | STOSW | STOSD | STOSQ | STUI | SYSENTER
         | SYSEXIT | SYSRET | TESTUI | UD2 | UIRET | WAIT | FWAIT | WBINVD | WRMSR | WRPKRU => {
@@ -828,6 +995,8 @@ pub fn shr_chk(ins: &Instruction) -> Option<RASMError> {
         | Mnm::JNGE
         | Mnm::JBE
         | Mnm::JNO
+        | Mnm::JNG
+        | Mnm::JNS
         | Mnm::JNP
         | Mnm::JPO
         | Mnm::JPE
@@ -907,6 +1076,67 @@ pub fn shr_chk(ins: &Instruction) -> Option<RASMError> {
             &[],
         ),
 
+        // x87 FPU
+        F2XM1 | FABS | FCHS | FCOS | FDECSTP | FINCSTP | FLD1 | FLDL2T | FLDL2E | FLDPI
+        | FLDLG2 | FLDLN2 | FLDZ | FNOP | FPATAN | FPREM | FPREM1 | FPTAN | FRNDINT | FSCALE
+        | FSIN | FSINCOS | FSQRT | FTST | FXAM | FXTRACT | FYL2X | FYL2XP1 | FCOMPP | FUCOMPP
+        | FINIT | FNINIT | FCLEX | FNCLEX => ot_chk(ins, &[], &[], &[]),
+        FLD | FSTP => ot_chk(ins, &[(&[ST, M32, M64, M80], Optional::Needed)], &[], &[]),
+        FST => ot_chk(ins, &[(&[ST, M32, M64], Optional::Needed)], &[], &[]),
+        FILD | FISTP | FISTTP => ot_chk(ins, &[(&[M16, M32, M64], Optional::Needed)], &[], &[]),
+        FIST | FIADD | FIMUL | FICOM | FICOMP | FISUB | FISUBR | FIDIV | FIDIVR => {
+            ot_chk(ins, &[(&[M16, M32], Optional::Needed)], &[], &[])
+        }
+        FBLD | FBSTP => ot_chk(ins, &[(&[M80], Optional::Needed)], &[], &[]),
+        FADD | FMUL | FSUB | FSUBR | FDIV | FDIVR => ot_chk(
+            ins,
+            &[
+                (&[ST, M32, M64], Optional::Needed),
+                (&[ST], Optional::Optional),
+            ],
+            &[(MA, ST)],
+            &[],
+        )
+        .or_else(|| fpu_chk(ins)),
+        FADDP | FMULP | FSUBP | FSUBRP | FDIVP | FDIVRP => ot_chk(
+            ins,
+            &[
+                (&[ST], Optional::Optional),
+                (&[ExtendedRegister(Register::ST0)], Optional::Optional),
+            ],
+            &[],
+            &[],
+        ),
+        FCOM | FCOMP => ot_chk(ins, &[(&[ST, M32, M64], Optional::Optional)], &[], &[]),
+        FUCOM | FUCOMP => ot_chk(ins, &[(&[ST], Optional::Optional)], &[], &[]),
+        FXCH => ot_chk(
+            ins,
+            &[(&[ST], Optional::Optional), (&[ST], Optional::Optional)],
+            &[],
+            &[],
+        )
+        .or_else(|| fpu_chk(ins)),
+        FFREE => ot_chk(ins, &[(&[ST], Optional::Needed)], &[], &[]),
+        FCOMI | FCOMIP | FUCOMI | FUCOMIP | FCMOVB | FCMOVE | FCMOVBE | FCMOVU | FCMOVNB
+        | FCMOVNE | FCMOVNBE | FCMOVNU => ot_chk(
+            ins,
+            &[
+                (&[ExtendedRegister(Register::ST0)], Optional::Needed),
+                (&[ST], Optional::Needed),
+            ],
+            &[],
+            &[],
+        ),
+        FLDCW | FNSTCW | FSTCW => ot_chk(ins, &[(&[M16], Optional::Needed)], &[], &[]),
+        FNSTSW | FSTSW => ot_chk(
+            ins,
+            &[(&[M16, ExtendedRegister(Register::AX)], Optional::Needed)],
+            &[],
+            &[],
+        ),
+        FLDENV | FNSTENV | FSTENV | FRSTOR | FNSAVE | FSAVE | FXSAVE | FXSAVE64 | FXRSTOR
+        | FXRSTOR64 => ot_chk(ins, &[(&[MA], Optional::Needed)], &[], &[]),
+
         // #####  #####  #####
         // #      #      #
         // #####  #####  #####
@@ -1596,7 +1826,7 @@ pub fn shr_chk(ins: &Instruction) -> Option<RASMError> {
             &[(XMM, YMM), (XMM, M256), (YMM, M128), (YMM, XMM)],
             &[],
         ),
-        Mnm::VMOVAPS | Mnm::VMOVAPD | Mnm::VMOVUPS | Mnm::VMOVUPD | Mnm::VMOVDQA => ot_chk(
+        Mnm::VMOVDQA => ot_chk(
             ins,
             &[
                 (&[XMM, YMM, M128, M256], Optional::Needed),
@@ -1634,7 +1864,7 @@ pub fn shr_chk(ins: &Instruction) -> Option<RASMError> {
             &[(MA, MA, XMM), (XMM, MA, XMM), (MA, XMM, XMM)],
             &[],
         ),
-        Mnm::VPMULDQ | Mnm::VPMULLD => avx_ot_chk(
+        Mnm::VPMULDQ => avx_ot_chk(
             ins,
             &[
                 (&[XMM, YMM], Optional::Needed),
@@ -1685,13 +1915,7 @@ pub fn shr_chk(ins: &Instruction) -> Option<RASMError> {
             &[(XMM, YMM), (XMM, M256), (YMM, XMM), (YMM, M64)],
             &[],
         ),
-        Mnm::VMOVSLDUP
-        | Mnm::VPTEST
-        | Mnm::VMOVSHDUP
-        | Mnm::VRCPPS
-        | Mnm::VSQRTPS
-        | Mnm::VRSQRTPS
-        | Mnm::VSQRTPD => ot_chk(
+        Mnm::VMOVSLDUP | Mnm::VPTEST | Mnm::VMOVSHDUP | Mnm::VRCPPS | Mnm::VRSQRTPS => ot_chk(
             ins,
             &[
                 (&[XMM, YMM], Optional::Needed),
@@ -1734,22 +1958,10 @@ pub fn shr_chk(ins: &Instruction) -> Option<RASMError> {
             &[],
             &[],
         ),
-        Mnm::VADDPD
-        | Mnm::VSUBPD
-        | Mnm::VDIVPD
-        | Mnm::VMULPD
-        | Mnm::VMINPD
-        | Mnm::VMAXPD
-        | Mnm::VORPD
+        Mnm::VORPD
         | Mnm::VANDNPD
         | Mnm::VANDPD
         | Mnm::VXORPD
-        | Mnm::VADDPS
-        | Mnm::VSUBPS
-        | Mnm::VDIVPS
-        | Mnm::VMULPS
-        | Mnm::VMINPS
-        | Mnm::VMAXPS
         | Mnm::VORPS
         | Mnm::VANDNPS
         | Mnm::VANDPS
@@ -1878,17 +2090,134 @@ pub fn shr_chk(ins: &Instruction) -> Option<RASMError> {
             &[],
         ),
 
+        // AVX-512F
+        Mnm::VMOVAPS
+        | Mnm::VMOVAPD
+        | Mnm::VMOVUPS
+        | Mnm::VMOVUPD
+        | Mnm::VMOVDQA32
+        | Mnm::VMOVDQA64
+        | Mnm::VMOVDQU32
+        | Mnm::VMOVDQU64 => ot_chk(
+            ins,
+            &[
+                (&[XMM, YMM, ZMM, M128, M256, M512], Optional::Needed),
+                (&[XMM, YMM, ZMM, M128, M256, M512], Optional::Needed),
+            ],
+            &[(MA, MA)],
+            &[],
+        )
+        .or_else(|| vsize_chk(ins)),
+        Mnm::VSQRTPS | Mnm::VSQRTPD | Mnm::VPABSQ => ot_chk(
+            ins,
+            &[
+                (&[XMM, YMM, ZMM], Optional::Needed),
+                (
+                    &[XMM, YMM, ZMM, M32, M64, M128, M256, M512],
+                    Optional::Needed,
+                ),
+            ],
+            &[],
+            &[],
+        )
+        .or_else(|| vsize_chk(ins)),
+        Mnm::VADDPS
+        | Mnm::VADDPD
+        | Mnm::VSUBPS
+        | Mnm::VSUBPD
+        | Mnm::VMULPS
+        | Mnm::VMULPD
+        | Mnm::VDIVPS
+        | Mnm::VDIVPD
+        | Mnm::VMINPS
+        | Mnm::VMINPD
+        | Mnm::VMAXPS
+        | Mnm::VMAXPD
+        | Mnm::VPADDD
+        | Mnm::VPADDQ
+        | Mnm::VPSUBD
+        | Mnm::VPSUBQ
+        | Mnm::VPMULLD
+        | Mnm::VPANDD
+        | Mnm::VPANDQ
+        | Mnm::VPANDND
+        | Mnm::VPANDNQ
+        | Mnm::VPORD
+        | Mnm::VPORQ
+        | Mnm::VPXORD
+        | Mnm::VPXORQ
+        | Mnm::VPERMT2D
+        | Mnm::VPERMT2Q
+        | Mnm::VPERMT2PS
+        | Mnm::VPERMT2PD
+        | Mnm::VPERMI2D
+        | Mnm::VPERMI2Q
+        | Mnm::VPERMI2PS
+        | Mnm::VPERMI2PD
+        | Mnm::VPMAXSQ
+        | Mnm::VPMINSQ
+        | Mnm::VPMAXUQ
+        | Mnm::VPMINUQ => avx_ot_chk(
+            ins,
+            &[
+                (&[XMM, YMM, ZMM], Optional::Needed),
+                (&[XMM, YMM, ZMM], Optional::Needed),
+                (
+                    &[XMM, YMM, ZMM, M32, M64, M128, M256, M512],
+                    Optional::Needed,
+                ),
+            ],
+            &[],
+            &[],
+        )
+        .or_else(|| vsize_chk(ins)),
+        Mnm::VPTERNLOGD | Mnm::VPTERNLOGQ | Mnm::VALIGND | Mnm::VALIGNQ => avx_ot_chk(
+            ins,
+            &[
+                (&[XMM, YMM, ZMM], Optional::Needed),
+                (&[XMM, YMM, ZMM], Optional::Needed),
+                (
+                    &[XMM, YMM, ZMM, M32, M64, M128, M256, M512],
+                    Optional::Needed,
+                ),
+                (&[I8], Optional::Needed),
+            ],
+            &[],
+            &[],
+        )
+        .or_else(|| vsize_chk(ins)),
+        Mnm::VPCMPD | Mnm::VPCMPUD | Mnm::VPCMPQ | Mnm::VPCMPUQ => avx_ot_chk(
+            ins,
+            &[
+                (&[K], Optional::Needed),
+                (&[XMM, YMM, ZMM], Optional::Needed),
+                (
+                    &[XMM, YMM, ZMM, M32, M64, M128, M256, M512],
+                    Optional::Needed,
+                ),
+                (&[I8], Optional::Needed),
+            ],
+            &[],
+            &[],
+        )
+        .or_else(|| vsize_chk(ins)),
+        Mnm::KMOVW => ot_chk(
+            ins,
+            &[
+                (&[K, R32, M16], Optional::Needed),
+                (&[K, R32, M16], Optional::Needed),
+            ],
+            &[(R32, R32), (R32, M16), (M16, R32), (M16, M16)],
+            &[],
+        ),
+
         // MMX derived
         Mnm::VPOR
         | Mnm::VPAND
         | Mnm::VPXOR
         | Mnm::VPADDB
         | Mnm::VPADDW
-        | Mnm::VPADDD
-        | Mnm::VPADDQ
         | Mnm::VPSUBB
-        | Mnm::VPSUBD
-        | Mnm::VPSUBQ
         | Mnm::VPSUBW
         | Mnm::VPANDN
         | Mnm::VPSUBSW
@@ -2109,6 +2438,132 @@ pub fn shr_chk(ins: &Instruction) -> Option<RASMError> {
             &[],
             &[],
         ),
+        Mnm::VPERMD | Mnm::VPERMPS => avx_ot_chk(
+            ins,
+            &[
+                (&[YMM], Optional::Needed),
+                (&[YMM], Optional::Needed),
+                (&[YMM, M256], Optional::Needed),
+            ],
+            &[],
+            &[],
+        ),
+        Mnm::VPERMQ | Mnm::VPERMPD => avx_ot_chk(
+            ins,
+            &[
+                (&[YMM], Optional::Needed),
+                (&[YMM, M256], Optional::Needed),
+                (&[I8], Optional::Needed),
+            ],
+            &[],
+            &[],
+        ),
+        Mnm::VPBROADCASTB => avx_ot_chk_wthout(
+            ins,
+            &[
+                (&[XMM, YMM], Optional::Needed),
+                (&[XMM, M8], Optional::Needed),
+            ],
+            &[],
+            &[],
+        ),
+        Mnm::VPBROADCASTW => avx_ot_chk_wthout(
+            ins,
+            &[
+                (&[XMM, YMM], Optional::Needed),
+                (&[XMM, M16], Optional::Needed),
+            ],
+            &[],
+            &[],
+        ),
+        Mnm::VPBROADCASTD => avx_ot_chk_wthout(
+            ins,
+            &[
+                (&[XMM, YMM], Optional::Needed),
+                (&[XMM, M32], Optional::Needed),
+            ],
+            &[],
+            &[],
+        ),
+        Mnm::VPBROADCASTQ => avx_ot_chk_wthout(
+            ins,
+            &[
+                (&[XMM, YMM], Optional::Needed),
+                (&[XMM, M64], Optional::Needed),
+            ],
+            &[],
+            &[],
+        ),
+        Mnm::VBROADCASTI128 => avx_ot_chk_wthout(
+            ins,
+            &[(&[YMM], Optional::Needed), (&[M128], Optional::Needed)],
+            &[],
+            &[],
+        ),
+        Mnm::VINSERTI128 => avx_ot_chk_wthout(
+            ins,
+            &[
+                (&[YMM], Optional::Needed),
+                (&[YMM], Optional::Needed),
+                (&[XMM, M128], Optional::Needed),
+                (&[I8], Optional::Needed),
+            ],
+            &[],
+            &[],
+        ),
+        Mnm::VEXTRACTI128 => avx_ot_chk_wthout(
+            ins,
+            &[
+                (&[XMM, M128], Optional::Needed),
+                (&[YMM], Optional::Needed),
+                (&[I8], Optional::Needed),
+            ],
+            &[],
+            &[],
+        ),
+        Mnm::VPSLLVD | Mnm::VPSLLVQ | Mnm::VPSRLVD | Mnm::VPSRLVQ | Mnm::VPSRAVD => avx_ot_chk(
+            ins,
+            &[
+                (&[XMM, YMM], Optional::Needed),
+                (&[XMM, YMM], Optional::Needed),
+                (&[XMM, YMM, M128, M256], Optional::Needed),
+            ],
+            &[],
+            &[],
+        ),
+        Mnm::VPMASKMOVD | Mnm::VPMASKMOVQ | Mnm::VMASKMOVPS => avx_ot_chk(
+            ins,
+            &[
+                (&[XMM, YMM, M128, M256], Optional::Needed),
+                (&[XMM, YMM], Optional::Needed),
+                (&[XMM, YMM, M128, M256], Optional::Needed),
+            ],
+            &[
+                (XMM, XMM, XMM),
+                (YMM, YMM, YMM),
+                (M128, XMM, M128),
+                (M256, YMM, M256),
+            ],
+            &[],
+        ),
+        Mnm::VPGATHERDD
+        | Mnm::VPGATHERDQ
+        | Mnm::VPGATHERQD
+        | Mnm::VPGATHERQQ
+        | Mnm::VGATHERDPS
+        | Mnm::VGATHERDPD
+        | Mnm::VGATHERQPS
+        | Mnm::VGATHERQPD => avx_ot_chk(
+            ins,
+            &[
+                (&[XMM, YMM], Optional::Needed),
+                (&[MA, M32, M64], Optional::Needed),
+                (&[XMM, YMM], Optional::Needed),
+            ],
+            &[],
+            &[],
+        )
+        .or_else(|| gather_chk(ins)),
         // part2c
         Mnm::VPMAXSW
         | Mnm::VPMINSW
@@ -2512,16 +2967,779 @@ pub fn shr_chk(ins: &Instruction) -> Option<RASMError> {
             &[],
             &[],
         ),
+        Mnm::VCVTPH2PS => avx_ot_chk_wthout(
+            ins,
+            &[
+                (&[XMM, YMM], Optional::Needed),
+                (&[XMM, M64, M128], Optional::Needed),
+            ],
+            &[],
+            &[],
+        )
+        .or_else(|| f16c_chk(ins)),
+        Mnm::VCVTPS2PH => avx_ot_chk_wthout(
+            ins,
+            &[
+                (&[XMM, M64, M128], Optional::Needed),
+                (&[XMM, YMM], Optional::Needed),
+                (&[I8], Optional::Needed),
+            ],
+            &[],
+            &[],
+        )
+        .or_else(|| f16c_chk(ins)),
 
-        _ => Some(RASMError::no_tip(
+        LOCK | REP | REPE | REPZ | REPNE | REPNZ => Some(RASMError::with_tip(
             Some(ins.line),
-            Some("Tried to use currently unsupported instruction."),
+            Some(format!(
+                "Prefix {} was used without instruction",
+                ins.mnem.to_string()
+            )),
+            Some("Consider placing prefix before instruction it applies to (e.g. `lock add`)"),
+        )),
+        _ => Some(RASMError::no_tip(
+            Some(ins.line),
+            Some("Tried to use currently unsupported instruction."),
         )),
     }
 }
 
 // Utils
 
+fn mask_chk(ins: &Instruction) -> Option<RASMError> {
+    let mask = ins.mask?;
+    if mask.mask.purpose() != RPurpose::Mask {
+        return Some(RASMError::with_tip(
+            Some(ins.line),
+            Some(format!(
+                "Tried to use {} register as opmask",
+                mask.mask.purpose().to_string()
+            )),
+            Some("Consider using one of k1-k7 registers"),
+        ));
+    }
+    if mask.mask == Register::K0 {
+        return Some(RASMError::with_tip(
+            Some(ins.line),
+            Some("Register k0 cannot be used as opmask (it means \"no masking\")"),
+            Some("Consider using one of k1-k7 registers or removing opmask"),
+        ));
+    }
+    if mask.zeroing {
+        if let Some(true) = ins.dst().map(|d| d.is_mem()) {
+            return Some(RASMError::no_tip(
+                Some(ins.line),
+                Some("Zeroing-masking cannot be used with memory destination"),
+            ));
+        }
+        if let Some(Operand::Reg(r)) = ins.dst() {
+            if r.purpose() == RPurpose::Mask {
+                return Some(RASMError::no_tip(
+                    Some(ins.line),
+                    Some("Zeroing-masking cannot be used with opmask destination"),
+                ));
+            }
+        }
+    }
+    if !ins.mnem.is_avx512() {
+        return Some(RASMError::no_tip(
+            Some(ins.line),
+            Some(format!(
+                "Instruction {} doesn't support opmask",
+                ins.mnem.to_string()
+            )),
+        ));
+    }
+    None
+}
+
+fn evex_chk(ins: &Instruction) -> Option<RASMError> {
+    if ins.needs_evex() && !ins.mnem.is_avx512() {
+        return Some(RASMError::with_tip(
+            Some(ins.line),
+            Some(format!(
+                "Instruction {} cannot be used with zmm registers, zword memory, xmm16-31/ymm16-31 registers, broadcast or rounding",
+                ins.mnem.to_string()
+            )),
+            Some("These operands are only available for AVX-512 instructions"),
+        ));
+    }
+    None
+}
+
+// checks AVX-512 decorators: broadcast (`{1toN}`) and rounding/SAE (`{rn-sae}`)
+fn decor_chk(ins: &Instruction) -> Option<RASMError> {
+    if let Some(rnd) = ins.rounding {
+        let allowed = if rnd.is_sae() {
+            ins.mnem.allows_sae()
+        } else {
+            ins.mnem.allows_er()
+        };
+        if !allowed {
+            let tip = if ins.mnem.allows_er() {
+                Some("Consider using one of: `{rn-sae}`, `{rd-sae}`, `{ru-sae}`, `{rz-sae}`")
+            } else if ins.mnem.allows_sae() {
+                Some("Consider using `{sae}`")
+            } else {
+                None
+            };
+            return Some(RASMError::with_tip(
+                Some(ins.line),
+                Some(format!(
+                    "Instruction {} doesn't support {} decorator",
+                    ins.mnem.to_string(),
+                    rnd.to_string()
+                )),
+                tip,
+            ));
+        }
+        if ins.oprs.iter().flatten().any(|o| o.is_mem()) {
+            return Some(RASMError::no_tip(
+                Some(ins.line),
+                Some("Rounding/SAE can only be used if all operands are registers"),
+            ));
+        }
+        if ins.which_variant() != IVariant::ZMM {
+            return Some(RASMError::no_tip(
+                Some(ins.line),
+                Some("Rounding/SAE can only be used with zmm registers"),
+            ));
+        }
+    }
+    for o in ins.oprs.iter().flatten() {
+        let mem = if let Some(m) = o.get_mem() {
+            m
+        } else {
+            continue;
+        };
+        let elem = ins.mnem.bcst_size();
+        let n = if let Some(n) = mem.bcst() {
+            n
+        } else {
+            if let (Some(e), Some(sz)) = (elem, mem.size()) {
+                if matches!(
+                    (e, sz),
+                    (Size::Dword, Size::Dword) | (Size::Qword, Size::Qword)
+                ) {
+                    return Some(RASMError::with_tip(
+                        Some(ins.line),
+                        Some(format!(
+                            "Memory operand of size {e} can only be used with broadcast"
+                        )),
+                        Some("Consider adding broadcast (like `{1to16}`) after memory operand"),
+                    ));
+                }
+            }
+            continue;
+        };
+        let elem = if let Some(e) = elem {
+            e
+        } else {
+            return Some(RASMError::no_tip(
+                Some(ins.line),
+                Some(format!(
+                    "Instruction {} doesn't support broadcast",
+                    ins.mnem.to_string()
+                )),
+            ));
+        };
+        if let Some(sz) = mem.size() {
+            if !matches!(sz, Size::Any) && sz != elem {
+                return Some(RASMError::with_tip(
+                    Some(ins.line),
+                    Some(format!("Broadcasted element must be {elem}, found {sz}")),
+                    Some(format!(
+                        "Consider changing size of memory operand to {elem}"
+                    )),
+                ));
+            }
+        }
+        // comparisons into opmask take vector length from first source
+        let vec = match ins.dst() {
+            Some(Operand::Reg(r)) if r.purpose() == RPurpose::Mask => ins.src(),
+            d => d,
+        };
+        let vsz = vec.map(|d| u8::from(d.size()) as u16).unwrap_or(0);
+        let esz = u8::from(elem) as u16;
+        if esz * n as u16 != vsz {
+            return Some(RASMError::with_tip(
+                Some(ins.line),
+                Some(format!(
+                    "Broadcast {{1to{n}}} doesn't match size of destination operand"
+                )),
+                Some(format!("Consider using {{1to{}}}", vsz / esz)),
+            ));
+        }
+    }
+    None
+}
+
+// checks if all vector (and memory) operands have the same size
+fn prefix_chk(ins: &Instruction) -> Option<RASMError> {
+    let addt = ins.addt?;
+    let allowed = match addt {
+        Mnm::LOCK => ins.mnem.allows_lock(),
+        Mnm::REP => ins.mnem.allows_rep(),
+        Mnm::REPE | Mnm::REPZ | Mnm::REPNE | Mnm::REPNZ => ins.mnem.allows_repcc(),
+        _ => return None,
+    };
+    if !allowed {
+        return Some(RASMError::with_tip(
+            Some(ins.line),
+            Some(format!(
+                "Prefix {} cannot be used with {} instruction",
+                addt.to_string(),
+                ins.mnem.to_string()
+            )),
+            Some("List of instructions that can be used with LOCK and REP* prefixes can be found in docs/core.md"),
+        ));
+    }
+    if addt == Mnm::LOCK && !ins.dst().is_some_and(|d| d.is_mem()) {
+        return Some(RASMError::no_tip(
+            Some(ins.line),
+            Some("LOCK prefix can only be used with memory destination operand"),
+        ));
+    }
+    None
+}
+
+// extension that instruction belongs to with its operands
+// (like `vpaddd` with ymm registers coming with AVX2 and not AVX)
+fn isa_ext(ins: &Instruction) -> Extension {
+    let uses = |p: RPurpose| {
+        ins.oprs
+            .iter()
+            .flatten()
+            .any(|o| matches!(o, Operand::Reg(r) if r.purpose() == p))
+    };
+    match ins.mnem.extension() {
+        Extension::AVX | Extension::AVX2 if ins.needs_evex() => Extension::AVX512F,
+        // MMX (and integer SSE) instructions with xmm registers
+        Extension::SSE
+            if uses(RPurpose::F128)
+                && matches!(
+                    ins.mnem,
+                    Mnm::PAVGB
+                        | Mnm::PAVGW
+                        | Mnm::PINSRW
+                        | Mnm::PEXTRW
+                        | Mnm::PMAXSW
+                        | Mnm::PMINSW
+                        | Mnm::PMULHUW
+                ) =>
+        {
+            Extension::SSE2
+        }
+        Extension::MMX if uses(RPurpose::F128) => Extension::SSE2,
+        // integer AVX instructions with ymm registers
+        Extension::AVX if uses(RPurpose::F256) && ins.mnem.is_avx_int() => Extension::AVX2,
+        Extension::AVX
+            if matches!(ins.mnem, Mnm::VBROADCASTSS | Mnm::VBROADCASTSD)
+                && matches!(ins.src(), Some(Operand::Reg(_))) =>
+        {
+            Extension::AVX2
+        }
+        // VEX.256 forms of AES and PCLMULQDQ
+        Extension::AES
+            if uses(RPurpose::F256)
+                && matches!(
+                    ins.mnem,
+                    Mnm::VAESENC | Mnm::VAESENCLAST | Mnm::VAESDEC | Mnm::VAESDECLAST
+                ) =>
+        {
+            Extension::VAES
+        }
+        Extension::PCLMUL if uses(RPurpose::F256) => Extension::VPCLMULQDQ,
+        e => e,
+    }
+}
+
+// instruction has to be supported by target CPU (`.cpu`/`-march=`)
+fn isa_chk(ins: &Instruction, cpu: Cpu) -> Option<RASMError> {
+    let ext = isa_ext(ins);
+    let zword = ins.oprs.iter().flatten().any(|o| match o {
+        Operand::Reg(r) => r.size() == Size::Zword,
+        _ => o.get_mem().is_some_and(|m| m.size() == Some(Size::Zword)),
+    });
+    let mut required = vec![ext];
+    // VEX forms of AES, PCLMULQDQ, etc. also need AVX
+    if ins.mnem.is_avx() && !matches!(ext, Extension::Base | Extension::AVX512F) {
+        required.push(Extension::AVX);
+    }
+    // EVEX with xmm/ymm registers (opmask instructions don't have vector length)
+    if ext == Extension::AVX512F && !zword && ins.mnem.is_avx() {
+        required.push(Extension::AVX512VL);
+    }
+    let missing = required.into_iter().find(|e| !cpu.has(*e))?;
+    Some(RASMError::with_tip(
+        None,
+        Some(format!(
+            "Instruction {} requires {} extension, that is not supported by target CPU",
+            ins.mnem.to_string(),
+            missing.to_string()
+        )),
+        Some(format!(
+            "Target CPU is selected with `.cpu` directive or `-march=` flag; consider adding `{}` to it",
+            missing.to_string()
+        )),
+    ))
+}
+
+// pseudo prefixes that can only be verified on encoded instruction
+// (it is only run on instructions that passed other checks)
+fn encoding_chk(ins: &Instruction, bits: u8) -> Option<RASMError> {
+    let rex = ins.pseudo(Pseudo::Rex) && bits == 64;
+    // EVEX uses compressed displacement (disp8*N), where N depends on tuple type of instruction
+    let disp8 = ins.pseudo(Pseudo::Disp8) && ins.needs_evex();
+    if !(rex || disp8) {
+        return None;
+    }
+    let bytes = comp::compile_instruction(ins, bits, false).0;
+    // some instructions are not encoded with generic encoder, which handles `{rex}`
+    if rex && !matches!(bytes.get(disp::prefix_len(&bytes, bits)), Some(0x40..=0x4F)) {
+        return Some(RASMError::no_tip(
+            Some(ins.line),
+            Some(format!(
+                "Pseudo prefix {{rex}} is not supported by instruction {}",
+                ins.mnem.to_string()
+            )),
+        ));
+    }
+    if disp8 {
+        let (idx, _) = disp::modrm_idx(&bytes, bits)?;
+        if bytes.get(idx)? >> 6 != 0b01 {
+            return Some(RASMError::with_tip(
+                Some(ins.line),
+                Some("Displacement cannot be encoded as EVEX compressed disp8*N (required by {disp8})"),
+                Some("Displacement has to be a multiple of N (size of memory operand or broadcasted element) and fit in 8 bits after dividing it by N"),
+            ));
+        }
+    }
+    None
+}
+
+// encoding-override pseudo prefixes (like `{rex}` or `{disp8}`)
+fn pseudo_chk(ins: &Instruction, bits: u8) -> Option<RASMError> {
+    let err = |msg: &str| Some(RASMError::no_tip(Some(ins.line), Some(msg)));
+    if (ins.pseudo(Pseudo::Vex) || ins.pseudo(Pseudo::Vex3))
+        && (!ins.mnem.is_avx() || ins.needs_evex())
+    {
+        return err("Pseudo prefix {vex} can only be used with instructions that can be encoded with VEX prefix");
+    }
+    if ins.pseudo(Pseudo::Rex) {
+        if bits != 64 {
+            return err("Pseudo prefix {rex} can only be used in 64-bit mode");
+        }
+        if ins.mnem.is_avx() {
+            return err("Pseudo prefix {rex} cannot be used with VEX/EVEX instructions");
+        }
+        let high = |o: &Operand| {
+            matches!(
+                o,
+                Operand::Reg(Register::AH | Register::BH | Register::CH | Register::DH)
+            )
+        };
+        if ins.oprs.iter().flatten().any(high) {
+            return err("Pseudo prefix {rex} cannot be used with ah, bh, ch or dh registers");
+        }
+    }
+    let (disp8, disp32) = (ins.pseudo(Pseudo::Disp8), ins.pseudo(Pseudo::Disp32));
+    if disp8 || disp32 {
+        if disp8 && disp32 {
+            return err("Pseudo prefixes {disp8} and {disp32} cannot be used together");
+        }
+        let m = if let Some(m) = ins.get_mem() {
+            m
+        } else {
+            return err("Pseudo prefixes {disp8} and {disp32} require memory operand");
+        };
+        if m.base() == Some(Register::RIP)
+            || m.get_flag(mem::INDEX_ONLY).unwrap_or(false)
+            || m.get_flag(OFFSET_ONLY).unwrap_or(false)
+        {
+            return err(
+                "RIP-relative, index-only and offset-only memory have fixed displacement size",
+            );
+        }
+        if disp32 && m.is_addr16(bits) {
+            return err("Pseudo prefix {disp32} cannot be used with 16-bit addressing");
+        }
+        if disp8 && m.get_flag(mem::SYMBOL).unwrap_or(false) {
+            return err("Memory with symbol cannot use 8-bit displacement");
+        }
+        if disp8 && !ins.needs_evex() && i8::try_from(m.offset().unwrap_or(0)).is_err() {
+            return err("Displacement doesn't fit in 8 bits (required by {disp8})");
+        }
+    }
+    let (load, store) = (ins.pseudo(Pseudo::Load), ins.pseudo(Pseudo::Store));
+    if load || store {
+        if load && store {
+            return err("Pseudo prefixes {load} and {store} cannot be used together");
+        }
+        let regs = matches!(
+            (ins.dst(), ins.src()),
+            (Some(Operand::Reg(_)), Some(Operand::Reg(_)))
+        );
+        if !(regs && ins.oprs[2].is_none() && ins.mnem.allows_direction()) {
+            return Some(RASMError::with_tip(
+                Some(ins.line),
+                Some(format!(
+                    "Pseudo prefixes {{load}} and {{store}} cannot be used with this form of {} instruction",
+                    ins.mnem.to_string()
+                )),
+                Some("They can only be used with reg-reg mov, ALU instructions and SSE/AVX moves"),
+            ));
+        }
+    }
+    None
+}
+
+// movabs either loads 64-bit immediate (or address of symbol) into 64-bit register
+// or moves accumulator from/to 64-bit memory offset (moffs64)
+fn movabs_chk(ins: &Instruction) -> Option<RASMError> {
+    let (reg, mem) = match (ins.dst()?, ins.src()?) {
+        (Operand::Reg(r), Operand::Mem(m)) | (Operand::Mem(m), Operand::Reg(r)) => (r, m),
+        (Operand::Reg(r), _) => {
+            if r.size() == Size::Qword {
+                return None;
+            }
+            return Some(RASMError::no_tip(
+                Some(ins.line),
+                Some("Destination of movabs with immediate (or symbol) must be 64-bit register"),
+            ));
+        }
+        _ => {
+            return Some(RASMError::no_tip(
+                Some(ins.line),
+                Some("Movabs cannot store immediate (or symbol) into memory"),
+            ))
+        }
+    };
+    if !matches!(
+        reg,
+        Register::AL | Register::AX | Register::EAX | Register::RAX
+    ) {
+        return Some(RASMError::with_tip(
+            Some(ins.line),
+            Some("Movabs with memory offset can only be used with accumulator register"),
+            Some("Consider using al, ax, eax or rax register"),
+        ));
+    }
+    if !mem.get_flag(OFFSET_ONLY).unwrap_or(false) {
+        return Some(RASMError::with_tip(
+            Some(ins.line),
+            Some("Memory operand of movabs must only contain offset (or symbol)"),
+            Some("Consider using mov instruction instead"),
+        ));
+    }
+    match mem.size() {
+        Some(size) if size != reg.size() => Some(RASMError::no_tip(
+            Some(ins.line),
+            Some(format!(
+                "Memory operand of movabs must be {} when used with {} register",
+                reg.size(),
+                reg.to_string()
+            )),
+        )),
+        _ => None,
+    }
+}
+
+// short (rel8) references can only be used as targets of branches, that have rel8 form
+fn rel8_chk(ins: &Instruction) -> Option<RASMError> {
+    for o in ins.oprs.iter().flatten() {
+        if let Operand::SymbolRefExt(s) = o {
+            if s.reltype == RelType::REL8 && ins.mnem.rel8_opcode().is_none() {
+                return Some(RASMError::with_tip(
+                    Some(ins.line),
+                    Some(format!(
+                        "Short (rel8) reference cannot be used with {} instruction",
+                        ins.mnem.to_string()
+                    )),
+                    Some("Short references can only be used with jmp, jcc and loop* instructions"),
+                ));
+            }
+            if s.reltype != RelType::REL8 && ins.mnem.is_rel8_only() {
+                return Some(RASMError::no_tip(
+                    Some(ins.line),
+                    Some(format!(
+                        "Instruction {} can only be used with short (rel8) reference",
+                        ins.mnem.to_string()
+                    )),
+                ));
+            }
+        }
+    }
+    None
+}
+
+// symbol used as displacement of memory operand (like `(%rip + @data)` or `(%rbx + @table)`)
+fn memsym_chk(ins: &Instruction) -> Option<RASMError> {
+    let symbol = ins.symbol.as_ref()?;
+    if ins
+        .oprs
+        .iter()
+        .flatten()
+        .any(|o| matches!(o, Operand::SymbolRef(_) | Operand::SymbolRefExt(_)))
+    {
+        return Some(RASMError::no_tip(
+            Some(ins.line),
+            Some("Only one symbol can be referenced in instruction"),
+        ));
+    }
+    let mem = ins.get_mem()?;
+    if symbol.reltype == RelType::REL8 {
+        return Some(RASMError::no_tip(
+            Some(ins.line),
+            Some("Short (rel8) reference cannot be used in memory operand"),
+        ));
+    }
+    if mem.base() == Some(Register::RIP) && symbol.reltype != RelType::REL32 {
+        return Some(RASMError::no_tip(
+            Some(ins.line),
+            Some("Symbol used in RIP-relative memory operand must use relative (rel) relocation"),
+        ));
+    }
+    if mem.addrsize() == Some(Size::Word) {
+        return Some(RASMError::no_tip(
+            Some(ins.line),
+            Some(format!(
+                "Symbol `{}` cannot be used in memory operand with 16-bit registers",
+                symbol.symbol
+            )),
+        ));
+    }
+    None
+}
+
+// 16-bit addressing (like `(%bx + %si + $4)`) can only be used outside of long mode
+// and only with bx/bp as base and si/di as index
+// RIP-relative addressing only exists in 64-bit mode (elsewhere ModRM 00/101 is absolute disp32)
+fn rip_chk(ins: &Instruction, bits: u8) -> Option<RASMError> {
+    let mem = ins.get_mem()?;
+    // offset-only memory is also marked as RIP-relative (and is absolute outside of 64-bit mode)
+    if bits == 64 || mem.base() != Some(Register::RIP) || mem.get_flag(OFFSET_ONLY).unwrap_or(false)
+    {
+        return None;
+    }
+    Some(RASMError::with_tip(
+        Some(ins.line),
+        Some("RIP-relative memory can only be used in 64-bit mode"),
+        Some("Consider using absolute address or symbol instead"),
+    ))
+}
+
+fn addr16_chk(ins: &Instruction, bits: u8) -> Option<RASMError> {
+    let mem = ins.get_mem()?;
+    if !mem.is_addr16(bits) {
+        return None;
+    }
+    if bits == 64 {
+        return Some(RASMError::with_tip(
+            Some(ins.line),
+            Some("You cannot address with 16-bit registers in 64-bit mode"),
+            Some("Consider using 32-bit or 64-bit registers"),
+        ));
+    }
+    if mem.addrsize() == Some(Size::Word) && mem.rm16().is_none() {
+        return Some(RASMError::with_tip(
+            Some(ins.line),
+            Some(format!(
+                "Memory {} has invalid combination of 16-bit registers",
+                mem.to_string()
+            )),
+            Some("16-bit addressing only allows %bx or %bp as base and %si or %di as index (without scale)"),
+        ));
+    }
+    if let Some(off) = mem.offset() {
+        if !(i16::MIN as i32..=u16::MAX as i32).contains(&off) {
+            return Some(RASMError::no_tip(
+                Some(ins.line),
+                Some(format!(
+                    "Displacement {off} doesn't fit in 16-bit addressing"
+                )),
+            ));
+        }
+    }
+    None
+}
+
+// VSIB memory (with xmm/ymm index) can only be used by gathers
+fn vsib_chk(ins: &Instruction) -> Option<RASMError> {
+    let index = ins.get_mem()?.index()?;
+    if index.purpose() == RPurpose::General || ins.mnem.is_gather() {
+        return None;
+    }
+    Some(RASMError::no_tip(
+        Some(ins.line),
+        Some(format!(
+            "Instruction {} cannot use memory with xmm/ymm index (VSIB)",
+            ins.mnem.to_string()
+        )),
+    ))
+}
+
+// operand forms of gather (see gather_chk); `vm32x` is memory with xmm index
+// of 32-bit elements, `vm64y` with ymm index of 64-bit elements, etc.
+fn gather_forms(mnem: Mnm) -> Vec<OperandForm> {
+    use Mnm::*;
+    let forms = match mnem {
+        VPGATHERDD | VGATHERDPS => [["xmm", "vm32x", "xmm"], ["ymm", "vm32y", "ymm"]],
+        VPGATHERDQ | VGATHERDPD => [["xmm", "vm32x", "xmm"], ["ymm", "vm32x", "ymm"]],
+        VPGATHERQD | VGATHERQPS => [["xmm", "vm64x", "xmm"], ["xmm", "vm64y", "xmm"]],
+        _ => [["xmm", "vm64x", "xmm"], ["ymm", "vm64y", "ymm"]],
+    };
+    forms
+        .iter()
+        .map(|f| f.iter().map(|o| (vec![o.to_string()], false)).collect())
+        .collect()
+}
+
+// checks sizes of gather's operands and if destination, index and mask are different registers
+fn gather_chk(ins: &Instruction) -> Option<RASMError> {
+    use Mnm::*;
+    let (dst, mask) = match (ins.dst(), ins.src2()) {
+        (Some(Operand::Reg(d)), Some(Operand::Reg(m))) => (*d, *m),
+        _ => return None,
+    };
+    let index = match ins.get_mem().and_then(|m| m.index()) {
+        Some(r) if r.purpose() != RPurpose::General => r,
+        _ => {
+            return Some(RASMError::with_tip(
+                Some(ins.line),
+                Some(format!(
+                    "Instruction {} needs memory with xmm/ymm index (VSIB)",
+                    ins.mnem.to_string()
+                )),
+                Some("Consider using memory like: `(%rax + %xmm1 * 4)`"),
+            ))
+        }
+    };
+    let (dsz, isz, msz) = (dst.size(), index.size(), mask.size());
+    let valid = match ins.mnem {
+        // 32-bit indices and elements
+        VPGATHERDD | VGATHERDPS => dsz == isz && dsz == msz,
+        // 32-bit indices, 64-bit elements
+        VPGATHERDQ | VGATHERDPD => isz == Size::Xword && dsz == msz,
+        // 64-bit indices, 32-bit elements
+        VPGATHERQD | VGATHERQPS => dsz == Size::Xword && msz == Size::Xword,
+        // 64-bit indices and elements
+        _ => dsz == isz && dsz == msz,
+    };
+    if !valid {
+        return Some(RASMError::no_tip(
+            Some(ins.line),
+            Some(format!(
+                "Invalid combination of destination ({}), index ({}) and mask ({}) registers for {} instruction",
+                dst.to_string(),
+                index.to_string(),
+                mask.to_string(),
+                ins.mnem.to_string()
+            )),
+        ));
+    }
+    // registers are compared without their size (xmm1 and ymm1 are same register)
+    let id = |r: Register| (r.needs_rex() as u8) << 3 | r.to_byte();
+    if id(dst) == id(index) || id(dst) == id(mask) || id(index) == id(mask) {
+        return Some(RASMError::no_tip(
+            Some(ins.line),
+            Some("Destination, index and mask registers of gather must be different registers"),
+        ));
+    }
+    None
+}
+
+// half-precision operand (xmm or memory) has half of the size of single-precision operand
+// and rounding control (imm8) of vcvtps2ph uses only bits 0-2
+fn f16c_chk(ins: &Instruction) -> Option<RASMError> {
+    let (half, single) = if ins.mnem == Mnm::VCVTPH2PS {
+        (ins.src()?, ins.dst()?)
+    } else {
+        (ins.dst()?, ins.src()?)
+    };
+    let expected = if single.size() == Size::Yword {
+        Size::Xword
+    } else {
+        Size::Qword
+    };
+    if let Operand::Mem(m) = half {
+        if m.size() != Some(expected) {
+            return Some(RASMError::no_tip(
+                Some(ins.line),
+                Some(format!(
+                    "Memory operand of {} instruction must be {} when used with {} register",
+                    ins.mnem.to_string(),
+                    expected,
+                    single.size()
+                )),
+            ));
+        }
+    }
+    if let Some(Operand::Imm(n)) = ins.src2() {
+        if n.get_as_u64() > 0b111 {
+            return Some(RASMError::with_tip(
+                Some(ins.line),
+                Some("Rounding control of vcvtps2ph must be in range from 0 to 7"),
+                Some("Bits 0-1 select rounding mode and bit 2 selects MXCSR.RC instead"),
+            ));
+        }
+    }
+    None
+}
+
+// x87 instructions that take two registers need one of them to be st(0)
+fn fpu_chk(ins: &Instruction) -> Option<RASMError> {
+    if let (Some(Operand::Reg(d)), Some(Operand::Reg(s))) = (ins.dst(), ins.src()) {
+        if *d != Register::ST0 && *s != Register::ST0 {
+            return Some(RASMError::with_tip(
+                Some(ins.line),
+                Some("Neither of x87 instruction's operands is st0"),
+                Some("Consider using st0 as either destination or source operand"),
+            ));
+        }
+    }
+    None
+}
+
+fn vsize_chk(ins: &Instruction) -> Option<RASMError> {
+    let mut size = None;
+    for o in ins.oprs.iter().flatten() {
+        if let Some(m) = o.get_mem() {
+            if m.bcst().is_some() {
+                continue;
+            }
+        }
+        let sz = match o {
+            // opmask destination of comparison
+            Operand::Reg(r) if r.purpose() == RPurpose::Mask => continue,
+            Operand::Reg(_) | Operand::Mem(_) | Operand::Segment(_) => o.size(),
+            _ => continue,
+        };
+        if matches!(sz, Size::Any | Size::Unknown) {
+            continue;
+        }
+        if let Some(s) = size {
+            if !matches!(
+                (s, sz),
+                (Size::Xword, Size::Xword)
+                    | (Size::Yword, Size::Yword)
+                    | (Size::Zword, Size::Zword)
+            ) {
+                return Some(RASMError::with_tip(
+                    Some(ins.line),
+                    Some(format!(
+                        "Tried to use operands of different sizes: {s} and {sz}"
+                    )),
+                    Some(format!("Consider changing operands to be {s}")),
+                ));
+            }
+        } else {
+            size = Some(sz);
+        }
+    }
+    None
+}
+
 #[derive(PartialEq)]
 enum Optional {
     Needed,
@@ -2534,6 +3752,9 @@ fn avx_ot_chk_wthout(
     forb: &[(AType, AType, AType)],
     addt: &[Mnm],
 ) -> Option<RASMError> {
+    if record_forms(ops) {
+        return None;
+    }
     if let Some(err) = addt_chk(ins, addt) {
         return Some(err);
     }
@@ -2575,6 +3796,9 @@ fn avx_ot_chk(
     forb: &[(AType, AType, AType)],
     addt: &[Mnm],
 ) -> Option<RASMError> {
+    if record_forms(ops) {
+        return None;
+    }
     if let Some(err) = addt_chk(ins, addt) {
         return Some(err);
     }
@@ -2648,6 +3872,9 @@ fn ot_chk(
     forb: &[(AType, AType)],
     addt: &[Mnm],
 ) -> Option<RASMError> {
+    if record_forms(ops) {
+        return None;
+    }
     if let Some(err) = addt_chk(ins, addt) {
         return Some(err);
     }
@@ -2673,7 +3900,7 @@ fn ot_chk(
             }
         }
     }
-    if ops.len() == 2 {
+    if ops.len() == 2 && ins.src().is_some() {
         if let Some(err) = size_chk(ins) {
             return Some(err);
         }
@@ -2711,14 +3938,6 @@ fn forb_chk(ins: &Instruction, forb: &[(AType, AType)]) -> Option<RASMError> {
 }
 
 fn type_check(operand: &Operand, accepted: &[AType], idx: usize) -> Option<RASMError> {
-    if let Some(m) = operand.get_mem() {
-        if m.addrsize() == Some(Size::Word) {
-            return Some(RASMError::no_tip(
-                None,
-                Some("You cannot address with 16-bit registers; consider using 32-bit/64-bit (depending on bits).")
-            ));
-        }
-    }
     if find(accepted, operand.atype()) || find_ext(accepted, operand.ext_atype()) {
         None
     } else {
@@ -2872,13 +4091,15 @@ fn size_chk(ins: &Instruction) -> Option<RASMError> {
                     || g0 == RPurpose::Sgmnt
                     || g0 == RPurpose::Mmx
                     || g0 == RPurpose::F128
-                    || g0 == RPurpose::F256)
+                    || g0 == RPurpose::F256
+                    || g0 == RPurpose::Mask)
                     || (g1 == RPurpose::Dbg
                         || g1 == RPurpose::Ctrl
                         || g1 == RPurpose::Sgmnt
                         || g1 == RPurpose::Mmx
                         || g1 == RPurpose::F128
-                        || g1 == RPurpose::F256))
+                        || g1 == RPurpose::F256
+                        || g1 == RPurpose::Mask))
             {
                 None
             } else {
@@ -2900,7 +4121,8 @@ fn size_chk(ins: &Instruction) -> Option<RASMError> {
 
 fn addt_chk(ins: &Instruction, accpt_addt: &[Mnm]) -> Option<RASMError> {
     if let Some(addt) = &ins.addt {
-        if !find_bool(accpt_addt, addt) {
+        // legacy prefixes are checked in prefix_chk
+        if addt.prefix_byte().is_none() && !find_bool(accpt_addt, addt) {
             return Some(RASMError::no_tip(
                 Some(ins.line),
                 Some(format!(
@@ -2923,12 +4145,17 @@ fn find_bool(addts: &[Mnm], searched: &Mnm) -> bool {
 }
 
 fn find_ext(items: &[AType], searched: AType) -> bool {
+    // far pointer only matches itself
+    if searched == AType::FarPtr {
+        return items.contains(&AType::FarPtr);
+    }
     let (size, regprp, reg) = match searched {
         AType::Register(prp, size) => (size, Some(prp), None),
         AType::Immediate(size) => (size, None, None),
         AType::SMemory(size) | AType::Memory(size) => (size, None, None),
         AType::Symbol => (Size::Any, None, None),
         AType::ExtendedRegister(r) => (r.size(), Some(r.purpose()), Some(r)),
+        AType::FarPtr => unreachable!(),
     };
     for i in items {
         let (isize, iregprp, ireg) = match i {
@@ -2937,6 +4164,7 @@ fn find_ext(items: &[AType], searched: AType) -> bool {
             AType::SMemory(size) | AType::Memory(size) => (*size, None, None),
             AType::Symbol => (Size::Any, None, None),
             AType::ExtendedRegister(r) => (r.size(), Some(r.purpose()), Some(*r)),
+            AType::FarPtr => continue,
         };
         if let (Some(ireg), Some(reg)) = (ireg, reg) {
             if ireg == reg {
@@ -2949,6 +4177,9 @@ fn find_ext(items: &[AType], searched: AType) -> bool {
     false
 }
 fn find(items: &[AType], searched: AType) -> bool {
+    if searched == AType::FarPtr {
+        return items.contains(&AType::FarPtr);
+    }
     let (size, regprp) = match searched {
         AType::Register(prp, size) => (size, Some(prp)),
         AType::Immediate(size) => (size, None),
@@ -2962,6 +4193,7 @@ fn find(items: &[AType], searched: AType) -> bool {
             AType::Immediate(size) => (size, None),
             AType::SMemory(size) | AType::Memory(size) => (size, None),
             AType::Symbol => (&Size::Any, None),
+            AType::FarPtr => continue,
             _ => (&Size::Unknown, None),
         };
         if isize == &size && regprp.as_ref() == iregprp {
@@ -0,0 +1,317 @@
+// rasmx86_64 - src/pre/cond.rs
+// ----------------------------
+// made by matissoss
+// licensed under MPL 2.0
+
+use crate::{
+    conf::{COMMENT_S, PREFIX_KWD},
+    shr::{error::RASMError, math::MathematicalEvaluation as MathEval, num::Number},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum State {
+    // current branch is assembled
+    Active,
+    // no branch was assembled yet
+    Pending,
+    // branch was already assembled (or whole block is inside skipped branch)
+    Done,
+}
+
+struct Block {
+    // line of `.if` (in expanded source)
+    line: usize,
+    state: State,
+    has_else: bool,
+}
+
+// state of conditional assembly (`.if`/`.ifdef`/`.elif`/`.else`/`.endif`)
+pub struct Conditions {
+    // `-D` defines and `.math` constants (name, value)
+    symbols: Vec<(String, String)>,
+    blocks: Vec<Block>,
+}
+
+impl Conditions {
+    pub fn new(defines: &[(String, String)]) -> Self {
+        Self {
+            symbols: defines.to_vec(),
+            blocks: Vec::new(),
+        }
+    }
+    pub fn active(&self) -> bool {
+        self.blocks.iter().all(|b| b.state == State::Active)
+    }
+    // returns true if line should be assembled (directives themselves are not);
+    // `n` is line in expanded source (used for errors about unclosed blocks)
+    pub fn process(&mut self, line: &str, n: usize) -> Result<bool, RASMError> {
+        let text = line.split(COMMENT_S).next().unwrap_or("").trim();
+        let (kwd, args) = match text.strip_prefix(PREFIX_KWD) {
+            Some(t) => t.split_once(char::is_whitespace).unwrap_or((t, "")),
+            None => ("", ""),
+        };
+        let args = args.trim();
+        match kwd {
+            "if" | "ifdef" | "ifndef" => {
+                let test = if self.active() {
+                    self.test(kwd, args)
+                } else {
+                    Ok(false)
+                };
+                // block is opened even if condition is invalid, so its `.endif` matches
+                let state = match test {
+                    Ok(true) => State::Active,
+                    Ok(false) if self.active() => State::Pending,
+                    _ => State::Done,
+                };
+                self.blocks.push(Block {
+                    line: n,
+                    state,
+                    has_else: false,
+                });
+                test?;
+            }
+            "elif" | "else" => {
+                let Some(block) = self.blocks.last() else {
+                    return Err(RASMError::no_tip(
+                        None,
+                        Some(format!("Found `.{kwd}` without `.if`")),
+                    ));
+                };
+                if block.has_else {
+                    return Err(RASMError::no_tip(
+                        None,
+                        Some(format!("Found `.{kwd}` after `.else`")),
+                    ));
+                }
+                let state = match block.state {
+                    State::Pending if kwd == "else" || self.test("if", args)? => State::Active,
+                    State::Pending => State::Pending,
+                    _ => State::Done,
+                };
+                let block = self.blocks.last_mut().unwrap();
+                block.state = state;
+                block.has_else = kwd == "else";
+            }
+            "endif" => {
+                if self.blocks.pop().is_none() {
+                    return Err(RASMError::no_tip(
+                        None,
+                        Some("Found `.endif` without `.if`"),
+                    ));
+                }
+            }
+            _ => {
+                let active = self.active();
+                // constants are visible for conditions that come after them
+                if active && kwd == "math" {
+                    if let Some((name, val)) = args.split_once(char::is_whitespace) {
+                        self.symbols
+                            .push((name.to_string(), val.trim().to_string()));
+                    }
+                }
+                return Ok(active);
+            }
+        }
+        Ok(false)
+    }
+    // errors for blocks that were not closed with `.endif`
+    pub fn finish(self) -> Vec<RASMError> {
+        self.blocks
+            .into_iter()
+            .map(|b| {
+                RASMError::no_tip(
+                    Some(b.line),
+                    Some("Conditional block is not closed with `.endif`"),
+                )
+            })
+            .collect()
+    }
+    fn test(&self, kwd: &str, args: &str) -> Result<bool, RASMError> {
+        match kwd {
+            "ifdef" => Ok(self.symbol(args).is_some()),
+            "ifndef" => Ok(self.symbol(args).is_none()),
+            _ => self.condition(args),
+        }
+    }
+    fn symbol(&self, name: &str) -> Option<&String> {
+        self.symbols
+            .iter()
+            .rev()
+            .find(|s| s.0 == name)
+            .map(|s| &s.1)
+    }
+    // `LHS [OP RHS]`, where OP is one of: `==`, `!=`, `<`, `<=`, `>`, `>=`
+    // (without OP condition is true if LHS is not zero)
+    fn condition(&self, cond: &str) -> Result<bool, RASMError> {
+        if cond.is_empty() {
+            return Err(RASMError::with_tip(
+                None,
+                Some("Expected condition after `.if`"),
+                Some("Consider using: `.if @NAME == $1`"),
+            ));
+        }
+        let Some((idx, op)) = comparison(cond) else {
+            return Ok(self.value(cond)? != 0);
+        };
+        let lhs = self.value(cond[..idx].trim())?;
+        let rhs = self.value(cond[idx + op.len()..].trim())?;
+        Ok(match op {
+            "==" => lhs == rhs,
+            "!=" => lhs != rhs,
+            "<" => lhs < rhs,
+            "<=" => lhs <= rhs,
+            ">" => lhs > rhs,
+            _ => lhs >= rhs,
+        })
+    }
+    // evaluates `$IMM`, `$(...)` or `@NAME` (`@NAME` can be also used inside `$(...)`);
+    // values are compared as signed integers
+    fn value(&self, str: &str) -> Result<i64, RASMError> {
+        let expr = self.substitute(str)?;
+        let expr = unwrap_closure(expr.strip_prefix('$').unwrap_or(&expr));
+        if expr.is_empty() {
+            return Err(RASMError::no_tip(
+                None,
+                Some(format!("Expected value in condition, found `{str}`")),
+            ));
+        }
+        if let Ok(n) = Number::from_str(expr) {
+            return Ok(n.get_as_u64() as i64);
+        }
+        MathEval::eval(MathEval::from_str(expr)?)
+            .map(|n| n as i64)
+            .ok_or(RASMError::no_tip(
+                None,
+                Some(format!("Couldn't evaluate `{str}` in condition")),
+            ))
+    }
+    fn substitute(&self, str: &str) -> Result<String, RASMError> {
+        let mut out = String::new();
+        let mut chars = str.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c != '@' {
+                out.push(c);
+                continue;
+            }
+            let mut name = String::new();
+            while let Some(c) = chars.next_if(|c| c.is_ascii_alphanumeric() || *c == '_') {
+                name.push(c);
+            }
+            let Some(val) = self.symbol(&name) else {
+                return Err(RASMError::with_tip(
+                    None,
+                    Some(format!("Unknown symbol `{name}` in condition")),
+                    Some("Symbols used in conditions have to be defined with `.math` or `-D` flag (use `.ifdef` to check if they are)"),
+                ));
+            };
+            let val = val.strip_prefix('$').unwrap_or(val);
+            // math engine can't evaluate closures with only number inside
+            if val.starts_with('(') || Number::from_str(val).is_ok() {
+                out.push_str(val);
+            } else {
+                out.push('(');
+                out.push_str(val);
+                out.push(')');
+            }
+        }
+        Ok(out)
+    }
+}
+
+// `(...)` -> `...` (top-level closure cannot be evaluated)
+fn unwrap_closure(mut expr: &str) -> &str {
+    while expr.starts_with('(') && closing(expr) == Some(expr.len() - 1) {
+        expr = expr[1..expr.len() - 1].trim();
+    }
+    expr
+}
+
+// index of `)` that closes `(` at start of expression
+fn closing(expr: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in expr.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+// finds comparison operator that is not inside closure
+fn comparison(cond: &str) -> Option<(usize, &'static str)> {
+    let bytes = cond.as_bytes();
+    let mut depth = 0;
+    for (i, c) in bytes.iter().enumerate() {
+        match c {
+            b'(' => depth += 1,
+            b')' => depth -= 1,
+            _ if depth != 0 => {}
+            b'=' | b'!' | b'<' | b'>' => {
+                for op in ["==", "!=", "<=", ">=", "<", ">"] {
+                    if cond[i..].starts_with(op) {
+                        return Some((i, op));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn cond_test() {
+        let mut c = Conditions::new(&[("ARCH".to_string(), "2".to_string())]);
+        assert_eq!(c.process(".if @ARCH == $2", 0), Ok(false));
+        assert_eq!(c.process("nop", 1), Ok(true));
+        assert_eq!(c.process(".elif $1", 2), Ok(false));
+        assert_eq!(c.process("nop", 3), Ok(false));
+        assert_eq!(c.process(".else", 4), Ok(false));
+        assert_eq!(c.process("nop", 5), Ok(false));
+        assert_eq!(c.process(".endif", 6), Ok(false));
+        assert!(c.active());
+
+        let _ = c.process(".math size $(4 * 2)", 7);
+        let _ = c.process(".if $(@size * 2) > $10", 8);
+        assert!(c.active());
+        let _ = c.process(".ifdef FOO", 9);
+        assert!(!c.active());
+        let _ = c.process(".if @FOO", 10);
+        assert_eq!(c.process(".else", 11), Ok(false));
+        assert!(!c.active());
+        let _ = c.process(".endif", 12);
+        let _ = c.process(".elif $1", 13);
+        assert!(c.active());
+        let _ = c.process(".endif", 14);
+        assert_eq!(c.finish().len(), 1);
+
+        let mut c = Conditions::new(&[]);
+        assert!(c.process(".if @FOO", 0).is_err());
+        assert!(c.process(".endif", 0).is_ok());
+        assert!(c.process(".endif", 0).is_err());
+        let _ = c.process(".if $0", 0);
+        let _ = c.process(".else", 1);
+        assert!(c.process(".else", 2).is_err());
+
+        // values are signed
+        let mut c = Conditions::new(&[("MIN".to_string(), "$-8".to_string())]);
+        assert_eq!(c.process(".if $-1 < $0", 0), Ok(false));
+        assert!(c.active());
+        let _ = c.process(".endif", 1);
+        let _ = c.process(".if @MIN >= $-7", 2);
+        assert!(!c.active());
+        let _ = c.process(".elif $0x7FFFFFFFFFFFFFFF > @MIN", 3);
+        assert!(c.active());
+    }
+}
@@ -4,18 +4,27 @@
 // licensed under MPL 2.0
 
 use crate::{
+    conf::{DECORATOR_START, PREFIX_REF},
     pre::tok::Token,
     shr::{
         ast::{ASTNode, Instruction, Operand},
+        booltable::BoolTable8,
         error::RASMError,
         ins::Mnemonic as Mnm,
+        isa::Cpu,
         kwd::Keyword,
+        mask::Mask,
         mem::Mem,
-        segment::Segment,
+        pseudo::Pseudo,
+        reg::{Purpose as RPurpose, Register},
+        reloc::RelType,
+        rounding::Rounding,
+        segment::{FarPtr, Segment},
         size::Size,
+        symbol::SymbolRef,
     },
 };
-use std::path::PathBuf;
+use std::{path::PathBuf, str::FromStr};
 
 pub struct Lexer;
 impl Lexer {
@@ -118,23 +127,33 @@ impl Lexer {
                         ));
                     }
                 }
-                Some(Token::Keyword(Keyword::Math)) => match make_eval(line) {
-                    Ok(n) => node = Some(ASTNode::MathEval(n.0, n.1)),
+                Some(Token::Keyword(Keyword::Cpu)) => match make_cpu(line) {
+                    Ok(c) => node = Some(ASTNode::Cpu(c)),
                     Err(mut e) => {
                         e.set_line(line_count);
                         error = Some(e)
                     }
                 },
-                Some(Token::Mnemonic(_)) => match make_ins(line) {
-                    Ok(mut i) => {
-                        i.line = line_count;
-                        node = Some(ASTNode::Ins(i));
-                    }
+                Some(Token::Keyword(Keyword::Math)) => match make_eval(line) {
+                    Ok(n) => node = Some(ASTNode::MathEval(n.0, n.1)),
                     Err(mut e) => {
                         e.set_line(line_count);
-                        error = Some(e);
+                        error = Some(e)
                     }
                 },
+                // instruction with pseudo prefix (like `{vex} addps ...`)
+                Some(Token::Mnemonic(_) | Token::Closure(DECORATOR_START, _)) => {
+                    match make_ins(line) {
+                        Ok(mut i) => {
+                            i.line = line_count;
+                            node = Some(ASTNode::Ins(i));
+                        }
+                        Err(mut e) => {
+                            e.set_line(line_count);
+                            error = Some(e);
+                        }
+                    }
+                }
                 Some(Token::Unknown(s)) => ast_tree.push(Err(RASMError::no_tip(
                     Some(line_count),
                     Some(format!("Tried to start line with unknown mnemonic `{s}`")),
@@ -169,6 +188,36 @@ fn make_include(line: Vec<Token>) -> Result<PathBuf, RASMError> {
     }
 }
 
+// `.cpu x86-64-v2, aes`
+fn make_cpu(line: Vec<Token>) -> Result<Cpu, RASMError> {
+    let mut names = Vec::new();
+    for t in line.into_iter().skip(1) {
+        match t {
+            Token::Unknown(s) | Token::String(s) => names.push(s),
+            // extension names like `popcnt` or `movbe` are also mnemonics
+            Token::Mnemonic(m) => names.push(m.to_string()),
+            Token::Comma => continue,
+            t => {
+                return Err(RASMError::no_tip(
+                    None,
+                    Some(format!(
+                        "Unexpected token `{}` in cpu directive",
+                        t.to_string()
+                    )),
+                ))
+            }
+        }
+    }
+    if names.is_empty() {
+        return Err(RASMError::with_tip(
+            None,
+            Some("Unexpected end of line after cpu keyword, expected CPU, found nothing"),
+            Some("Consider adding x86-64 level (like `x86-64-v2`) or extension after cpu keyword"),
+        ));
+    }
+    Cpu::from_str(&names.join(","))
+}
+
 fn make_eval(mut line: Vec<Token>) -> Result<(String, String), RASMError> {
     if line.is_empty() {
         return Err(RASMError::no_tip(
@@ -219,7 +268,22 @@ fn make_ins(line: Vec<Token>) -> Result<Instruction, RASMError> {
     }
     let mut mnems: Vec<Mnm> = Vec::with_capacity(2);
     let mut tmp_buf: Vec<Token> = Vec::with_capacity(6);
-    let mut iter = line.into_iter();
+    let mut pseudo = BoolTable8::new();
+    let mut iter = line.into_iter().peekable();
+    while let Some(Token::Closure(DECORATOR_START, _)) = iter.peek() {
+        if let Some(Token::Closure(_, p)) = iter.next() {
+            match Pseudo::from_str(&p) {
+                Ok(p) => pseudo.set(p as u8, true),
+                Err(_) => {
+                    return Err(RASMError::with_tip(
+                        None,
+                        Some(format!("Unknown pseudo prefix `{{{p}}}`")),
+                        Some("Expected one of: `{vex}`, `{vex3}`, `{rex}`, `{disp8}`, `{disp32}`, `{load}`, `{store}`"),
+                    ))
+                }
+            }
+        }
+    }
     while let Some(t) = iter.next() {
         if let Token::Mnemonic(m) = t {
             mnems.push(m);
@@ -232,18 +296,32 @@ fn make_ins(line: Vec<Token>) -> Result<Instruction, RASMError> {
     }
 
     let mut ops = [None, None, None, None, None];
+    let mut mask = None;
+    let mut rounding = None;
+    let mut symbol = None;
     let mut opi = 0;
     while let Some(t) = iter.next() {
         if t == Token::Comma {
             if !tmp_buf.is_empty() {
-                ops[opi] = Some(make_op(&mut tmp_buf)?);
-                if opi > 5 {
+                if rounding.is_some() {
                     return Err(RASMError::no_tip(
                         None,
-                        Some("More than max operands in instruction (5) were used!"),
+                        Some("Rounding/SAE decorator must be the last operand"),
                     ));
                 }
-                opi += 1;
+                make_mask(&mut tmp_buf, opi, &mut mask)?;
+                let bcst = make_decorator(&mut tmp_buf, &mut rounding)?;
+                let msym = make_memsym(&mut tmp_buf, &mut symbol)?;
+                if !tmp_buf.is_empty() {
+                    ops[opi] = Some(make_msym(make_bcst(make_op(&mut tmp_buf)?, bcst)?, msym));
+                    if opi > 5 {
+                        return Err(RASMError::no_tip(
+                            None,
+                            Some("More than max operands in instruction (5) were used!"),
+                        ));
+                    }
+                    opi += 1;
+                }
                 tmp_buf.clear();
             }
         } else {
@@ -251,7 +329,18 @@ fn make_ins(line: Vec<Token>) -> Result<Instruction, RASMError> {
         }
     }
     if !tmp_buf.is_empty() {
-        ops[opi] = Some(make_op(&mut tmp_buf)?);
+        if rounding.is_some() {
+            return Err(RASMError::no_tip(
+                None,
+                Some("Rounding/SAE decorator must be the last operand"),
+            ));
+        }
+        make_mask(&mut tmp_buf, opi, &mut mask)?;
+        let bcst = make_decorator(&mut tmp_buf, &mut rounding)?;
+        let msym = make_memsym(&mut tmp_buf, &mut symbol)?;
+        if !tmp_buf.is_empty() {
+            ops[opi] = Some(make_msym(make_bcst(make_op(&mut tmp_buf)?, bcst)?, msym));
+        }
     }
     if mnems.is_empty() {
         return Err(RASMError::no_tip(
@@ -272,9 +361,279 @@ fn make_ins(line: Vec<Token>) -> Result<Instruction, RASMError> {
         addt,
         oprs: ops,
         line: 0,
+        mask,
+        rounding,
+        symbol,
+        pseudo,
+        cpu: None,
     })
 }
 
+// strips opmask modifier (like `%zmm0:k1:z` or `(%rax):k1`) from operand
+fn make_mask(line: &mut [Token], idx: usize, mask: &mut Option<Mask>) -> Result<(), RASMError> {
+    for tok in line.iter_mut() {
+        let (base, modf) = if let Token::Modifier(base, modf) = tok {
+            match &**base {
+                Token::Register(r) if r.purpose() != RPurpose::Sgmnt => {}
+                Token::Closure(' ', _) => {}
+                _ => continue,
+            }
+            (base.clone(), modf.clone())
+        } else {
+            continue;
+        };
+        let (mreg, zeroing) = match *modf {
+            Token::Modifier(m, z) => match *z {
+                Token::Unknown(z) if z == "z" => (*m, true),
+                _ => {
+                    return Err(RASMError::with_tip(
+                        None,
+                        Some(format!(
+                            "Expected zeroing (`z`) after opmask, found `{}`",
+                            z.to_string()
+                        )),
+                        Some("Opmask modifier has format: `%zmm0:k1` or `%zmm0:k1:z`"),
+                    ))
+                }
+            },
+            m => (m, false),
+        };
+        let mreg = match mreg {
+            Token::Register(r) => r,
+            Token::Unknown(s) => match Register::from_str(&s) {
+                Ok(r) => r,
+                Err(_) => {
+                    return Err(RASMError::no_tip(
+                        None,
+                        Some(format!("Couldn't make opmask register from `{s}`")),
+                    ))
+                }
+            },
+            t => {
+                return Err(RASMError::no_tip(
+                    None,
+                    Some(format!(
+                        "Couldn't make opmask register from `{}`",
+                        t.to_string()
+                    )),
+                ))
+            }
+        };
+        if idx != 0 {
+            return Err(RASMError::no_tip(
+                None,
+                Some("Opmask can only be used on destination operand"),
+            ));
+        }
+        *mask = Some(Mask::new(mreg, zeroing));
+        *tok = *base;
+        return Ok(());
+    }
+    Ok(())
+}
+
+// strips AVX-512 decorator (like `(%rax){1to16}` or `{rn-sae}`) from operand;
+// returns N of broadcast (if found)
+fn make_decorator(
+    line: &mut Vec<Token>,
+    rounding: &mut Option<Rounding>,
+) -> Result<Option<u8>, RASMError> {
+    let idx = if let Some(i) = line
+        .iter()
+        .position(|t| matches!(t, Token::Closure(DECORATOR_START, _)))
+    {
+        i
+    } else {
+        return Ok(None);
+    };
+    let decorator = if let Token::Closure(_, s) = line.remove(idx) {
+        s
+    } else {
+        unreachable!()
+    };
+    // standalone decorator means rounding/SAE
+    if line.is_empty() {
+        return match Rounding::from_str(&decorator) {
+            Ok(r) => {
+                *rounding = Some(r);
+                Ok(None)
+            }
+            Err(_) => Err(RASMError::with_tip(
+                None,
+                Some(format!("Couldn't make rounding from `{{{decorator}}}`")),
+                Some("Expected one of: `{rn-sae}`, `{rd-sae}`, `{ru-sae}`, `{rz-sae}`, `{sae}`"),
+            )),
+        };
+    }
+    if let Some(n) = decorator.strip_prefix("1to") {
+        if let Ok(n @ (2 | 4 | 8 | 16)) = n.parse::<u8>() {
+            return Ok(Some(n));
+        }
+    }
+    Err(RASMError::with_tip(
+        None,
+        Some(format!("Couldn't make broadcast from `{{{decorator}}}`")),
+        Some("Expected one of: `{1to2}`, `{1to4}`, `{1to8}`, `{1to16}`"),
+    ))
+}
+
+fn make_bcst(mut op: Operand, bcst: Option<u8>) -> Result<Operand, RASMError> {
+    let n = if let Some(n) = bcst {
+        n
+    } else {
+        return Ok(op);
+    };
+    match &mut op {
+        Operand::Mem(m)
+        | Operand::Segment(Segment {
+            segment: _,
+            address: m,
+        }) => {
+            m.set_bcst(n);
+            Ok(op)
+        }
+        _ => Err(RASMError::no_tip(
+            None,
+            Some("Broadcast can only be used with memory operand"),
+        )),
+    }
+}
+
+// strips symbol reference (like `(%rip + @data)` or `(%rip + @(data:+8))`) from
+// memory operand; returns true if symbol was found
+fn make_memsym(line: &mut [Token], symbol: &mut Option<SymbolRef>) -> Result<bool, RASMError> {
+    for tok in line.iter_mut() {
+        let mem = match tok {
+            Token::Closure(' ', m) => m,
+            Token::Modifier(_, m) => match &mut **m {
+                Token::Closure(' ', m) => m,
+                _ => continue,
+            },
+            _ => continue,
+        };
+        let start = if let Some(i) = mem.find(PREFIX_REF) {
+            i
+        } else {
+            continue;
+        };
+        if symbol.is_some() {
+            return Err(RASMError::no_tip(
+                None,
+                Some("Only one symbol can be referenced in instruction"),
+            ));
+        }
+        let bytes = mem.as_bytes();
+        if start != 0 && bytes[start - 1] == b'-' {
+            return Err(RASMError::no_tip(
+                None,
+                Some("Symbol used in memory operand cannot be negated"),
+            ));
+        }
+        let end = if bytes.get(start + 1) == Some(&b'(') {
+            match mem[start..].find(')') {
+                Some(i) => start + i + 1,
+                None => {
+                    return Err(RASMError::no_tip(
+                        None,
+                        Some(format!("Unclosed symbol reference in `({mem})`")),
+                    ))
+                }
+            }
+        } else {
+            mem[start..]
+                .find(['+', '-', '*'])
+                .map(|i| start + i)
+                .unwrap_or(mem.len())
+        };
+        *symbol = Some(SymbolRef::try_new(&mem[start + 1..end])?);
+
+        let start = if start != 0 && bytes[start - 1] == b'+' {
+            start - 1
+        } else {
+            start
+        };
+        let mut rest = format!("{}{}", &mem[..start], &mem[end..]);
+        if rest.starts_with('+') {
+            rest.remove(0);
+        }
+        // symbol-only memory (like `(@data)`) is offset-only memory
+        if rest.is_empty() {
+            rest.push_str("$0");
+        }
+        *mem = rest;
+        return Ok(true);
+    }
+    Ok(false)
+}
+
+fn make_msym(mut op: Operand, msym: bool) -> Operand {
+    if !msym {
+        return op;
+    }
+    if let Operand::Mem(m)
+    | Operand::Segment(Segment {
+        segment: _,
+        address: m,
+    }) = &mut op
+    {
+        m.set_flag(crate::shr::mem::SYMBOL);
+        if m.offset().is_none() {
+            m.set_offset(0);
+        }
+    }
+    op
+}
+
+// makes far pointer (like `.far $0x08:@pm_entry` or `.far $0x08:$0x7C00`)
+fn make_farptr(sel: &Token, off: &Token) -> Result<Operand, RASMError> {
+    let selector = match sel {
+        Token::Immediate(n) if n.get_as_u64() <= u16::MAX as u64 => n.get_as_u64() as u16,
+        _ => {
+            return Err(RASMError::with_tip(
+                None,
+                Some(format!(
+                    "Couldn't make segment selector of far pointer from `{}`",
+                    sel.to_string()
+                )),
+                Some("Segment selector of far pointer must be 16-bit immediate"),
+            ))
+        }
+    };
+    let (offset, symbol) = match off {
+        Token::Immediate(n) if n.get_as_u64() <= u32::MAX as u64 => (n.get_as_u32(), None),
+        Token::SymbolRef(s) => (
+            0,
+            Some(SymbolRef {
+                symbol: s.to_string(),
+                addend: 0,
+                reltype: RelType::ABS32,
+            }),
+        ),
+        Token::SymbolRefExt(s) => (
+            0,
+            Some(SymbolRef {
+                reltype: RelType::ABS32,
+                ..s.clone()
+            }),
+        ),
+        _ => {
+            return Err(RASMError::with_tip(
+                None,
+                Some(format!(
+                    "Couldn't make offset of far pointer from `{}`",
+                    off.to_string()
+                )),
+                Some("Offset of far pointer must be 32-bit immediate or symbol"),
+            ))
+        }
+    };
+    Ok(Operand::FarPtr(FarPtr {
+        selector,
+        offset,
+        symbol,
+    }))
+}
+
 fn make_op(line: &mut Vec<Token>) -> Result<Operand, RASMError> {
     if line.is_empty() {
         return Err(RASMError::no_tip(
@@ -292,6 +651,8 @@ fn make_op(line: &mut Vec<Token>) -> Result<Operand, RASMError> {
              (Token::Closure(' ', m), Token::Keyword(k))
             |(Token::Keyword(k), Token::Closure(' ', m)) =>
                 return Ok(Operand::Mem(Mem::new(m, Size::try_from(*k).unwrap_or(Size::Unknown))?)),
+            (Token::Keyword(Keyword::Far), Token::Modifier(sel, off)) =>
+                return make_farptr(sel, off),
              (Token::Modifier(r, m), Token::Keyword(k))
             |(Token::Keyword(k), Token::Modifier(r, m)) => {
                 let size = match Size::try_from(*k){
@@ -319,6 +680,17 @@ fn make_op(line: &mut Vec<Token>) -> Result<Operand, RASMError> {
                 };
                 return Ok(Operand::Segment(Segment { segment: r, address: mem}));
             }
+            (Token::Keyword(k @ (Keyword::Short | Keyword::Near)), Token::SymbolRef(s)) =>
+                return Ok(Operand::SymbolRefExt(SymbolRef {
+                    symbol: s.to_string(),
+                    addend: 0,
+                    reltype: if *k == Keyword::Short { RelType::REL8 } else { RelType::REL32 },
+                })),
+            (Token::Keyword(k @ (Keyword::Short | Keyword::Near)), Token::SymbolRefExt(s)) => {
+                let mut s = s.clone();
+                s.reltype = if *k == Keyword::Short { RelType::REL8 } else { RelType::REL32 };
+                return Ok(Operand::SymbolRefExt(s));
+            }
             _ => return Err(RASMError::no_tip(
                 None,
                 Some("Tried to make unexpected operand from two tokens; expected memory address (or segment) along with size specifier".to_string()),
@@ -0,0 +1,496 @@
+// rasmx86_64 - src/pre/mac.rs
+// ---------------------------
+// made by matissoss
+// licensed under MPL 2.0
+
+use std::str::FromStr;
+
+use crate::{
+    conf::{COMMENT_S, PREFIX_KWD},
+    pre::cond::Conditions,
+    shr::{
+        error::{LineOrigin, RASMError},
+        ins::Mnemonic,
+    },
+};
+
+// max depth of nested macro expansions (guards against recursive macros)
+const MAX_DEPTH: usize = 64;
+
+#[derive(Debug, Clone)]
+struct Param {
+    name: String,
+    default: Option<String>,
+    vararg: bool,
+}
+
+#[derive(Debug, Clone)]
+struct Macro {
+    name: String,
+    params: Vec<Param>,
+    // (line in source file, content)
+    body: Vec<(usize, String)>,
+}
+
+struct Expander {
+    macros: Vec<Macro>,
+    // counter for `\@` (unique per expansion)
+    count: usize,
+    cond: Conditions,
+    out: Vec<(String, LineOrigin)>,
+    errors: Vec<RASMError>,
+}
+
+// expands macros (`.macro NAME a, b` ... `.endm`) and conditional blocks
+// (`.if` ... `.endif`) in source file; `defines` come from `-D` flag;
+// returns expanded lines, where each of them comes from and errors
+// (errors use lines of expanded source, so they should be printed after
+// registering returned line map with `error::set_line_map`)
+pub fn expand(
+    src: &str,
+    defines: &[(String, String)],
+) -> (Vec<String>, Vec<LineOrigin>, Vec<RASMError>) {
+    let mut exp = Expander {
+        macros: Vec::new(),
+        count: 0,
+        cond: Conditions::new(defines),
+        out: Vec::new(),
+        errors: Vec::new(),
+    };
+    let mut lines = src.lines().enumerate().peekable();
+    while let Some((n, l)) = lines.next() {
+        match directive(l) {
+            Some("macro") => {
+                // definitions (and skipped lines) are replaced with empty lines
+                let start = exp.out.len();
+                let mut def = vec![(n, l)];
+                while let Some((n, l)) = lines.peek() {
+                    let d = directive(l);
+                    if d == Some("macro") {
+                        break;
+                    }
+                    def.push((*n, *l));
+                    lines.next();
+                    if d == Some("endm") {
+                        break;
+                    }
+                }
+                for (n, _) in &def {
+                    exp.out.push((String::new(), origin(*n)));
+                }
+                if !exp.cond.active() {
+                    continue;
+                }
+                match define(&def) {
+                    Ok(m) => {
+                        if exp.macros.iter().any(|d| d.name == m.name) {
+                            exp.errors.push(RASMError::no_tip(
+                                Some(start),
+                                Some(format!("Macro `{}` was already defined", m.name)),
+                            ));
+                        } else {
+                            exp.macros.push(m);
+                        }
+                    }
+                    Err(mut e) => {
+                        let line = start + e.get_line().map(|l| l - n).unwrap_or(0);
+                        e.set_line(line);
+                        exp.errors.push(e);
+                    }
+                }
+            }
+            Some("endm") if exp.cond.active() => exp.error(
+                origin(n),
+                RASMError::no_tip(None, Some("Found `.endm` without `.macro`")),
+            ),
+            _ => exp.emit(l, origin(n)),
+        }
+    }
+    exp.errors.extend(exp.cond.finish());
+    let (lines, map) = exp.out.into_iter().unzip();
+    (lines, map, exp.errors)
+}
+
+fn origin(line: usize) -> LineOrigin {
+    LineOrigin {
+        line,
+        expansion: Vec::new(),
+    }
+}
+
+impl Expander {
+    // pushes error at placeholder line, so it can be traced back through expansions
+    fn error(&mut self, origin: LineOrigin, mut err: RASMError) {
+        self.out.push((String::new(), origin));
+        err.set_line(self.out.len() - 1);
+        self.errors.push(err);
+    }
+    fn emit(&mut self, line: &str, origin: LineOrigin) {
+        match self.cond.process(line, self.out.len()) {
+            Ok(true) => {}
+            Ok(false) => {
+                self.out.push((String::new(), origin));
+                return;
+            }
+            Err(e) => {
+                self.error(origin, e);
+                return;
+            }
+        }
+        let text = strip(line);
+        let name = text.split_whitespace().next().unwrap_or("");
+        let Some(m) = self.macros.iter().find(|m| m.name == name).cloned() else {
+            self.out.push((line.to_string(), origin));
+            return;
+        };
+        if origin.expansion.len() >= MAX_DEPTH {
+            self.error(
+                origin,
+                RASMError::with_tip(
+                    None,
+                    Some(format!(
+                        "Macro `{name}` exceeded max expansion depth of {MAX_DEPTH}"
+                    )),
+                    Some("Check if macro is not (indirectly) calling itself"),
+                ),
+            );
+            return;
+        }
+        let args = match bind(&m, text[name.len()..].trim()) {
+            Ok(a) => a,
+            Err(e) => {
+                self.error(origin, e);
+                return;
+            }
+        };
+        self.count += 1;
+        let id = self.count;
+        for (bl, l) in &m.body {
+            let mut o = origin.clone();
+            o.expansion.push((m.name.clone(), *bl));
+            self.emit(&substitute(l, &args, id), o);
+        }
+    }
+}
+
+// parses macro definition (header, body and `.endm` lines)
+fn define(lines: &[(usize, &str)]) -> Result<Macro, RASMError> {
+    let (n, header) = lines[0];
+    let header = strip(header)[PREFIX_KWD.len_utf8() + "macro".len()..].trim();
+    let (name, params) = header
+        .split_once(char::is_whitespace)
+        .unwrap_or((header, ""));
+    if name.is_empty() {
+        return Err(RASMError::with_tip(
+            Some(n),
+            Some("Expected macro name after `.macro`"),
+            Some("Consider using: `.macro NAME a, b`"),
+        ));
+    }
+    if !is_ident(name) {
+        return Err(RASMError::no_tip(
+            Some(n),
+            Some(format!("Macro name `{name}` is not a valid identifier")),
+        ));
+    }
+    if Mnemonic::from_str(&name.to_lowercase()).is_ok() {
+        return Err(RASMError::no_tip(
+            Some(n),
+            Some(format!("Macro name `{name}` is already a mnemonic")),
+        ));
+    }
+    let mut mac = Macro {
+        name: name.to_string(),
+        params: Vec::new(),
+        body: Vec::new(),
+    };
+    for p in split_args(params) {
+        if mac.params.last().is_some_and(|p| p.vararg) {
+            return Err(RASMError::no_tip(
+                Some(n),
+                Some("Variadic parameter has to be the last one"),
+            ));
+        }
+        let param = if let Some((p, d)) = p.split_once('=') {
+            Param {
+                name: p.trim().to_string(),
+                default: Some(d.trim().to_string()),
+                vararg: false,
+            }
+        } else if let Some(p) = p.strip_suffix(":vararg") {
+            Param {
+                name: p.trim().to_string(),
+                default: None,
+                vararg: true,
+            }
+        } else {
+            Param {
+                name: p,
+                default: None,
+                vararg: false,
+            }
+        };
+        if !is_ident(&param.name) {
+            return Err(RASMError::with_tip(
+                Some(n),
+                Some(format!("Invalid macro parameter `{}`", param.name)),
+                Some("Parameters are identifiers, optionally with default value (`b=$1`) or `:vararg` suffix"),
+            ));
+        }
+        if mac.params.iter().any(|p| p.name == param.name) {
+            return Err(RASMError::no_tip(
+                Some(n),
+                Some(format!("Macro parameter `{}` is defined twice", param.name)),
+            ));
+        }
+        mac.params.push(param);
+    }
+    for (ln, l) in &lines[1..] {
+        if directive(l) == Some("endm") {
+            return Ok(mac);
+        }
+        for r in references(l) {
+            if !mac.params.iter().any(|p| p.name == r) {
+                return Err(RASMError::no_tip(
+                    Some(*ln),
+                    Some(format!("Unknown parameter `\\{r}` in macro `{name}`")),
+                ));
+            }
+        }
+        mac.body.push((*ln, l.to_string()));
+    }
+    Err(RASMError::with_tip(
+        Some(n),
+        Some(format!("Macro `{name}` is not closed with `.endm`")),
+        Some("Macros cannot be defined inside other macros"),
+    ))
+}
+
+// assigns arguments of macro call to parameters
+fn bind(m: &Macro, args: &str) -> Result<Vec<(String, String)>, RASMError> {
+    let mut vals: Vec<Option<String>> = vec![None; m.params.len()];
+    let mut pos = 0;
+    let mut args = split_args(args).into_iter();
+    while let Some(a) = args.next() {
+        // named argument (like `b=$1`)
+        if let Some((n, v)) = a.split_once('=') {
+            if let Some(i) = m.params.iter().position(|p| p.name == n.trim()) {
+                if vals[i].is_some() {
+                    return Err(RASMError::no_tip(
+                        None,
+                        Some(format!(
+                            "Argument for parameter `{}` of macro `{}` was given twice",
+                            m.params[i].name, m.name
+                        )),
+                    ));
+                }
+                vals[i] = Some(v.trim().to_string());
+                continue;
+            }
+        }
+        while pos < vals.len() && vals[pos].is_some() {
+            pos += 1;
+        }
+        match m.params.get(pos) {
+            Some(p) if p.vararg => {
+                let rest = std::iter::once(a).chain(args.by_ref());
+                vals[pos] = Some(rest.collect::<Vec<String>>().join(", "));
+            }
+            Some(_) => {
+                // empty argument means default value
+                if !a.is_empty() {
+                    vals[pos] = Some(a);
+                }
+                pos += 1;
+            }
+            None => {
+                return Err(RASMError::no_tip(
+                    None,
+                    Some(format!(
+                        "Macro `{}` takes {} argument(s), but more were given",
+                        m.name,
+                        m.params.len()
+                    )),
+                ))
+            }
+        }
+    }
+    let mut bound = Vec::new();
+    for (p, v) in m.params.iter().zip(vals) {
+        let v = match (v, &p.default) {
+            (Some(v), _) => v,
+            (None, Some(d)) => d.clone(),
+            (None, None) if p.vararg => String::new(),
+            (None, None) => {
+                return Err(RASMError::no_tip(
+                    None,
+                    Some(format!(
+                        "Missing argument for parameter `{}` of macro `{}`",
+                        p.name, m.name
+                    )),
+                ))
+            }
+        };
+        bound.push((p.name.clone(), v));
+    }
+    Ok(bound)
+}
+
+// replaces `\name` with argument, `\@` with expansion id and `\()` with nothing
+// (comment is left as is)
+fn substitute(line: &str, args: &[(String, String)], id: usize) -> String {
+    let (code, comment) = line.split_at(line.find(COMMENT_S).unwrap_or(line.len()));
+    let mut out = String::new();
+    let mut chars = code.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.peek() {
+            Some('@') => {
+                chars.next();
+                out.push_str(&id.to_string());
+            }
+            Some('(') => {
+                chars.next();
+                if chars.peek() == Some(&')') {
+                    chars.next();
+                } else {
+                    out.push_str("\\(");
+                }
+            }
+            _ => {
+                let name = ident(&mut chars);
+                match args.iter().find(|a| a.0 == name) {
+                    Some((_, v)) => out.push_str(v),
+                    None => {
+                        out.push('\\');
+                        out.push_str(&name);
+                    }
+                }
+            }
+        }
+    }
+    out.push_str(comment);
+    out
+}
+
+// names of parameters referenced in line (`\name`), excluding comment
+fn references(line: &str) -> Vec<String> {
+    let mut refs = Vec::new();
+    let mut chars = strip(line).chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            let name = ident(&mut chars);
+            if !name.is_empty() {
+                refs.push(name);
+            }
+        }
+    }
+    refs
+}
+
+fn ident(chars: &mut std::iter::Peekable<std::str::Chars>) -> String {
+    let mut name = String::new();
+    while let Some(c) = chars.peek() {
+        if c.is_ascii_alphanumeric() || *c == '_' {
+            name.push(*c);
+            chars.next();
+        } else {
+            break;
+        }
+    }
+    name
+}
+
+fn is_ident(str: &str) -> bool {
+    !str.is_empty()
+        && !str.starts_with(|c: char| c.is_ascii_digit())
+        && str.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+// splits at commas that are not inside closures, decorators or strings
+fn split_args(str: &str) -> Vec<String> {
+    if str.trim().is_empty() {
+        return Vec::new();
+    }
+    let mut args = Vec::new();
+    let mut buf = String::new();
+    let mut depth = 0;
+    let mut string = false;
+    for c in str.chars() {
+        match c {
+            '"' => string = !string,
+            '(' | '{' | '[' if !string => depth += 1,
+            ')' | '}' | ']' if !string && depth > 0 => depth -= 1,
+            ',' if !string && depth == 0 => {
+                args.push(buf.trim().to_string());
+                buf.clear();
+                continue;
+            }
+            _ => {}
+        }
+        buf.push(c);
+    }
+    args.push(buf.trim().to_string());
+    args
+}
+
+fn strip(line: &str) -> &str {
+    line.split(COMMENT_S).next().unwrap_or("").trim()
+}
+
+// keyword that line starts with (like `macro` for `.macro NAME`)
+fn directive(line: &str) -> Option<&str> {
+    strip(line)
+        .strip_prefix(PREFIX_KWD)?
+        .split_whitespace()
+        .next()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn macro_test() {
+        let src = ".macro zero r\n\txor \\r, \\r\n.endm\nzero %eax\nzero %ecx";
+        let (lines, map, errs) = expand(src, &[]);
+        assert!(errs.is_empty());
+        assert_eq!(lines[3], "\txor %eax, %eax");
+        assert_eq!(lines[4], "\txor %ecx, %ecx");
+        assert_eq!(map[4].line, 4);
+        assert_eq!(map[4].expansion, vec![("zero".to_string(), 1)]);
+
+        // default, named and variadic arguments
+        let src = ".macro m a, b=$1, rest:vararg\n\\a \\b, \\rest\n.endm\nm add, , %eax\nm sub, b=$2, %ecx, %edx";
+        let (lines, _, errs) = expand(src, &[]);
+        assert!(errs.is_empty());
+        assert_eq!(lines[3], "add $1, %eax");
+        assert_eq!(lines[4], "sub $2, %ecx, %edx");
+
+        // local labels
+        let src = ".macro l\nl\\@:\n.endm\nl\nl";
+        let (lines, _, _) = expand(src, &[]);
+        assert_eq!(lines[3], "l1:");
+        assert_eq!(lines[4], "l2:");
+
+        // comments are not substituted
+        let src = ".macro m a\nmov %eax, \\a ; \\a in C:\\dir\n.endm\nm $1";
+        let (lines, _, errs) = expand(src, &[]);
+        assert!(errs.is_empty());
+        assert_eq!(lines[3], "mov %eax, $1 ; \\a in C:\\dir");
+    }
+    #[test]
+    fn macro_err_test() {
+        let (_, _, errs) = expand(".macro m a\n\\b\n.endm", &[]);
+        assert_eq!(errs[0].get_line(), Some(&1));
+        let (_, map, errs) = expand(".macro m a\n\\a %eax\n.endm\nm", &[]);
+        assert_eq!(map[*errs[0].get_line().unwrap()].line, 3);
+        let (_, map, errs) = expand(".macro m\nm\n.endm\nm", &[]);
+        assert_eq!(map[*errs[0].get_line().unwrap()].expansion.len(), MAX_DEPTH);
+        assert_eq!(expand(".macro m\n.macro n\n.endm", &[]).2.len(), 1);
+        assert_eq!(expand(".endm", &[]).2.len(), 1);
+        assert_eq!(expand(".macro add\n.endm", &[]).2.len(), 1);
+    }
+}
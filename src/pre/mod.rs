@@ -1,4 +1,6 @@
 pub mod chk;
+pub mod cond;
 pub mod lex;
+pub mod mac;
 pub mod par;
 pub mod tok;
@@ -7,6 +7,7 @@ const EMPTY_STRING: &str = "";
 use crate::shr::{
     ast::{ASTNode, Instruction, Label, AST},
     error::RASMError,
+    num::Number,
     section::Section,
     symbol::Visibility,
 };
@@ -24,6 +25,8 @@ impl Parser {
         let mut tmp_attributes: Vec<String> = Vec::with_capacity(4);
         let mut inside_label: (bool, String) = (false, String::new());
         let mut instructions: Vec<Instruction> = Vec::with_capacity(PAR_INST_CAP);
+        // target CPU selected with `.cpu`
+        let mut cpu = None;
         let mut section_idx: usize = 0;
         let mut inside_section = Section::default();
         let mut labels = Vec::new();
@@ -125,6 +128,7 @@ impl Parser {
                         }
                         ASTNode::Include(p) => ast.includes.push(p),
                         ASTNode::MathEval(name, value) => ast.math.push((name, value)),
+                        ASTNode::Cpu(c) => cpu = Some(c),
                         ASTNode::Label(lbl) => {
                             if !instructions.is_empty() {
                                 if let Err(err) = collect_label(
@@ -150,7 +154,7 @@ impl Parser {
                                     Some("RASM doesn't support instructions outside of label. Consider adding it to label like: _misc or something like this")
                                 ));
                             } else {
-                                instructions.push(ins);
+                                instructions.push(Instruction { cpu, ..ins });
                             }
                         }
                         ASTNode::Extern(extrn) => {
@@ -256,6 +260,7 @@ fn split_str_into_vec(str: &str) -> Vec<String> {
 #[derive(Default, Clone, Copy, PartialEq, Debug)]
 struct TmpLabelAttr {
     align: u16,
+    fill: Option<u8>,
     bits: u8,
     global: bool,
 }
@@ -268,8 +273,8 @@ fn collect_label(
     defbits: u8,
     secidx: usize,
 ) -> Result<(), Error> {
-    let (bits, align, global) = match parse_attr(attrs) {
-        Ok(t) => (t.bits, t.align, t.global),
+    let (bits, align, fill, global) = match parse_attr(attrs) {
+        Ok(t) => (t.bits, t.align, t.fill, t.global),
         Err(e) => return Err(e),
     };
     vec.push(Label {
@@ -287,6 +292,7 @@ fn collect_label(
         },
         shidx: secidx,
         align,
+        fill,
     });
     Ok(())
 }
@@ -310,11 +316,24 @@ fn parse_attr(attr: String) -> Result<TmpLabelAttr, RASMError> {
                     }
                 }
                 "align" => {
+                    // align can have custom fill byte (like `align=16:0xCC`)
+                    let (val, fill) = match val.split_once(':') {
+                        Some((v, f)) => (v, Some(f)),
+                        None => (val, None),
+                    };
                     if let Ok(n) = val.parse::<u16>() {
                         attrs.align = n;
                     } else {
                         return Err(RASMError::no_tip(None, Some("Tried to assign label align attribute; expected a unsigned 16-bit integer, found unknown")));
                     }
+                    if let Some(f) = fill {
+                        match Number::from_str(f) {
+                            Ok(n) if n.get_as_u64() <= u8::MAX as u64 => {
+                                attrs.fill = Some(n.get_as_u64() as u8)
+                            }
+                            _ => return Err(RASMError::no_tip(None, Some("Tried to assign fill byte of label align attribute; expected a unsigned 8-bit integer, found unknown"))),
+                        }
+                    }
                 }
                 "bits" => {
                     if let Ok(n) = val.parse::<u8>() {
@@ -344,8 +363,10 @@ fn parse_attr(attr: String) -> Result<TmpLabelAttr, RASMError> {
 mod tests {
     use super::*;
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn section_par_test() {
         use crate::shr::*;
+        use booltable::BoolTable8;
         use section::SectionAttributes;
         let nodes = vec![
             Ok((ASTNode::Section(".text".to_string()), 0)),
@@ -357,6 +378,11 @@ mod tests {
                     oprs: [None, None, None, None, None],
                     addt: None,
                     line: 0,
+                    mask: None,
+                    rounding: None,
+                    symbol: None,
+                    pseudo: BoolTable8::new(),
+                    cpu: None,
                     mnem: ins::Mnemonic::__LAST,
                 }),
                 0,
@@ -367,6 +393,11 @@ mod tests {
                     oprs: [None, None, None, None, None],
                     addt: None,
                     line: 0,
+                    mask: None,
+                    rounding: None,
+                    symbol: None,
+                    pseudo: BoolTable8::new(),
+                    cpu: None,
                     mnem: ins::Mnemonic::__LAST,
                 }),
                 0,
@@ -380,6 +411,11 @@ mod tests {
                     oprs: [None, None, None, None, None],
                     addt: None,
                     line: 0,
+                    mask: None,
+                    rounding: None,
+                    symbol: None,
+                    pseudo: BoolTable8::new(),
+                    cpu: None,
                     mnem: ins::Mnemonic::__LAST,
                 }),
                 0,
@@ -390,6 +426,11 @@ mod tests {
                     oprs: [None, None, None, None, None],
                     addt: None,
                     line: 0,
+                    mask: None,
+                    rounding: None,
+                    symbol: None,
+                    pseudo: BoolTable8::new(),
+                    cpu: None,
                     mnem: ins::Mnemonic::__LAST,
                 }),
                 0,
@@ -416,9 +457,15 @@ mod tests {
                             oprs: [None, None, None, None, None],
                             addt: None,
                             line: 0,
+                            mask: None,
+                            rounding: None,
+                            symbol: None,
+                            pseudo: BoolTable8::new(),
+                            cpu: None,
                             mnem: ins::Mnemonic::__LAST,
                         }],
                         shidx: 0,
+                        fill: None,
                     },
                     Label {
                         name: String::from("tesy"),
@@ -429,9 +476,15 @@ mod tests {
                             oprs: [None, None, None, None, None],
                             addt: None,
                             line: 0,
+                            mask: None,
+                            rounding: None,
+                            symbol: None,
+                            pseudo: BoolTable8::new(),
+                            cpu: None,
                             mnem: ins::Mnemonic::__LAST,
                         }],
                         shidx: 0,
+                        fill: None,
                     },
                 ],
                 bits: 64
@@ -452,9 +505,15 @@ mod tests {
                             oprs: [None, None, None, None, None],
                             addt: None,
                             line: 0,
+                            mask: None,
+                            rounding: None,
+                            symbol: None,
+                            pseudo: BoolTable8::new(),
+                            cpu: None,
                             mnem: ins::Mnemonic::__LAST,
                         }],
                         shidx: 1,
+                        fill: None,
                     },
                     Label {
                         name: String::from("tesy"),
@@ -465,9 +524,15 @@ mod tests {
                             oprs: [None, None, None, None, None],
                             addt: None,
                             line: 0,
+                            mask: None,
+                            rounding: None,
+                            symbol: None,
+                            pseudo: BoolTable8::new(),
+                            cpu: None,
                             mnem: ins::Mnemonic::__LAST,
                         }],
                         shidx: 1,
+                        fill: None,
                     },
                 ],
                 bits: 64
@@ -475,4 +540,14 @@ mod tests {
         ]
         );
     }
+    #[test]
+    fn default_section_test() {
+        use crate::pre::{lex::Lexer, tok::Tokenizer};
+        let src = ["_start:", "nop"];
+        let toks = src.iter().map(|l| Tokenizer::tokenize_line(l)).collect();
+        let ast = Parser::build_tree(Lexer::parse_file(toks)).unwrap();
+        // code outside of declared sections keeps default (not executable) attributes
+        assert_eq!(ast.sections[0].name, ".rasm.default");
+        assert!(!ast.sections[0].attributes.exec());
+    }
 }
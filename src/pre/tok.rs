@@ -58,6 +58,15 @@ fn post_process(toks: &mut [Token]) {
                 Ok(s) => *t = Token::SymbolRefExt(s),
                 Err(e) => *t = Token::Error(e),
             },
+            // far pointer (like `$0x08:@(pm_entry:4)`)
+            Token::Modifier(_, r) => {
+                if let Token::Closure(PREFIX_REF, content) = r.as_ref() {
+                    *r.as_mut() = match SymbolRef::try_new(content) {
+                        Ok(s) => Token::SymbolRefExt(s),
+                        Err(e) => Token::Error(e),
+                    };
+                }
+            }
             _ => continue,
         }
     }
@@ -89,6 +98,15 @@ impl Tokenizer {
                 (Some('"'), c) => tmp_buf.push(c),
 
                 (None, ':') => {
+                    // memory with modifier (like `(%rax):k1`)
+                    if tmp_buf.is_empty() {
+                        if let Some(Token::Closure(' ', _)) = tokens.last() {
+                            tmp_toks.push(tokens.pop().unwrap());
+                            closure_pfx = None;
+                            inside_closure = Some(':');
+                            continue;
+                        }
+                    }
                     tokens.push(Token::Label(String::from_iter(tmp_buf.iter())));
                     tmp_buf.clear();
                 }
@@ -119,7 +137,7 @@ impl Tokenizer {
                         tmp_buf.push(':');
                     }
                 }
-                (Some(':'), PREFIX_KWD | PREFIX_REG | PREFIX_VAL) => {
+                (Some(':'), PREFIX_KWD | PREFIX_REG | PREFIX_VAL | PREFIX_REF) => {
                     if delimeter_count == 0 {
                         closure_pfx = Some(c);
                     } else {
@@ -145,6 +163,24 @@ impl Tokenizer {
                         delimeter_count -= 1;
                     }
                 }
+                (Some(':'), ',') => {
+                    if delimeter_count == 0 {
+                        if !tmp_buf.is_empty() {
+                            tmp_toks.push(Token::make_from(
+                                closure_pfx,
+                                String::from_iter(tmp_buf.iter()),
+                            ));
+                        }
+                        tmp_buf.clear();
+                        tokens.push(Token::make_modifier(tmp_toks));
+                        tokens.push(Token::Comma);
+                        tmp_toks = Vec::with_capacity(4);
+                        inside_closure = None;
+                        closure_pfx = None;
+                    } else {
+                        tmp_buf.push(c);
+                    }
+                }
                 (Some(':'), ' ') => {
                     if delimeter_count == 0 {
                         if !tmp_buf.is_empty() {
@@ -161,6 +197,26 @@ impl Tokenizer {
                     }
                 }
 
+                (None | Some(PREFIX_REG | PREFIX_VAL | PREFIX_KWD), DECORATOR_START) => {
+                    if !tmp_buf.is_empty() {
+                        tokens.push(Token::make_from(
+                            inside_closure,
+                            String::from_iter(tmp_buf.iter()),
+                        ));
+                        tmp_buf.clear();
+                    }
+                    inside_closure = Some(DECORATOR_START);
+                }
+                (Some(DECORATOR_START), DECORATOR_END) => {
+                    tokens.push(Token::make_closure(
+                        DECORATOR_START,
+                        String::from_iter(tmp_buf.iter()),
+                    ));
+                    tmp_buf.clear();
+                    inside_closure = None;
+                }
+                (Some(DECORATOR_START), ' ' | '\t') => continue,
+
                 (Some(CLOSURE_START), ',') => tmp_buf.push(c),
 
                 (Some(PREFIX_REG | PREFIX_KWD | PREFIX_VAL), ',') => {
@@ -330,6 +386,7 @@ impl ToString for Token {
             Self::Segment(s) => s.to_string(),
             Self::Comma => ','.to_string(),
             Self::UnknownSegment(s, _) => s.to_string(),
+            Self::Closure(DECORATOR_START, content) => format!("{{{content}}}"),
             Self::Closure(pfx, content) => format!("{pfx}({content})"),
             Self::Modifier(content, next) => {
                 format!("{}:{}", content.to_string(), next.to_string())
@@ -397,6 +454,15 @@ mod tests {
                 ))
             )]
         );
+        let str = "$0x08:@pm_entry";
+        let tokens = Tokenizer::tokenize_line(str);
+        assert_eq!(
+            tokens,
+            vec![Token::Modifier(
+                Box::new(Token::Immediate(Number::uint64(0x08))),
+                Box::new(Token::SymbolRef("pm_entry".to_string()))
+            )]
+        );
         let str = "mov %edi, %ds:(%rbx + %rcx * $4 - $10) .dword";
         let tokens = Tokenizer::tokenize_line(str);
         assert_eq!(
@@ -417,6 +483,51 @@ mod tests {
             Tokenizer::tokenize_line(str),
             vec![Token::String("Hello, World!".to_string())]
         );
+        let str = "vaddps %xmm1:k1:z, %xmm2, %xmm3";
+        assert_eq!(
+            Tokenizer::tokenize_line(str),
+            vec![
+                Token::Mnemonic(crate::shr::ins::Mnemonic::VADDPS),
+                Token::Modifier(
+                    Box::new(Token::Register(Register::XMM1)),
+                    Box::new(Token::Modifier(
+                        Box::new(Token::Unknown("k1".to_string())),
+                        Box::new(Token::Unknown("z".to_string()))
+                    ))
+                ),
+                Token::Comma,
+                Token::Register(Register::XMM2),
+                Token::Comma,
+                Token::Register(Register::XMM3),
+            ]
+        );
+        let str = "(%rax):%k1 .xword";
+        assert_eq!(
+            Tokenizer::tokenize_line(str),
+            vec![
+                Token::Modifier(
+                    Box::new(Token::Closure(' ', "%rax".to_string())),
+                    Box::new(Token::Register(Register::K1))
+                ),
+                Token::Keyword(Keyword::Xword)
+            ]
+        );
+        let str = "vaddps %zmm0, %zmm1, (%rax){1to16} .dword, {rn-sae}";
+        assert_eq!(
+            Tokenizer::tokenize_line(str),
+            vec![
+                Token::Mnemonic(crate::shr::ins::Mnemonic::VADDPS),
+                Token::Register(Register::ZMM0),
+                Token::Comma,
+                Token::Register(Register::ZMM1),
+                Token::Comma,
+                Token::Closure(' ', "%rax".to_string()),
+                Token::Closure('{', "1to16".to_string()),
+                Token::Keyword(Keyword::Dword),
+                Token::Comma,
+                Token::Closure('{', "rn-sae".to_string()),
+            ]
+        );
         let str = "@(symbol:rel:+10)";
         assert_eq!(
             Tokenizer::tokenize_line(str),
@@ -6,9 +6,11 @@
 use crate::shr::{ast::AST, error::RASMError};
 
 pub mod math;
+pub mod pseudo;
 
 // here we run code before assembling phase is started
 pub fn post_process(ast: &mut AST) -> Result<(), RASMError> {
     math::post_process(ast)?;
+    pseudo::post_process(ast)?;
     Ok(())
 }
@@ -0,0 +1,61 @@
+// rasmx86_64 - src/pre_core/pseudo.rs
+// -----------------------------------
+// made by matissoss
+// licensed under MPL 2.0
+
+use std::str::FromStr;
+
+use crate::{
+    pre::chk,
+    shr::{
+        ast::{Instruction, AST},
+        error::RASMError,
+        ins::Mnemonic,
+        pseudo::Pseudo,
+    },
+};
+
+// `{vex}` (or `{vex3}`) with legacy SSE instruction is replaced with its VEX form
+// (like `{vex} addps %xmm0, %xmm1` becoming `vaddps %xmm0, %xmm0, %xmm1`)
+pub fn post_process(ast: &mut AST) -> Result<(), RASMError> {
+    for sec in &mut ast.sections {
+        for l in &mut sec.content {
+            for ins in &mut l.inst {
+                if (ins.pseudo(Pseudo::Vex) || ins.pseudo(Pseudo::Vex3)) && !ins.mnem.is_avx() {
+                    *ins = vex_form(ins, l.bits)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+// legacy destination is also first source, so VEX form either has the same
+// operands or uses destination as non-destructive source (VEX.vvvv)
+// (same operands are tried first: checker does not reject extra ones)
+fn vex_form(ins: &Instruction, bits: u8) -> Result<Instruction, RASMError> {
+    if let Ok(mnem) = Mnemonic::from_str(&format!("v{}", ins.mnem.to_string())) {
+        let same = Instruction {
+            mnem,
+            ..ins.clone()
+        };
+        if chk::check_ins(&same, bits).is_none() {
+            return Ok(same);
+        }
+        let mut nds = same;
+        if nds.oprs[4].is_none() {
+            nds.oprs.rotate_right(1);
+            nds.oprs[0] = nds.oprs[1].clone();
+            if chk::check_ins(&nds, bits).is_none() {
+                return Ok(nds);
+            }
+        }
+    }
+    Err(RASMError::no_tip(
+        Some(ins.line),
+        Some(format!(
+            "Instruction {} has no VEX form that could be used with {{vex}}",
+            ins.mnem.to_string()
+        )),
+    ))
+}
@@ -8,14 +8,19 @@ use std::path::PathBuf;
 use crate::pre::tok::Token;
 use crate::shr::{
     atype::{AType, ToAType},
+    booltable::BoolTable8,
     error::RASMError,
     ins::Mnemonic,
+    isa::Cpu,
+    mask::Mask,
     math::MathematicalEvaluation as MathEval,
     mem::Mem,
     num::Number,
+    pseudo::Pseudo,
     reg::{Purpose as RPurpose, Register},
+    rounding::Rounding,
     section::Section,
-    segment::Segment,
+    segment::{FarPtr, Segment},
     size::Size,
     symbol::{SymbolRef, Visibility},
 };
@@ -32,6 +37,7 @@ pub enum Operand {
     SymbolRefExt(SymbolRef),
     String(String),
     Segment(Segment),
+    FarPtr(FarPtr),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -40,13 +46,24 @@ pub struct Instruction {
     pub addt: Option<Mnemonic>,
     pub oprs: [Option<Operand>; 5],
     pub line: usize,
+    pub mask: Option<Mask>,
+    pub rounding: Option<Rounding>,
+    // symbol used as displacement of memory operand
+    pub symbol: Option<SymbolRef>,
+    // encoding-override pseudo prefixes (indexed by Pseudo)
+    pub pseudo: BoolTable8,
+    // target CPU selected with last `.cpu` before instruction (None if there wasn't any)
+    pub cpu: Option<Cpu>,
 }
 
+// nodes only live between lexer and parser, so boxing instructions isn't worth it
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone)]
 pub enum ASTNode {
     Ins(Instruction),
     Attributes(String),
     Bits(u8),
+    Cpu(Cpu),
     Entry(String),
     Label(String),
     Extern(String),
@@ -66,6 +83,8 @@ pub struct Label {
     pub inst: Vec<Instruction>,
     pub shidx: usize,
     pub align: u16,
+    // custom byte used to pad label (if it is aligned)
+    pub fill: Option<u8>,
     pub visibility: Visibility,
     pub bits: u8,
 }
@@ -90,6 +109,7 @@ pub enum IVariant {
     MMX,
     XMM, // SSE/AVX
     YMM, // AVX
+    ZMM, // AVX-512
 }
 
 // implementations
@@ -163,6 +183,7 @@ impl Operand {
             Self::Segment(s) => s.address.size().unwrap_or(Size::Unknown),
             Self::SegReg(_) => Size::Word,
             Self::String(_) => Size::Unknown,
+            Self::FarPtr(_) => Size::Any,
         }
     }
     pub fn ext_atype(&self) -> AType {
@@ -175,6 +196,7 @@ impl Operand {
             Self::SymbolRef(_) | Self::SymbolRefExt(_) => AType::Symbol,
             Self::Segment(s) => s.address.atype(),
             Self::String(_) => AType::Immediate(Size::Unknown),
+            Self::FarPtr(p) => p.atype(),
         }
     }
 }
@@ -188,6 +210,7 @@ impl ToAType for Operand {
             Self::SymbolRef(_) | Self::SymbolRefExt(_) => AType::Symbol,
             Self::String(_) => AType::Immediate(Size::Unknown),
             Self::Segment(s) => s.address.atype(),
+            Self::FarPtr(p) => p.atype(),
         }
     }
 }
@@ -196,6 +219,7 @@ impl Instruction {
     pub fn which_variant(&self) -> IVariant {
         match self.dst() {
             Some(Operand::Reg(r)) => match r.size() {
+                Size::Zword => IVariant::ZMM,
                 Size::Yword => IVariant::YMM,
                 Size::Xword => IVariant::XMM,
                 Size::Qword | Size::Dword => {
@@ -221,6 +245,7 @@ impl Instruction {
                 _ => IVariant::STD,
             },
             Some(Operand::Mem(m)) => match m.size().unwrap_or(Size::Unknown) {
+                Size::Zword => IVariant::ZMM,
                 Size::Yword => IVariant::YMM,
                 Size::Xword => IVariant::XMM,
                 Size::Qword | Size::Dword => match self.src() {
@@ -242,6 +267,30 @@ impl Instruction {
             _ => IVariant::STD,
         }
     }
+    // true if instruction can only be encoded using EVEX prefix
+    pub fn needs_evex(&self) -> bool {
+        if self.mask.is_some() || self.rounding.is_some() {
+            return true;
+        }
+        for o in self.oprs.iter().flatten() {
+            #[allow(clippy::collapsible_match)]
+            match o {
+                Operand::Reg(r) => {
+                    if r.needs_evex() || matches!(r.size(), Size::Zword) {
+                        return true;
+                    }
+                }
+                Operand::Mem(_) | Operand::Segment(_) => {
+                    let m = o.get_mem().unwrap();
+                    if m.bcst().is_some() || matches!(m.size(), Some(Size::Zword)) {
+                        return true;
+                    }
+                }
+                _ => {}
+            }
+        }
+        false
+    }
     pub fn size(&self) -> Size {
         let dst = match &self.dst() {
             Some(o) => o.size(),
@@ -309,6 +358,10 @@ impl Instruction {
         matches!(src, Operand::DbgReg(_))
     }
     #[inline]
+    pub fn pseudo(&self, pfx: Pseudo) -> bool {
+        self.pseudo.get(pfx as u8).unwrap_or(false)
+    }
+    #[inline]
     pub fn dst(&self) -> Option<&Operand> {
         if let Some(Some(o)) = self.oprs.first() {
             Some(o)
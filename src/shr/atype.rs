@@ -28,10 +28,15 @@ pub const R8: AType = AType::Register(RegisterPurpose::General, Size::Byte);
 pub const MMX: AType = AType::Register(RegisterPurpose::Mmx, Size::Any);
 pub const XMM: AType = AType::Register(RegisterPurpose::F128, Size::Any);
 pub const YMM: AType = AType::Register(RegisterPurpose::F256, Size::Any);
+pub const ZMM: AType = AType::Register(RegisterPurpose::F512, Size::Any);
+pub const ST: AType = AType::Register(RegisterPurpose::Fpu, Size::Any);
+pub const K: AType = AType::Register(RegisterPurpose::Mask, Size::Any);
 
 pub const MA: AType = AType::Memory(Size::Any);
+pub const M512: AType = AType::Memory(Size::Zword);
 pub const M256: AType = AType::Memory(Size::Yword);
 pub const M128: AType = AType::Memory(Size::Xword);
+pub const M80: AType = AType::Memory(Size::Tword);
 pub const M64: AType = AType::Memory(Size::Qword);
 pub const M32: AType = AType::Memory(Size::Dword);
 pub const M16: AType = AType::Memory(Size::Word);
@@ -45,6 +50,7 @@ pub enum AType {
     SMemory(Size),                   // segment memory
     ExtendedRegister(Register),
     Symbol,
+    FarPtr,
 }
 
 pub trait ToAType {
@@ -71,6 +77,44 @@ impl ToString for AType {
                 format!("{} imm", sz)
             }
             Self::Symbol => String::from("symbol"),
+            Self::FarPtr => String::from("far pointer"),
+        }
+    }
+}
+
+impl AType {
+    // short name used in operand forms (like `r64`, `m128` or `imm8`)
+    pub fn short_name(&self) -> String {
+        let bits = |sz: &Size| match sz {
+            Size::Byte => "8",
+            Size::Word => "16",
+            Size::Dword => "32",
+            Size::Qword => "64",
+            Size::Tword => "80",
+            Size::Xword => "128",
+            Size::Yword => "256",
+            Size::Zword => "512",
+            _ => "",
+        };
+        match self {
+            Self::Register(RegisterPurpose::General, sz) => format!("r{}", bits(sz)),
+            Self::Register(RegisterPurpose::Mmx, _) => String::from("mm"),
+            Self::Register(RegisterPurpose::F128, _) => String::from("xmm"),
+            Self::Register(RegisterPurpose::F256, _) => String::from("ymm"),
+            Self::Register(RegisterPurpose::F512, _) => String::from("zmm"),
+            Self::Register(RegisterPurpose::Fpu, _) => String::from("st"),
+            Self::Register(RegisterPurpose::Mask, _) => String::from("k"),
+            Self::Register(RegisterPurpose::Sgmnt, _) => String::from("sreg"),
+            Self::Register(RegisterPurpose::Ctrl, _) => String::from("cr"),
+            Self::Register(RegisterPurpose::Dbg, _) => String::from("dr"),
+            Self::Register(RegisterPurpose::IPtr, _) => String::from("ip"),
+            Self::ExtendedRegister(r) => r.to_string(),
+            Self::Memory(sz) => format!("m{}", bits(sz)),
+            Self::SMemory(sz) => format!("seg:m{}", bits(sz)),
+            Self::Immediate(Size::Unknown) => String::from("str"),
+            Self::Immediate(sz) => format!("imm{}", bits(sz)),
+            Self::Symbol => String::from("sym"),
+            Self::FarPtr => String::from("ptr16:32"),
         }
     }
 }
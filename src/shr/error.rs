@@ -9,8 +9,8 @@ use std::{
     fmt::{Display, Error, Formatter},
     fs::{File, OpenOptions},
     io::Read,
-    path::PathBuf,
-    sync::LazyLock,
+    path::{Path, PathBuf},
+    sync::{LazyLock, Mutex},
 };
 
 static ERR_CTX: LazyLock<(File, PathBuf)> = LazyLock::new(|| {
@@ -35,6 +35,66 @@ static FILE: LazyLock<Vec<String>> = LazyLock::new(|| {
     buf.lines().map(|s| s.to_string()).collect::<Vec<String>>()
 });
 
+// where line of preprocessed source (after macro expansion) comes from
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineOrigin {
+    // line in source file (call site, if line comes from macro)
+    pub line: usize,
+    // macro name and line of macro body (outermost expansion first)
+    pub expansion: Vec<(String, usize)>,
+}
+
+type LineMap = (PathBuf, Vec<LineOrigin>);
+
+// line maps of preprocessed files; files without one use their own lines
+static LINE_MAPS: LazyLock<Mutex<Vec<LineMap>>> = LazyLock::new(|| Mutex::new(Vec::new()));
+
+pub fn set_line_map(file: &Path, map: Vec<LineOrigin>) {
+    let mut maps = LINE_MAPS.lock().unwrap();
+    maps.retain(|m| m.0 != file);
+    maps.push((file.to_path_buf(), map));
+}
+
+fn line_origin(file: &Path, line: usize) -> LineOrigin {
+    let maps = LINE_MAPS.lock().unwrap();
+    if let Some(Some(o)) = maps.iter().find(|m| m.0 == file).map(|m| m.1.get(line)) {
+        o.clone()
+    } else {
+        LineOrigin {
+            line,
+            expansion: Vec::new(),
+        }
+    }
+}
+
+// ` at line N` with content of line (and of macro body lines it was expanded from)
+fn line_ctx(file: &[String], path: &Path, line: Option<usize>) -> String {
+    let line = if let Some(l) = line {
+        line_origin(path, l)
+    } else {
+        return String::new();
+    };
+    let ctx = |l: usize| {
+        ColString::new(file.get(l).map(|s| s.trim()).unwrap_or(""))
+            .set_color(Color::GREEN)
+            .set_modf(Modifier::Bold)
+    };
+    let mut s = format!(
+        " at line {}\n\t{}",
+        ColString::new(line.line + 1).set_color(Color::YELLOW),
+        ctx(line.line)
+    );
+    for (name, l) in line.expansion {
+        s.push_str(&format!(
+            "\n\tin expansion of macro `{}` at line {}\n\t{}",
+            name,
+            ColString::new(l + 1).set_color(Color::YELLOW),
+            ctx(l)
+        ));
+    }
+    s
+}
+
 #[allow(unused)]
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum ExceptionType {
@@ -55,36 +115,12 @@ pub fn print_error(r: RASMError, file_name: &PathBuf) {
     let mut fileb = String::new();
     File::read_to_string(&mut File::open(file_name).unwrap(), &mut fileb).unwrap();
     let file: Vec<String> = fileb.lines().map(|s| s.to_string()).collect();
-    let line = r.line;
-
-    let ctx = if let Some(line) = line {
-        Some(&file[line])
-    } else {
-        None
-    };
 
     println!(
-        "{}:\n\tin {}{}{}{}{}",
+        "{}:\n\tin {}{}{}{}",
         r.etype,
         ColString::new(file_name.to_string_lossy()).set_color(Color::YELLOW),
-        if let Some(line) = line {
-            format!(
-                " at line {}",
-                ColString::new(line + 1).set_color(Color::YELLOW)
-            )
-        } else {
-            "".to_string()
-        },
-        if let Some(ctx) = ctx {
-            format!(
-                "\n\t{}",
-                ColString::new(ctx.trim())
-                    .set_color(Color::GREEN)
-                    .set_modf(Modifier::Bold)
-            )
-        } else {
-            "".to_string()
-        },
+        line_ctx(&file, file_name, r.line),
         if let Some(msg) = &r.msg {
             format!("\n\t---\n\t{}", msg)
         } else {
@@ -108,37 +144,12 @@ pub fn print_error(r: RASMError, file_name: &PathBuf) {
 
 impl Display for RASMError {
     fn fmt(&self, frm: &mut Formatter<'_>) -> Result<(), Error> {
-        let line = self.line;
-
-        let ctx = if let Some(line) = line {
-            Some(&FILE[line])
-        } else {
-            None
-        };
-
         writeln!(
             frm,
-            "{}:\n\tin {}{}{}{}{}",
+            "{}:\n\tin {}{}{}{}",
             self.etype,
             ColString::new(ERR_CTX.1.to_string_lossy()).set_color(Color::YELLOW),
-            if let Some(line) = line {
-                format!(
-                    " at line {}",
-                    ColString::new(line + 1).set_color(Color::YELLOW)
-                )
-            } else {
-                "".to_string()
-            },
-            if let Some(ctx) = ctx {
-                format!(
-                    "\n\t{}",
-                    ColString::new(ctx.trim())
-                        .set_color(Color::GREEN)
-                        .set_modf(Modifier::Bold)
-                )
-            } else {
-                "".to_string()
-            },
+            line_ctx(&FILE, &ERR_CTX.1, self.line),
             if let Some(msg) = &self.msg {
                 format!("\n\t---\n\t{}", msg)
             } else {
@@ -3,7 +3,7 @@
 // made by matissoss
 // licensed under MPL 2.0
 
-use crate::shr::{ins_switch, size::Size};
+use crate::shr::{ins_switch, isa::Extension, size::Size};
 use std::str::FromStr;
 
 #[rustfmt::skip]
@@ -16,6 +16,8 @@ pub enum Mnemonic {
     SHR  , SAR , SHL ,
     SAL  , LEA , INC ,
     DEC  , CMP , TEST,
+
+    MOVABS,
     
     JMP, CALL,
 
@@ -305,6 +307,12 @@ pub enum Mnemonic {
     VPMULHRSW,
     // part c extended - tests/*/avx-part2c-ext.asm
     PINSRW, PMAXSW, PMINSW, VPMAXUD, PMAXUD, PMULHUW,
+    // part d - tests/*/avx-part2d.asm
+    VPERMD, VPERMQ, VPERMPS, VPERMPD,
+    VPBROADCASTB, VPBROADCASTW, VPBROADCASTD, VPBROADCASTQ,
+    VBROADCASTI128, VINSERTI128, VEXTRACTI128,
+    VPSLLVD, VPSLLVQ, VPSRLVD, VPSRLVQ, VPSRAVD,
+    VPMASKMOVD, VPMASKMOVQ, VMASKMOVPS,
 
     // this is FMA extension, but it uses VEX, so why not?
     // /tests/*/fma-part1.asm
@@ -362,6 +370,9 @@ pub enum Mnemonic {
 
     VCVTTPD2DQ, VCVTTPS2DQ, VCVTTSD2SI, VCVTTSS2SI,
 
+    // F16C (half-precision conversions)
+    VCVTPH2PS, VCVTPS2PH,
+
     // /tests/*/norm-part1X.asm
     // X = a
     BT,
@@ -400,7 +411,7 @@ pub enum Mnemonic {
     // /tests/*/norm-part3.asm
     LOOP, LOOPE, LOOPNE, LSL, LTR, LZCNT, MOVBE,
     MOVDIRI, MOVSTRB, MOVSTRW, MOVSTRD, MOVSTRQ,
-    MOVZX, MULX, 
+    MOVZX, MOVSX, MOVSXD, MULX, 
     OUTIB, OUTIW, OUTID, OUTRB, OUTRW, OUTRD,
     OUTSB, OUTSD, OUTSW, 
     PEXT,
@@ -423,7 +434,7 @@ pub enum Mnemonic {
     SHRD, SMSW, STAC, STC, STD,
     STI, STOSB, STOSW, STOSD, STOSQ,
     STR, STUI, SYSENTER, SYSEXIT, SYSRET,
-    TESTUI, TPAUSE, UD0, UD1, UD2, UIRET,
+    TESTUI, TPAUSE, TZCNT, UD0, UD1, UD2, UIRET,
     UMONITOR, UMWAIT, VERR, VERW, WAIT, FWAIT,
     WBINVD, WRFSBASE, WRGSBASE, WRMSR,
     WRPKRU,
@@ -434,12 +445,56 @@ pub enum Mnemonic {
     XSAVEC64, XSAVEOPT, XSAVEOPT64, XSAVES, XSAVES64,
     XSETBV, XSUSLDTRK, XTEST,
 
+    // system and far-transfer instructions
+    // /tests/*/system.asm
+    LGDT, LIDT, SGDT, SIDT, SWAPGS,
+    RDFSBASE, RDGSBASE, PUSHA, PUSHAD, POPA, POPAD,
+    JECXZ, JRCXZ, RETF,
+
+    // legacy prefixes (used as additional mnemonic)
+    // /tests/*/prefix.asm
+    LOCK, REP, REPE, REPZ, REPNE, REPNZ,
+
     // /tests/*/sha.asm
     SHA1MSG1, SHA1MSG2,
     SHA1NEXTE, SHA1RNDS4,
     SHA256MSG1, SHA256MSG2,
     SHA256RNDS2,
 
+    // AVX2 gathers (VSIB)
+    // /tests/*/gather.asm
+    VPGATHERDD, VPGATHERDQ, VPGATHERQD, VPGATHERQQ,
+    VGATHERDPS, VGATHERDPD, VGATHERQPS, VGATHERQPD,
+
+    // AVX-512F (EVEX only)
+    // /tests/*/avx512f.asm
+    VMOVDQA32, VMOVDQA64, VMOVDQU32, VMOVDQU64,
+    VPANDD, VPANDQ, VPANDND, VPANDNQ,
+    VPORD, VPORQ, VPXORD, VPXORQ,
+    VPERMT2D, VPERMT2Q, VPERMT2PS, VPERMT2PD,
+    VPERMI2D, VPERMI2Q, VPERMI2PS, VPERMI2PD,
+    VPTERNLOGD, VPTERNLOGQ, VALIGND, VALIGNQ,
+    VPMAXSQ, VPMINSQ, VPMAXUQ, VPMINUQ,
+    VPABSQ, VPCMPD, VPCMPUD, VPCMPQ,
+    VPCMPUQ,
+    // opmask (VEX)
+    KMOVW,
+
+    // x87 FPU
+    // /tests/*/fpu.asm
+    F2XM1, FABS, FADD, FADDP, FBLD, FBSTP, FCHS, FCLEX, FNCLEX,
+    FCMOVB, FCMOVE, FCMOVBE, FCMOVU, FCMOVNB, FCMOVNE, FCMOVNBE, FCMOVNU,
+    FCOM, FCOMP, FCOMPP, FCOMI, FCOMIP, FUCOM, FUCOMP, FUCOMPP, FUCOMI, FUCOMIP,
+    FCOS, FDECSTP, FDIV, FDIVP, FDIVR, FDIVRP, FFREE,
+    FIADD, FICOM, FICOMP, FIDIV, FIDIVR, FILD, FIMUL, FINCSTP, FINIT, FNINIT,
+    FIST, FISTP, FISTTP, FISUB, FISUBR,
+    FLD, FLD1, FLDCW, FLDENV, FLDL2E, FLDL2T, FLDLG2, FLDLN2, FLDPI, FLDZ,
+    FMUL, FMULP, FNOP, FPATAN, FPREM, FPREM1, FPTAN, FRNDINT,
+    FRSTOR, FSAVE, FNSAVE, FSCALE, FSIN, FSINCOS, FSQRT,
+    FST, FSTCW, FNSTCW, FSTENV, FNSTENV, FSTP, FSTSW, FNSTSW,
+    FSUB, FSUBP, FSUBR, FSUBRP, FTST, FXAM, FXCH, FXTRACT, FYL2X, FYL2XP1,
+    FXSAVE, FXSAVE64, FXRSTOR, FXRSTOR64,
+
     // not real x86-64 instructions
     
     // aliases to big endian variant
@@ -468,11 +523,33 @@ impl FromStr for Mnemonic {
 }
 
 impl Mnemonic {
+    // AVX2 gathers (use VSIB memory)
+    pub fn is_gather(&self) -> bool {
+        matches!(
+            self,
+            Self::VPGATHERDD
+                | Self::VPGATHERDQ
+                | Self::VPGATHERQD
+                | Self::VPGATHERQQ
+                | Self::VGATHERDPS
+                | Self::VGATHERDPD
+                | Self::VGATHERQPS
+                | Self::VGATHERQPD
+        )
+    }
     pub fn allows_diff_size(&self, left: Option<Size>, right: Option<Size>) -> bool {
         if matches!(&self, Self::LAR) {
             let l = left.unwrap();
             let r = right.unwrap();
             l == Size::Word && r == Size::Dword
+        } else if matches!(&self, Self::MOVZX | Self::MOVSX) {
+            let l = left.unwrap();
+            let r = right.unwrap();
+            u8::from(r) < u8::from(l)
+        } else if matches!(&self, Self::MOVSXD) {
+            let l = left.unwrap();
+            let r = right.unwrap();
+            l == Size::Qword && r == Size::Dword
         } else if matches!(
             &self,
             Self::SHL
@@ -497,6 +574,209 @@ impl Mnemonic {
         format!("{:?}", self).starts_with('V')
     }
     #[rustfmt::skip]
+    pub fn is_x87(&self) -> bool {
+        matches!(
+            self,
+            Self::F2XM1    | Self::FABS     | Self::FADD     | Self::FADDP    | Self::FBLD |
+            Self::FBSTP    | Self::FCHS     | Self::FCLEX    | Self::FNCLEX   | Self::FCMOVB |
+            Self::FCMOVE   | Self::FCMOVBE  | Self::FCMOVU   | Self::FCMOVNB  | Self::FCMOVNE |
+            Self::FCMOVNBE | Self::FCMOVNU  | Self::FCOM     | Self::FCOMP    | Self::FCOMPP |
+            Self::FCOMI    | Self::FCOMIP   | Self::FUCOM    | Self::FUCOMP   | Self::FUCOMPP |
+            Self::FUCOMI   | Self::FUCOMIP  | Self::FCOS     | Self::FDECSTP  | Self::FDIV |
+            Self::FDIVP    | Self::FDIVR    | Self::FDIVRP   | Self::FFREE    | Self::FIADD |
+            Self::FICOM    | Self::FICOMP   | Self::FIDIV    | Self::FIDIVR   | Self::FILD |
+            Self::FIMUL    | Self::FINCSTP  | Self::FINIT    | Self::FNINIT   | Self::FIST |
+            Self::FISTP    | Self::FISTTP   | Self::FISUB    | Self::FISUBR   | Self::FLD |
+            Self::FLD1     | Self::FLDCW    | Self::FLDENV   | Self::FLDL2E   | Self::FLDL2T |
+            Self::FLDLG2   | Self::FLDLN2   | Self::FLDPI    | Self::FLDZ     | Self::FMUL |
+            Self::FMULP    | Self::FNOP     | Self::FPATAN   | Self::FPREM    | Self::FPREM1 |
+            Self::FPTAN    | Self::FRNDINT  | Self::FRSTOR   | Self::FSAVE    | Self::FNSAVE |
+            Self::FSCALE   | Self::FSIN     | Self::FSINCOS  | Self::FSQRT    | Self::FST |
+            Self::FSTCW    | Self::FNSTCW   | Self::FSTENV   | Self::FNSTENV  | Self::FSTP |
+            Self::FSTSW    | Self::FNSTSW   | Self::FSUB     | Self::FSUBP    | Self::FSUBR |
+            Self::FSUBRP   | Self::FTST     | Self::FXAM     | Self::FXCH     | Self::FXTRACT |
+            Self::FYL2X    | Self::FYL2XP1  | Self::FWAIT    | Self::FXSAVE   | Self::FXSAVE64 |
+            Self::FXRSTOR  | Self::FXRSTOR64
+        )
+    }
+    // integer AVX instructions (their forms with ymm registers require AVX2)
+    #[rustfmt::skip]
+    pub fn is_avx_int(&self) -> bool {
+        matches!(
+            self,
+            Self::VPEXTRB    | Self::VPEXTRW    | Self::VPEXTRD    | Self::VPEXTRQ    | Self::VPINSRB |
+            Self::VPINSRW    | Self::VPINSRD    | Self::VPINSRQ    | Self::VPMAXSB    | Self::VPMAXSW |
+            Self::VPMAXSD    | Self::VPMAXUB    | Self::VPMAXUW    | Self::VPMAXUD    | Self::VPMINSB |
+            Self::VPMINSW    | Self::VPMINSD    | Self::VPMINUB    | Self::VPMINUW    | Self::VPMULDQ |
+            Self::VPMULLD    | Self::VPMULUDQ   | Self::VPMULHW    | Self::VPMULLW    | Self::VPMULHUW |
+            Self::VPMULHRSW  | Self::VPMADDWD   | Self::VPBLENDW   | Self::VPBLENDVB  | Self::VPCMPEQB |
+            Self::VPCMPEQW   | Self::VPCMPEQD   | Self::VPCMPEQQ   | Self::VPCMPGTB   | Self::VPCMPGTW |
+            Self::VPCMPGTD   | Self::VPCMPGTQ   | Self::VPCMPESTRI | Self::VPCMPESTRM | Self::VPCMPISTRI |
+            Self::VPCMPISTRM | Self::VPHMINPOSUW| Self::VPACKUSDW  | Self::VPACKUSWB  | Self::VPACKSSWB |
+            Self::VPACKSSDW  | Self::VPOR       | Self::VPAND      | Self::VPANDN     | Self::VPXOR |
+            Self::VPADDB     | Self::VPADDW     | Self::VPADDD     | Self::VPADDQ     | Self::VPADDSB |
+            Self::VPADDSW    | Self::VPADDUSB   | Self::VPADDUSW   | Self::VPSUBB     | Self::VPSUBW |
+            Self::VPSUBD     | Self::VPSUBQ     | Self::VPSUBSB    | Self::VPSUBSW    | Self::VPSUBUSB |
+            Self::VPSUBUSW   | Self::VPSLLW     | Self::VPSLLD     | Self::VPSLLQ     | Self::VPSRLW |
+            Self::VPSRLD     | Self::VPSRLQ     | Self::VPSRAW     | Self::VPSRAD     | Self::VPSRLDQ |
+            Self::VPUNPCKLBW | Self::VPUNPCKLWD | Self::VPUNPCKLDQ | Self::VPUNPCKHBW | Self::VPUNPCKHWD |
+            Self::VPUNPCKHDQ | Self::VPAVGB     | Self::VPAVGW     | Self::VPHADDW    | Self::VPHADDD |
+            Self::VPHSUBW    | Self::VPHSUBD    | Self::VPALIGNR   | Self::VPSIGNB    | Self::VPSIGNW |
+            Self::VPSIGND    | Self::VMPSADBW   | Self::VMOVNTDQA
+        )
+    }
+    // instructions that can be encoded with EVEX prefix
+    #[rustfmt::skip]
+    pub fn is_avx512(&self) -> bool {
+        matches!(
+            self,
+            Self::VADDPS    | Self::VADDPD    | Self::VSUBPS    | Self::VSUBPD    |
+            Self::VMULPS    | Self::VMULPD    | Self::VDIVPS    | Self::VDIVPD    |
+            Self::VMINPS    | Self::VMINPD    | Self::VMAXPS    | Self::VMAXPD    |
+            Self::VSQRTPS   | Self::VSQRTPD   | Self::VPADDD    | Self::VPADDQ    |
+            Self::VPSUBD    | Self::VPSUBQ    | Self::VPMULLD   | Self::VMOVUPS   |
+            Self::VMOVUPD   | Self::VMOVAPS   | Self::VMOVAPD   |
+
+            Self::VMOVDQA32 | Self::VMOVDQA64 | Self::VMOVDQU32 | Self::VMOVDQU64 |
+            Self::VPANDD    | Self::VPANDQ    | Self::VPANDND   | Self::VPANDNQ   |
+            Self::VPORD     | Self::VPORQ     | Self::VPXORD    | Self::VPXORQ    |
+            Self::VPERMT2D  | Self::VPERMT2Q  | Self::VPERMT2PS | Self::VPERMT2PD |
+            Self::VPERMI2D  | Self::VPERMI2Q  | Self::VPERMI2PS | Self::VPERMI2PD |
+            Self::VPTERNLOGD| Self::VPTERNLOGQ| Self::VALIGND   | Self::VALIGNQ   |
+            Self::VPMAXSQ   | Self::VPMINSQ   | Self::VPMAXUQ   | Self::VPMINUQ   |
+            Self::VPABSQ    | Self::VPCMPD    | Self::VPCMPUD   | Self::VPCMPQ    |
+            Self::VPCMPUQ
+        )
+    }
+    // size of element that can be broadcasted (`{1toN}`) from memory
+    #[rustfmt::skip]
+    pub fn bcst_size(&self) -> Option<Size> {
+        match self {
+            Self::VADDPS    | Self::VSUBPS    | Self::VMULPS    | Self::VDIVPS    |
+            Self::VMINPS    | Self::VMAXPS    | Self::VSQRTPS   | Self::VPADDD    |
+            Self::VPSUBD    | Self::VPMULLD   | Self::VPANDD    | Self::VPANDND   |
+            Self::VPORD     | Self::VPXORD    | Self::VPERMT2D  | Self::VPERMT2PS |
+            Self::VPERMI2D  | Self::VPERMI2PS | Self::VPTERNLOGD| Self::VALIGND   |
+            Self::VPCMPD    | Self::VPCMPUD                                       => Some(Size::Dword),
+
+            Self::VADDPD    | Self::VSUBPD    | Self::VMULPD    | Self::VDIVPD    |
+            Self::VMINPD    | Self::VMAXPD    | Self::VSQRTPD   | Self::VPADDQ    |
+            Self::VPSUBQ    | Self::VPANDQ    | Self::VPANDNQ   | Self::VPORQ     |
+            Self::VPXORQ    | Self::VPERMT2Q  | Self::VPERMT2PD | Self::VPERMI2Q  |
+            Self::VPERMI2PD | Self::VPTERNLOGQ| Self::VALIGNQ   | Self::VPMAXSQ   |
+            Self::VPMINSQ   | Self::VPMAXUQ   | Self::VPMINUQ   | Self::VPABSQ    |
+            Self::VPCMPQ    | Self::VPCMPUQ                                       => Some(Size::Qword),
+            _ => None,
+        }
+    }
+    // allows static rounding (`{rn-sae}`, `{rd-sae}`, `{ru-sae}`, `{rz-sae}`)
+    #[rustfmt::skip]
+    pub fn allows_er(&self) -> bool {
+        matches!(
+            self,
+            Self::VADDPS | Self::VADDPD | Self::VSUBPS  | Self::VSUBPD  |
+            Self::VMULPS | Self::VMULPD | Self::VDIVPS  | Self::VDIVPD  |
+            Self::VSQRTPS| Self::VSQRTPD
+        )
+    }
+    // allows only suppress-all-exceptions (`{sae}`)
+    pub fn allows_sae(&self) -> bool {
+        matches!(
+            self,
+            Self::VMINPS | Self::VMINPD | Self::VMAXPS | Self::VMAXPD
+        )
+    }
+    // legacy prefix byte that additional mnemonic stands for
+    pub fn prefix_byte(&self) -> Option<u8> {
+        match self {
+            Self::LOCK => Some(0xF0),
+            Self::REPNE | Self::REPNZ => Some(0xF2),
+            Self::REP | Self::REPE | Self::REPZ => Some(0xF3),
+            _ => None,
+        }
+    }
+    // instructions that can be used with LOCK prefix (see docs/core.md)
+    #[rustfmt::skip]
+    pub fn allows_lock(&self) -> bool {
+        matches!(
+            self,
+            Self::ADC       | Self::ADD       | Self::AND       | Self::BTC   |
+            Self::BTR       | Self::BTS       | Self::CMPXCHG   | Self::DEC   |
+            Self::CMPXCHG8B | Self::CMPXCHG16B| Self::INC       | Self::NEG   |
+            Self::NOT       | Self::OR        | Self::SBB       | Self::SUB   |
+            Self::XADD      | Self::XCHG      | Self::XOR
+        )
+    }
+    // instructions that can be used with REP prefix
+    #[rustfmt::skip]
+    pub fn allows_rep(&self) -> bool {
+        matches!(
+            self,
+            Self::INSB    | Self::INSW    | Self::INSD    |
+            Self::LODSB   | Self::LODSW   | Self::LODSD   | Self::LODSQ   |
+            Self::MOVSTRB | Self::MOVSTRW | Self::MOVSTRD | Self::MOVSTRQ |
+            Self::OUTSB   | Self::OUTSW   | Self::OUTSD   |
+            Self::STOSB   | Self::STOSW   | Self::STOSD   | Self::STOSQ
+        )
+    }
+    // instructions that can be used with REPE/REPZ and REPNE/REPNZ prefixes
+    #[rustfmt::skip]
+    pub fn allows_repcc(&self) -> bool {
+        matches!(
+            self,
+            Self::CMPSTRB | Self::CMPSTRW | Self::CMPSTRD | Self::CMPSTRQ |
+            Self::SCASB   | Self::SCASW   | Self::SCASD   | Self::SCASQ
+        )
+    }
+    // instructions that have both load and store opcode for reg-reg form (`{load}`/`{store}`)
+    #[rustfmt::skip]
+    pub fn allows_direction(&self) -> bool {
+        matches!(
+            self,
+            Self::MOV     | Self::ADD     | Self::OR      | Self::ADC     |
+            Self::SBB     | Self::AND     | Self::SUB     | Self::XOR     |
+            Self::CMP     | Self::MOVAPS  | Self::MOVUPS  | Self::MOVAPD  |
+            Self::MOVUPD  | Self::MOVSS   | Self::MOVSD   | Self::MOVDQA  |
+            Self::VMOVAPS | Self::VMOVUPS | Self::VMOVAPD | Self::VMOVUPD |
+            Self::VMOVDQA | Self::VMOVDQA32 | Self::VMOVDQA64 | Self::VMOVDQU32 |
+            Self::VMOVDQU64
+        )
+    }
+    // opcode of short (rel8) form of branch instruction
+    pub fn rel8_opcode(&self) -> Option<u8> {
+        match self {
+            Self::JMP => Some(0xEB),
+            Self::LOOP => Some(0xE2),
+            Self::LOOPE => Some(0xE1),
+            Self::LOOPNE => Some(0xE0),
+            Self::JECXZ | Self::JRCXZ => Some(0xE3),
+            Self::JO => Some(0x70),
+            Self::JNO => Some(0x71),
+            Self::JB | Self::JC | Self::JNAE => Some(0x72),
+            Self::JAE | Self::JNB | Self::JNC => Some(0x73),
+            Self::JE | Self::JZ => Some(0x74),
+            Self::JNE | Self::JNZ => Some(0x75),
+            Self::JBE | Self::JNA => Some(0x76),
+            Self::JA | Self::JNBE => Some(0x77),
+            Self::JS => Some(0x78),
+            Self::JNS => Some(0x79),
+            Self::JP | Self::JPE => Some(0x7A),
+            Self::JNP | Self::JPO => Some(0x7B),
+            Self::JL | Self::JNGE => Some(0x7C),
+            Self::JGE | Self::JNL => Some(0x7D),
+            Self::JLE | Self::JNG => Some(0x7E),
+            Self::JG | Self::JNLE => Some(0x7F),
+            _ => None,
+        }
+    }
+    // true if branch has only short (rel8) form
+    pub fn is_rel8_only(&self) -> bool {
+        matches!(
+            self,
+            Self::LOOP | Self::LOOPE | Self::LOOPNE | Self::JECXZ | Self::JRCXZ
+        )
+    }
+    #[rustfmt::skip]
     pub fn defaults_to_64bit(&self) -> bool {
         matches!(
             self,
@@ -508,6 +788,222 @@ impl Mnemonic {
             Self::CVTPS2PD
         )
     }
+    // ISA extension that instruction belongs to; for some instructions it also
+    // depends on operands (see pre/chk.rs:isa_ext)
+    #[rustfmt::skip]
+    pub fn extension(&self) -> Extension {
+        use Extension as E;
+        match self {
+            Self::FISTTP => E::SSE3,
+            Self::FXSAVE | Self::FXSAVE64 | Self::FXRSTOR | Self::FXRSTOR64 => E::Base,
+            _ if self.is_x87() => E::X87,
+
+            Self::MOVD     | Self::MOVQ     | Self::PADDB    | Self::PADDW    |
+            Self::PADDD    | Self::PADDQ    | Self::PADDSB   | Self::PADDSW   |
+            Self::PADDUSB  | Self::PADDUSW  | Self::PSUBB    | Self::PSUBW    |
+            Self::PSUBD    | Self::PSUBSB   | Self::PSUBSW   | Self::PSUBUSB  |
+            Self::PSUBUSW  | Self::PANDN    | Self::PMULHW   | Self::PMULLW   |
+            Self::PMADDWD  | Self::PCMPEQB  | Self::PCMPEQW  | Self::PCMPEQD  |
+            Self::PCMPGTB  | Self::PCMPGTW  | Self::PCMPGTD  | Self::PACKUSWB |
+            Self::PACKSSWB | Self::PACKSSDW | Self::PUNPCKLBW| Self::PUNPCKLWD|
+            Self::PUNPCKLDQ| Self::PUNPCKHBW| Self::PUNPCKHWD| Self::PUNPCKHDQ|
+            Self::POR      | Self::PAND     | Self::PXOR     | Self::PSLLW    |
+            Self::PSLLD    | Self::PSLLQ    | Self::PSRLW    | Self::PSRLD    |
+            Self::PSRLQ    | Self::PSRAW    | Self::PSRAD    | Self::EMMS     => E::MMX,
+
+            Self::ADDPS    | Self::ADDSS    | Self::SUBPS    | Self::SUBSS    |
+            Self::MULPS    | Self::MULSS    | Self::DIVPS    | Self::DIVSS    |
+            Self::RCPPS    | Self::RCPSS    | Self::SQRTPS   | Self::SQRTSS   |
+            Self::RSQRTPS  | Self::RSQRTSS  | Self::MINPS    | Self::MINSS    |
+            Self::MAXPS    | Self::MAXSS    | Self::ORPS     | Self::ANDPS    |
+            Self::ANDNPS   | Self::XORPS    | Self::CMPPS    | Self::CMPSS    |
+            Self::COMISS   | Self::UCOMISS  | Self::SHUFPS   | Self::UNPCKLPS |
+            Self::UNPCKHPS | Self::MOVAPS   | Self::MOVUPS   | Self::MOVSS    |
+            Self::MOVLPS   | Self::MOVHPS   | Self::MOVLHPS  | Self::MOVHLPS  |
+            Self::STMXCSR  | Self::LDMXCSR  | Self::SFENCE   | Self::PREFETCH0|
+            Self::PREFETCH1| Self::PREFETCH2| Self::PREFETCHA| Self::CVTPI2PS |
+            Self::CVTPS2PI | Self::CVTSI2SS | Self::CVTSS2SI | Self::CVTTPS2PI|
+            Self::CVTTSS2SI| Self::PAVGB    | Self::PAVGW    | Self::PINSRW   |
+            Self::PEXTRW   | Self::PMAXSW   | Self::PMINSW   | Self::PMULHUW  => E::SSE,
+
+            Self::ADDPD    | Self::ADDSD    | Self::SUBPD    | Self::SUBSD    |
+            Self::MULPD    | Self::MULSD    | Self::DIVPD    | Self::DIVSD    |
+            Self::SQRTPD   | Self::SQRTSD   | Self::MINPD    | Self::MINSD    |
+            Self::MAXPD    | Self::MAXSD    | Self::ORPD     | Self::ANDPD    |
+            Self::ANDNPD   | Self::XORPD    | Self::CMPPD    | Self::CMPSD    |
+            Self::COMISD   | Self::UCOMISD  | Self::MOVAPD   | Self::MOVUPD   |
+            Self::MOVHPD   | Self::MOVLPD   | Self::MOVSD    | Self::MOVMSKPD |
+            Self::MOVDQA   | Self::MOVQ2DQ  | Self::MOVDQ2Q  | Self::PSUBQ    |
+            Self::PSHUFD   | Self::PSLLDQ   | Self::PSRLDQ   | Self::PMULUDQ  |
+            Self::PSHUFLW  | Self::PSHUFHW  | Self::PUNPCKHQDQ|Self::PUNPCKLQDQ|
+            Self::MASKMOVDQU|Self::MFENCE   | Self::LFENCE   | Self::CLFLUSH  |
+            Self::PAUSE    | Self::MOVNTPD  | Self::MOVNTDQ  | Self::MOVNTI   |
+            Self::CVTPS2DQ | Self::CVTPS2PD | Self::CVTPI2PD | Self::CVTPD2DQ |
+            Self::CVTPD2PI | Self::CVTPD2PS | Self::CVTDQ2PD | Self::CVTDQ2PS |
+            Self::CVTSD2SI | Self::CVTSD2SS | Self::CVTSI2SD | Self::CVTSS2SD |
+            Self::CVTTPD2DQ| Self::CVTTPD2PI| Self::CVTTPS2DQ| Self::CVTTSD2SI => E::SSE2,
+
+            Self::ADDSUBPS | Self::ADDSUBPD | Self::HADDPS   | Self::HSUBPS   |
+            Self::HADDPD   | Self::HSUBPD   | Self::MOVSLDUP | Self::MOVSHDUP |
+            Self::MOVDDUP  | Self::LDDQU    | Self::MONITOR  | Self::MWAIT    => E::SSE3,
+
+            Self::PABSW    | Self::PABSD    | Self::PABSB    | Self::PSIGNW   |
+            Self::PSIGND   | Self::PSIGNB   | Self::PHSUBW   | Self::PHSUBD   |
+            Self::PHADDW   | Self::PHADDD   | Self::PSHUFB   | Self::PHSUBSW  |
+            Self::PHADDSW  | Self::PALIGNR  | Self::PMULHRSW | Self::PMADDUBSW=> E::SSSE3,
+
+            Self::DPPS     | Self::DPPD     | Self::PTEST    | Self::PEXTRB   |
+            Self::PEXTRD   | Self::PEXTRQ   | Self::PINSRB   | Self::PINSRD   |
+            Self::PINSRQ   | Self::PMAXSB   | Self::PMAXSD   | Self::PMAXUW   |
+            Self::PMINSB   | Self::PMINSD   | Self::PMINUW   | Self::PMULDQ   |
+            Self::PMULLD   | Self::BLENDPS  | Self::BLENDPD  | Self::PBLENDW  |
+            Self::PCMPEQQ  | Self::ROUNDPD  | Self::ROUNDPS  | Self::ROUNDSD  |
+            Self::ROUNDSS  | Self::MPSADBW  | Self::BLENDVPS | Self::BLENDVPD |
+            Self::PBLENDVB | Self::INSERTPS | Self::PACKUSDW | Self::MOVNTDQA |
+            Self::EXTRACTPS| Self::PHMINPOSUW|Self::PMAXUD                   => E::SSE4_1,
+
+            Self::CRC32    | Self::PCMPGTQ  | Self::PCMPESTRI | Self::PCMPESTRM|
+            Self::PCMPISTRI| Self::PCMPISTRM                                 => E::SSE4_2,
+
+            Self::POPCNT => E::POPCNT,
+            Self::CMPXCHG16B => E::CX16,
+
+            Self::VPERMD   | Self::VPERMQ   | Self::VPERMPS  | Self::VPERMPD  |
+            Self::VPBROADCASTB|Self::VPBROADCASTW|Self::VPBROADCASTD|Self::VPBROADCASTQ|
+            Self::VBROADCASTI128|Self::VINSERTI128|Self::VEXTRACTI128|Self::VPERM2I128|
+            Self::VPSLLVD  | Self::VPSLLVQ  | Self::VPSRLVD  | Self::VPSRLVQ  |
+            Self::VPSRAVD  | Self::VPMASKMOVD| Self::VPMASKMOVQ              => E::AVX2,
+            _ if self.is_gather() => E::AVX2,
+
+            Self::VCVTPH2PS | Self::VCVTPS2PH => E::F16C,
+
+            Self::VFMADD132PD   | Self::VFMADD132PS   | Self::VFMADD132SD   | Self::VFMADD132SS |
+            Self::VFMADD213PD   | Self::VFMADD213PS   | Self::VFMADD213SD   | Self::VFMADD213SS |
+            Self::VFMADD231PD   | Self::VFMADD231PS   | Self::VFMADD231SD   | Self::VFMADD231SS |
+            Self::VFMADDSUB132PD| Self::VFMADDSUB132PS| Self::VFMADDSUB213PD| Self::VFMADDSUB213PS |
+            Self::VFMADDSUB231PD| Self::VFMADDSUB231PS| Self::VFMSUB132PD   | Self::VFMSUB132PS |
+            Self::VFMSUB132SD   | Self::VFMSUB132SS   | Self::VFMSUB213PD   | Self::VFMSUB213PS |
+            Self::VFMSUB213SD   | Self::VFMSUB213SS   | Self::VFMSUB231PD   | Self::VFMSUB231PS |
+            Self::VFMSUB231SD   | Self::VFMSUB231SS   | Self::VFMSUBADD132PD| Self::VFMSUBADD132PS |
+            Self::VFMSUBADD213PD| Self::VFMSUBADD213PS| Self::VFMSUBADD231PD| Self::VFMSUBADD231PS |
+            Self::VFNMADD132PD  | Self::VFNMADD132PS  | Self::VFNMADD132SD  | Self::VFNMADD132SS |
+            Self::VFNMADD213PD  | Self::VFNMADD213PS  | Self::VFNMADD213SD  | Self::VFNMADD213SS |
+            Self::VFNMADD231PD  | Self::VFNMADD231PS  | Self::VFNMADD231SD  | Self::VFNMADD231SS |
+            Self::VFNMSUB132PD  | Self::VFNMSUB132PS  | Self::VFNMSUB132SD  | Self::VFNMSUB132SS |
+            Self::VFNMSUB213PD  | Self::VFNMSUB213PS  | Self::VFNMSUB213SD  | Self::VFNMSUB213SS |
+            Self::VFNMSUB231PD  | Self::VFNMSUB231PS  | Self::VFNMSUB231SD  | Self::VFNMSUB231SS => E::FMA,
+
+            Self::AESDEC   | Self::AESENC   | Self::AESIMC   | Self::AESDECLAST|
+            Self::AESENCLAST|Self::AESKEYGENASSIST| Self::VAESDEC| Self::VAESENC|
+            Self::VAESIMC  | Self::VAESDECLAST| Self::VAESENCLAST|
+            Self::VAESKEYGENASSIST                                          => E::AES,
+            Self::PCLMULQDQ | Self::VPCLMULQDQ => E::PCLMUL,
+
+            Self::VMOVDQA32| Self::VMOVDQA64| Self::VMOVDQU32| Self::VMOVDQU64|
+            Self::VPANDD   | Self::VPANDQ   | Self::VPANDND  | Self::VPANDNQ  |
+            Self::VPORD    | Self::VPORQ    | Self::VPXORD   | Self::VPXORQ   |
+            Self::VPERMT2D | Self::VPERMT2Q | Self::VPERMT2PS| Self::VPERMT2PD|
+            Self::VPERMI2D | Self::VPERMI2Q | Self::VPERMI2PS| Self::VPERMI2PD|
+            Self::VPTERNLOGD|Self::VPTERNLOGQ| Self::VALIGND | Self::VALIGNQ  |
+            Self::VPMAXSQ  | Self::VPMINSQ  | Self::VPMAXUQ  | Self::VPMINUQ  |
+            Self::VPABSQ   | Self::VPCMPD   | Self::VPCMPUD  | Self::VPCMPQ   |
+            Self::VPCMPUQ  | Self::KMOVW                                    => E::AVX512F,
+            Self::VERR | Self::VERW => E::Base,
+            Self::VMOVAPS       | Self::VMOVUPS       | Self::VADDPS        | Self::VADDSS |
+            Self::VSUBPS        | Self::VSUBSS        | Self::VMULPS        | Self::VMULSS |
+            Self::VDIVPS        | Self::VDIVSS        | Self::VRCPPS        | Self::VRCPSS |
+            Self::VSQRTPS       | Self::VSQRTSS       | Self::VRSQRTPS      | Self::VRSQRTSS |
+            Self::VMINPS        | Self::VMINSS        | Self::VMAXPS        | Self::VMAXSS |
+            Self::VORPS         | Self::VANDPS        | Self::VANDNPS       | Self::VXORPS |
+            Self::VCMPPS        | Self::VCMPSS        | Self::VCOMISS       | Self::VUCOMISS |
+            Self::VSHUFPS       | Self::VUNPCKLPS     | Self::VUNPCKHPS     | Self::VMOVSS |
+            Self::VMOVLPS       | Self::VMOVHPS       | Self::VMOVLHPS      | Self::VMOVHLPS |
+            Self::VADDPD        | Self::VADDSD        | Self::VSUBPD        | Self::VSUBSD |
+            Self::VMULPD        | Self::VMULSD        | Self::VDIVPD        | Self::VDIVSD |
+            Self::VSQRTPD       | Self::VSQRTSD       | Self::VMINPD        | Self::VMINSD |
+            Self::VMAXPD        | Self::VMAXSD        | Self::VORPD         | Self::VANDPD |
+            Self::VANDNPD       | Self::VXORPD        | Self::VCMPPD        | Self::VCMPSD |
+            Self::VCOMISD       | Self::VUCOMISD      | Self::VMOVAPD       | Self::VMOVUPD |
+            Self::VMOVHPD       | Self::VMOVLPD       | Self::VMOVSD        | Self::VMOVMSKPD |
+            Self::VMOVDQA       | Self::VADDSUBPS     | Self::VADDSUBPD     | Self::VHADDPS |
+            Self::VHSUBPS       | Self::VHADDPD       | Self::VHSUBPD       | Self::VMOVSLDUP |
+            Self::VMOVSHDUP     | Self::VMOVDDUP      | Self::VLDDQU        | Self::VDPPS |
+            Self::VDPPD         | Self::VPTEST        | Self::VPEXTRB       | Self::VPEXTRW |
+            Self::VPEXTRD       | Self::VPEXTRQ       | Self::VPINSRB       | Self::VPINSRD |
+            Self::VPINSRQ       | Self::VPMAXSB       | Self::VPMAXSD       | Self::VPMAXUW |
+            Self::VPMINSB       | Self::VPMINSD       | Self::VPMINUW       | Self::VPMULDQ |
+            Self::VPMULLD       | Self::VPMAXUB       | Self::VPMINUB       | Self::VBLENDPS |
+            Self::VBLENDPD      | Self::VPBLENDW      | Self::VPCMPEQQ      | Self::VROUNDPD |
+            Self::VROUNDPS      | Self::VROUNDSD      | Self::VROUNDSS      | Self::VMPSADBW |
+            Self::VPCMPGTQ      | Self::VBLENDVPS     | Self::VBLENDVPD     | Self::VPBLENDVB |
+            Self::VINSERTPS     | Self::VPACKUSDW     | Self::VPCMPESTRI    | Self::VMOVNTDQA |
+            Self::VEXTRACTPS    | Self::VPCMPESTRM    | Self::VPCMPISTRI    | Self::VPCMPISTRM |
+            Self::VPHMINPOSUW   | Self::VPOR          | Self::VMOVD         | Self::VMOVQ |
+            Self::VPAND         | Self::VPXOR         | Self::VPADDB        | Self::VPADDW |
+            Self::VPADDD        | Self::VPADDQ        | Self::VPSUBB        | Self::VPSUBW |
+            Self::VPSUBD        | Self::VPSUBQ        | Self::VPANDN        | Self::VPSLLW |
+            Self::VPSLLD        | Self::VPSLLQ        | Self::VPSRLW        | Self::VPSRLD |
+            Self::VPSRLQ        | Self::VPSRAW        | Self::VPSRAD        | Self::VPSUBSB |
+            Self::VPMULHW       | Self::VPMULLW       | Self::VPADDSB       | Self::VPADDSW |
+            Self::VPSUBSW       | Self::VPSUBUSB      | Self::VPADDUSB      | Self::VPADDUSW |
+            Self::VPSUBUSW      | Self::VPMADDWD      | Self::VPCMPEQB      | Self::VPCMPEQW |
+            Self::VPCMPEQD      | Self::VPCMPGTB      | Self::VPCMPGTW      | Self::VPCMPGTD |
+            Self::VPACKUSWB     | Self::VPACKSSWB     | Self::VPACKSSDW     | Self::VPUNPCKLBW |
+            Self::VPUNPCKLWD    | Self::VPUNPCKLDQ    | Self::VPUNPCKHBW    | Self::VPUNPCKHWD |
+            Self::VPUNPCKHDQ    | Self::VPAVGB        | Self::VPAVGW        | Self::VPHADDW |
+            Self::VPHADDD       | Self::VPHSUBW       | Self::VPHSUBD       | Self::VZEROALL |
+            Self::VPALIGNR      | Self::VZEROUPPER    | Self::VINSERTF128   | Self::VEXTRACTF128 |
+            Self::VBROADCASTSS  | Self::VBROADCASTSD  | Self::VBROADCASTF128| Self::VLDMXCSR |
+            Self::VSTMXCSR      | Self::VMOVMSKPS     | Self::VPERMILPD     | Self::VPERMILPS |
+            Self::VPERM2F128    | Self::VPINSRW       | Self::VPMAXSW       | Self::VPMINSW |
+            Self::VPSRLDQ       | Self::VPSIGND       | Self::VPSIGNB       | Self::VPSIGNW |
+            Self::VPMULUDQ      | Self::VPMULHUW      | Self::VPMULHRSW     | Self::VPMAXUD |
+            Self::VMASKMOVPS    | Self::VCVTPD2DQ     | Self::VCVTPD2PS     | Self::VCVTPS2DQ |
+            Self::VCVTPS2PD     | Self::VCVTSD2SI     | Self::VCVTSD2SS     | Self::VCVTSI2SD |
+            Self::VCVTSI2SS     | Self::VCVTSS2SD     | Self::VCVTSS2SI     | Self::VCVTDQ2PD |
+            Self::VCVTDQ2PS     | Self::VCVTTPD2DQ    | Self::VCVTTPS2DQ    | Self::VCVTTSD2SI |
+            Self::VCVTTSS2SI => E::AVX,
+
+            Self::SHA1MSG1 | Self::SHA1MSG2 | Self::SHA1NEXTE| Self::SHA1RNDS4|
+            Self::SHA256MSG1|Self::SHA256MSG2|Self::SHA256RNDS2              => E::SHA,
+
+            Self::ANDN | Self::BLSI | Self::BLSR | Self::BLSMSK |
+            Self::BEXTR| Self::TZCNT                                        => E::BMI1,
+            Self::BZHI | Self::MULX | Self::PDEP | Self::PEXT   |
+            Self::RORX | Self::SARX | Self::SHLX | Self::SHRX                => E::BMI2,
+            Self::LZCNT => E::LZCNT,
+            Self::MOVBE => E::MOVBE,
+            Self::ADCX | Self::ADOX => E::ADX,
+            Self::RDRAND => E::RDRAND,
+            Self::RDSEED => E::RDSEED,
+            Self::RDFSBASE | Self::RDGSBASE | Self::WRFSBASE | Self::WRGSBASE => E::FSGSBASE,
+            Self::RDTSCP => E::RDTSCP,
+            Self::RDPID => E::RDPID,
+            Self::RDPKRU | Self::WRPKRU => E::PKU,
+            Self::CLAC | Self::STAC => E::SMAP,
+            // ENDBR32/ENDBR64 execute as NOP on CPUs without CET, so they are not here
+            Self::CLRSSBSY | Self::RDSSPD | Self::RDSSPQ | Self::RSTORSSP |
+            Self::SETSSBY                                                    => E::CET,
+            // same goes for XACQUIRE/XRELEASE (HLE)
+            Self::XBEGIN | Self::XEND | Self::XABORT | Self::XTEST => E::RTM,
+            Self::XSUSLDTRK | Self::XRESLDTRK => E::TSXLDTRK,
+            Self::TPAUSE | Self::UMONITOR | Self::UMWAIT => E::WAITPKG,
+            Self::CLUI | Self::STUI | Self::SENDUIPI | Self::TESTUI | Self::UIRET => E::UINTR,
+            Self::SERIALIZE => E::SERIALIZE,
+            Self::HRESET => E::HRESET,
+            Self::CLDEMOTE => E::CLDEMOTE,
+            Self::CLWB => E::CLWB,
+            Self::MOVDIRI => E::MOVDIRI,
+            Self::INVPCID => E::INVPCID,
+            Self::PREFETCHW => E::PREFETCHW,
+            Self::XSAVE | Self::XSAVE64 | Self::XRSTOR | Self::XRSTOR64 |
+            Self::XGETBV | Self::XSETBV                                      => E::XSAVE,
+            Self::XSAVEOPT | Self::XSAVEOPT64 => E::XSAVEOPT,
+            Self::XSAVEC | Self::XSAVEC64 => E::XSAVEC,
+            Self::XSAVES | Self::XSAVES64 | Self::XRSTORS | Self::XRSTORS64 => E::XSAVES,
+            _ => E::Base,
+        }
+    }
 }
 
 impl ToString for Mnemonic {
@@ -523,4 +1019,16 @@ mod tests {
     fn ins_test() {
         assert!(ins_switch::mnem_fromstr("vphminposuw") == Some(Mnemonic::VPHMINPOSUW));
     }
+    #[test]
+    fn extension_test() {
+        assert_eq!(Mnemonic::MOV.extension(), Extension::Base);
+        assert_eq!(Mnemonic::FISTTP.extension(), Extension::SSE3);
+        assert_eq!(Mnemonic::PADDD.extension(), Extension::MMX);
+        assert_eq!(Mnemonic::VFMADD231PS.extension(), Extension::FMA);
+        assert_eq!(Mnemonic::VPGATHERDD.extension(), Extension::AVX2);
+        assert_eq!(Mnemonic::VADDPS.extension(), Extension::AVX);
+        assert_eq!(Mnemonic::VERR.extension(), Extension::Base);
+        assert_eq!(Mnemonic::VPTERNLOGD.extension(), Extension::AVX512F);
+        assert_eq!(Mnemonic::KMOVW.extension(), Extension::AVX512F);
+    }
 }
@@ -19,6 +19,6 @@ pub fn mnem_fromstr(str: &str) -> Option<Ins> {
 	use Ins::*;
 	let r = str.as_bytes();
 	match r.len() {
-		2=>match r[0]{b'b'=>match r[1]{b't'=>s(BT),_=>N}b'o'=>match r[1]{b'r'=>s(OR),_=>N}b'j' => match r[1]{b'a'=>s(JA),b'b'=>s(JB),b'c'=>s(JC),b'e'=>s(JE),b'g'=>s(JG),b'l'=>s(JL),b'o'=>s(JO),b'p'=>s(JP),b's'=>s(JS),b'z'=>s(JZ),_=>N}_=>N}3=>match r[0]{b'h'=>match r[1]{b'l'=>match r[2]{b't'=>s(HLT),_=>N}_=>N}b'i'=>match r[1]{b'n' => match r[2]{b'c'=>s(INC),b't'=>s(INT),_=>N}_=>N}b'p'=>match r[1]{b'o' => match r[2]{b'p'=>s(POP),b'r'=>s(POR),_=>N}_=>N}b'u'=>match r[1]{b'd' => match r[2]{b'0'=>s(UD0),b'1'=>s(UD1),b'2'=>s(UD2),_=>N}_=>N}b'x'=>match r[1]{b'o'=>match r[2]{b'r'=>s(XOR),_=>N}_=>N}b'a' => match r[1]{b'n'=>match r[2]{b'd'=>s(AND),_=>N}b'a' => match r[2]{b'a'=>s(AAA),b'd'=>s(AAD),b'm'=>s(AAM),b's'=>s(AAS),_=>N}b'd' => match r[2]{b'c'=>s(ADC),b'd'=>s(ADD),_=>N}_=>N}b'b' => match r[1]{b's' => match r[2]{b'f'=>s(BSF),b'r'=>s(BSR),_=>N}b't' => match r[2]{b'c'=>s(BTC),b'r'=>s(BTR),b's'=>s(BTS),_=>N}_=>N}b'c' => match r[1]{b'b'=>match r[2]{b'w'=>s(CBW),_=>N}b'd'=>match r[2]{b'q'=>s(CDQ),_=>N}b'q'=>match r[2]{b'o'=>s(CQO),_=>N}b'w'=>match r[2]{b'd'=>s(CWD),_=>N}b'l' => match r[2]{b'c'=>s(CLC),b'd'=>s(CLD),b'i'=>s(CLI),_=>N}b'm' => match r[2]{b'c'=>s(CMC),b'p'=>s(CMP),_=>N}_=>N}b'd' => match r[1]{b'e'=>match r[2]{b'c'=>s(DEC),_=>N}b'i'=>match r[2]{b'v'=>s(DIV),_=>N}b'a' => match r[2]{b'a'=>s(DAA),b's'=>s(DAS),_=>N}_=>N}b'j' => match r[1]{b'a'=>match r[2]{b'e'=>s(JAE),_=>N}b'b'=>match r[2]{b'e'=>s(JBE),_=>N}b'g'=>match r[2]{b'e'=>s(JGE),_=>N}b'l'=>match r[2]{b'e'=>s(JLE),_=>N}b'm'=>match r[2]{b'p'=>s(JMP),_=>N}b'n' => match r[2]{b'a'=>s(JNA),b'b'=>s(JNB),b'c'=>s(JNC),b'e'=>s(JNE),b'g'=>s(JNG),b'l'=>s(JNL),b'o'=>s(JNO),b'p'=>s(JNP),b's'=>s(JNS),b'z'=>s(JNZ),_=>N}b'p' => match r[2]{b'e'=>s(JPE),b'o'=>s(JPO),_=>N}_=>N}b'l' => match r[1]{b'a'=>match r[2]{b'r'=>s(LAR),_=>N}b'e'=>match r[2]{b'a'=>s(LEA),_=>N}b's'=>match r[2]{b'l'=>s(LSL),_=>N}b't'=>match r[2]{b'r'=>s(LTR),_=>N}_=>N}b'm' => match r[1]{b'o'=>match r[2]{b'v'=>s(MOV),_=>N}b'u'=>match r[2]{b'l'=>s(MUL),_=>N}_=>N}b'n' => match r[1]{b'e'=>match r[2]{b'g'=>s(NEG),_=>N}b'o' => match r[2]{b'p'=>s(NOP),b't'=>s(NOT),_=>N}_=>N}b'r' => match r[1]{b'e'=>match r[2]{b't'=>s(RET),_=>N}b's'=>match r[2]{b'm'=>s(RSM),_=>N}b'c' => match r[2]{b'l'=>s(RCL),b'r'=>s(RCR),_=>N}b'o' => match r[2]{b'l'=>s(ROL),b'r'=>s(ROR),_=>N}_=>N}b's' => match r[1]{b'b'=>match r[2]{b'b'=>s(SBB),_=>N}b'u'=>match r[2]{b'b'=>s(SUB),_=>N}b'a' => match r[2]{b'l'=>s(SAL),b'r'=>s(SAR),_=>N}b'h' => match r[2]{b'l'=>s(SHL),b'r'=>s(SHR),_=>N}b't' => match r[2]{b'c'=>s(STC),b'd'=>s(STD),b'i'=>s(STI),b'r'=>s(STR),_=>N}_=>N}_=>N}4=>match r[0]{b'd'=>match r[1]{b'p'=>match r[2]{b'p' => match r[3]{b'd'=>s(DPPD),b's'=>s(DPPS),_=>N}_=>N}_=>N}b'e'=>match r[1]{b'm'=>match r[2]{b'm'=>match r[3]{b's'=>s(EMMS),_=>N}_=>N}_=>N}b'j'=>match r[1]{b'n' => match r[2]{b'a'=>match r[3]{b'e'=>s(JNAE),_=>N}b'b'=>match r[3]{b'e'=>s(JNBE),_=>N}b'g'=>match r[3]{b'e'=>s(JNGE),_=>N}b'l'=>match r[3]{b'e'=>s(JNLE),_=>N}_=>N}_=>N}b'o'=>match r[1]{b'r'=>match r[2]{b'p' => match r[3]{b'd'=>s(ORPD),b's'=>s(ORPS),_=>N}_=>N}_=>N}b'r'=>match r[1]{b'o'=>match r[2]{b'r'=>match r[3]{b'x'=>s(RORX),_=>N}_=>N}_=>N}b't'=>match r[1]{b'e'=>match r[2]{b's'=>match r[3]{b't'=>s(TEST),_=>N}_=>N}_=>N}b'a' => match r[1]{b'n'=>match r[2]{b'd'=>match r[3]{b'n'=>s(ANDN),_=>N}_=>N}b'r'=>match r[2]{b'p'=>match r[3]{b'l'=>s(ARPL),_=>N}_=>N}b'd' => match r[2]{b'c'=>match r[3]{b'x'=>s(ADCX),_=>N}b'o'=>match r[3]{b'x'=>s(ADOX),_=>N}_=>N}_=>N}b'b' => match r[1]{b'l'=>match r[2]{b's' => match r[3]{b'i'=>s(BLSI),b'r'=>s(BLSR),_=>N}_=>N}b'y'=>match r[2]{b't'=>match r[3]{b'e'=>s(BYTE),_=>N}_=>N}b'z'=>match r[2]{b'h'=>match r[3]{b'i'=>s(BZHI),_=>N}_=>N}_=>N}b'c' => match r[1]{b'a'=>match r[2]{b'l'=>match r[3]{b'l'=>s(CALL),_=>N}_=>N}b'd'=>match r[2]{b'q'=>match r[3]{b'e'=>s(CDQE),_=>N}_=>N}b'w'=>match r[2]{b'd'=>match r[3]{b'e'=>s(CWDE),_=>N}_=>N}b'l' => match r[2]{b'a'=>match r[3]{b'c'=>s(CLAC),_=>N}b't'=>match r[3]{b's'=>s(CLTS),_=>N}b'u'=>match r[3]{b'i'=>s(CLUI),_=>N}b'w'=>match r[3]{b'b'=>s(CLWB),_=>N}_=>N}_=>N}b'i' => match r[1]{b'd'=>match r[2]{b'i'=>match r[3]{b'v'=>s(IDIV),_=>N}_=>N}b'm'=>match r[2]{b'u'=>match r[3]{b'l'=>s(IMUL),_=>N}_=>N}b'r'=>match r[2]{b'e'=>match r[3]{b't'=>s(IRET),_=>N}_=>N}b'n' => match r[2]{b'v'=>match r[3]{b'd'=>s(INVD),_=>N}b's' => match r[3]{b'b'=>s(INSB),b'd'=>s(INSD),b'w'=>s(INSW),_=>N}b't' => match r[3]{b'1'=>s(INT1),b'3'=>s(INT3),b'o'=>s(INTO),_=>N}_=>N}_=>N}b'l' => match r[1]{b'a'=>match r[2]{b'h'=>match r[3]{b'f'=>s(LAHF),_=>N}_=>N}b'l'=>match r[2]{b'd'=>match r[3]{b't'=>s(LLDT),_=>N}_=>N}b'm'=>match r[2]{b's'=>match r[3]{b'w'=>s(LMSW),_=>N}_=>N}b'o'=>match r[2]{b'o'=>match r[3]{b'p'=>s(LOOP),_=>N}_=>N}_=>N}b'm' => match r[1]{b'o'=>match r[2]{b'v' => match r[3]{b'd'=>s(MOVD),b'q'=>s(MOVQ),_=>N}_=>N}b'u'=>match r[2]{b'l'=>match r[3]{b'x'=>s(MULX),_=>N}_=>N}_=>N}b'p' => match r[1]{b'a'=>match r[2]{b'n'=>match r[3]{b'd'=>s(PAND),_=>N}_=>N}b'd'=>match r[2]{b'e'=>match r[3]{b'p'=>s(PDEP),_=>N}_=>N}b'e'=>match r[2]{b'x'=>match r[3]{b't'=>s(PEXT),_=>N}_=>N}b'o'=>match r[2]{b'p'=>match r[3]{b'f'=>s(POPF),_=>N}_=>N}b'u'=>match r[2]{b's'=>match r[3]{b'h'=>s(PUSH),_=>N}_=>N}b'x'=>match r[2]{b'o'=>match r[3]{b'r'=>s(PXOR),_=>N}_=>N}_=>N}b's' => match r[1]{b'e'=>match r[2]{b't' => match r[3]{b'a'=>s(SETA),b'b'=>s(SETB),b'c'=>s(SETC),b'e'=>s(SETE),b'g'=>s(SETG),b'l'=>s(SETL),b'o'=>s(SETO),b'p'=>s(SETP),b's'=>s(SETS),b'z'=>s(SETZ),_=>N}_=>N}b'm'=>match r[2]{b's'=>match r[3]{b'w'=>s(SMSW),_=>N}_=>N}b'a' => match r[2]{b'h'=>match r[3]{b'f'=>s(SAHF),_=>N}b'r'=>match r[3]{b'x'=>s(SARX),_=>N}_=>N}b'h' => match r[2]{b'l' => match r[3]{b'd'=>s(SHLD),b'x'=>s(SHLX),_=>N}b'r' => match r[3]{b'd'=>s(SHRD),b'x'=>s(SHRX),_=>N}_=>N}b't' => match r[2]{b'a'=>match r[3]{b'c'=>s(STAC),_=>N}b'r'=>match r[3]{b'z'=>s(STRZ),_=>N}b'u'=>match r[3]{b'i'=>s(STUI),_=>N}_=>N}_=>N}b'v' => match r[1]{b'e'=>match r[2]{b'r' => match r[3]{b'r'=>s(VERR),b'w'=>s(VERW),_=>N}_=>N}b'p'=>match r[2]{b'o'=>match r[3]{b'r'=>s(VPOR),_=>N}_=>N}_=>N}b'w' => match r[1]{b'a'=>match r[2]{b'i'=>match r[3]{b't'=>s(WAIT),_=>N}_=>N}b'o'=>match r[2]{b'r'=>match r[3]{b'd'=>s(WORD),_=>N}_=>N}_=>N}b'x' => match r[1]{b'a'=>match r[2]{b'd'=>match r[3]{b'd'=>s(XADD),_=>N}_=>N}b'c'=>match r[2]{b'h'=>match r[3]{b'g'=>s(XCHG),_=>N}_=>N}b'e'=>match r[2]{b'n'=>match r[3]{b'd'=>s(XEND),_=>N}_=>N}b'l'=>match r[2]{b'a'=>match r[3]{b't'=>s(XLAT),_=>N}_=>N}_=>N}_=>N}5=>match r[0]{b'f'=>match r[1]{b'w'=>match r[2]{b'a'=>match r[3]{b'i'=>match r[4]{b't'=>s(FWAIT),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[1]{b'u'=>match r[2]{b't' => match r[3]{b'i' => match r[4]{b'b'=>s(OUTIB),b'd'=>s(OUTID),b'w'=>s(OUTIW),_=>N}b'r' => match r[4]{b'b'=>s(OUTRB),b'd'=>s(OUTRD),b'w'=>s(OUTRW),_=>N}b's' => match r[4]{b'b'=>s(OUTSB),b'd'=>s(OUTSD),b'w'=>s(OUTSW),_=>N}_=>N}_=>N}_=>N}b'q'=>match r[1]{b'w'=>match r[2]{b'o'=>match r[3]{b'r'=>match r[4]{b'd'=>s(QWORD),_=>N}_=>N}_=>N}_=>N}b'u'=>match r[1]{b'i'=>match r[2]{b'r'=>match r[3]{b'e'=>match r[4]{b't'=>s(UIRET),_=>N}_=>N}_=>N}_=>N}b'w'=>match r[1]{b'r'=>match r[2]{b'm'=>match r[3]{b's'=>match r[4]{b'r'=>s(WRMSR),_=>N}_=>N}_=>N}_=>N}b'a' => match r[1]{b'd'=>match r[2]{b'd' => match r[3]{b'p' => match r[4]{b'd'=>s(ADDPD),b's'=>s(ADDPS),_=>N}b's' => match r[4]{b'd'=>s(ADDSD),b's'=>s(ADDSS),_=>N}_=>N}_=>N}b'n'=>match r[2]{b'd'=>match r[3]{b'p' => match r[4]{b'd'=>s(ANDPD),b's'=>s(ANDPS),_=>N}_=>N}_=>N}_=>N}b'b' => match r[1]{b'e'=>match r[2]{b'x'=>match r[3]{b't'=>match r[4]{b'r'=>s(BEXTR),_=>N}_=>N}_=>N}b's'=>match r[2]{b'w'=>match r[3]{b'a'=>match r[4]{b'p'=>s(BSWAP),_=>N}_=>N}_=>N}_=>N}b'c' => match r[1]{b'p'=>match r[2]{b'u'=>match r[3]{b'i'=>match r[4]{b'd'=>s(CPUID),_=>N}_=>N}_=>N}b'r'=>match r[2]{b'c'=>match r[3]{b'3'=>match r[4]{b'2'=>s(CRC32),_=>N}_=>N}_=>N}b'm' => match r[2]{b'o'=>match r[3]{b'v' => match r[4]{b'a'=>s(CMOVA),b'b'=>s(CMOVB),b'c'=>s(CMOVC),b'e'=>s(CMOVE),b'g'=>s(CMOVG),b'l'=>s(CMOVL),b'o'=>s(CMOVO),b'p'=>s(CMOVP),b's'=>s(CMOVS),b'z'=>s(CMOVZ),_=>N}_=>N}b'p' => match r[3]{b'p' => match r[4]{b'd'=>s(CMPPD),b's'=>s(CMPPS),_=>N}b's' => match r[4]{b'd'=>s(CMPSD),b's'=>s(CMPSS),_=>N}_=>N}_=>N}_=>N}b'd' => match r[1]{b'i'=>match r[2]{b'v' => match r[3]{b'p' => match r[4]{b'd'=>s(DIVPD),b's'=>s(DIVPS),_=>N}b's' => match r[4]{b'd'=>s(DIVSD),b's'=>s(DIVSS),_=>N}_=>N}_=>N}b'w'=>match r[2]{b'o'=>match r[3]{b'r'=>match r[4]{b'd'=>s(DWORD),_=>N}_=>N}_=>N}_=>N}b'e' => match r[1]{b'm'=>match r[2]{b'p'=>match r[3]{b't'=>match r[4]{b'y'=>s(EMPTY),_=>N}_=>N}_=>N}b'n'=>match r[2]{b't'=>match r[3]{b'e'=>match r[4]{b'r'=>s(ENTER),_=>N}_=>N}_=>N}_=>N}b'i' => match r[1]{b'n'=>match r[2]{b'd'=>match r[3]{b'x' => match r[4]{b'b'=>s(INDXB),b'd'=>s(INDXD),b'w'=>s(INDXW),_=>N}_=>N}_=>N}b'r'=>match r[2]{b'e'=>match r[3]{b't' => match r[4]{b'd'=>s(IRETD),b'q'=>s(IRETQ),_=>N}_=>N}_=>N}_=>N}b'l' => match r[1]{b'd'=>match r[2]{b'd'=>match r[3]{b'q'=>match r[4]{b'u'=>s(LDDQU),_=>N}_=>N}_=>N}b'e'=>match r[2]{b'a'=>match r[3]{b'v'=>match r[4]{b'e'=>s(LEAVE),_=>N}_=>N}_=>N}b'z'=>match r[2]{b'c'=>match r[3]{b'n'=>match r[4]{b't'=>s(LZCNT),_=>N}_=>N}_=>N}b'o' => match r[2]{b'd'=>match r[3]{b's' => match r[4]{b'b'=>s(LODSB),b'd'=>s(LODSD),b'q'=>s(LODSQ),b'w'=>s(LODSW),_=>N}_=>N}b'o'=>match r[3]{b'p'=>match r[4]{b'e'=>s(LOOPE),_=>N}_=>N}_=>N}_=>N}b'm' => match r[1]{b'a'=>match r[2]{b'x' => match r[3]{b'p' => match r[4]{b'd'=>s(MAXPD),b's'=>s(MAXPS),_=>N}b's' => match r[4]{b'd'=>s(MAXSD),b's'=>s(MAXSS),_=>N}_=>N}_=>N}b'i'=>match r[2]{b'n' => match r[3]{b'p' => match r[4]{b'd'=>s(MINPD),b's'=>s(MINPS),_=>N}b's' => match r[4]{b'd'=>s(MINSD),b's'=>s(MINSS),_=>N}_=>N}_=>N}b'o'=>match r[2]{b'v' => match r[3]{b'b'=>match r[4]{b'e'=>s(MOVBE),_=>N}b'z'=>match r[4]{b'x'=>s(MOVZX),_=>N}b's' => match r[4]{b'd'=>s(MOVSD),b's'=>s(MOVSS),_=>N}_=>N}_=>N}b'u'=>match r[2]{b'l' => match r[3]{b'p' => match r[4]{b'd'=>s(MULPD),b's'=>s(MULPS),_=>N}b's' => match r[4]{b'd'=>s(MULSD),b's'=>s(MULSS),_=>N}_=>N}_=>N}b'w'=>match r[2]{b'a'=>match r[3]{b'i'=>match r[4]{b't'=>s(MWAIT),_=>N}_=>N}_=>N}_=>N}b'p' => match r[1]{b'o'=>match r[2]{b'p'=>match r[3]{b'f' => match r[4]{b'd'=>s(POPFD),b'q'=>s(POPFQ),_=>N}_=>N}_=>N}b't'=>match r[2]{b'e'=>match r[3]{b's'=>match r[4]{b't'=>s(PTEST),_=>N}_=>N}_=>N}b'u'=>match r[2]{b's'=>match r[3]{b'h'=>match r[4]{b'f'=>s(PUSHF),_=>N}_=>N}_=>N}b'a' => match r[2]{b'b'=>match r[3]{b's' => match r[4]{b'b'=>s(PABSB),b'd'=>s(PABSD),b'w'=>s(PABSW),_=>N}_=>N}b'd'=>match r[3]{b'd' => match r[4]{b'b'=>s(PADDB),b'd'=>s(PADDD),b'q'=>s(PADDQ),b'w'=>s(PADDW),_=>N}_=>N}b'n'=>match r[3]{b'd'=>match r[4]{b'n'=>s(PANDN),_=>N}_=>N}b'u'=>match r[3]{b's'=>match r[4]{b'e'=>s(PAUSE),_=>N}_=>N}b'v'=>match r[3]{b'g' => match r[4]{b'b'=>s(PAVGB),b'w'=>s(PAVGW),_=>N}_=>N}_=>N}b's' => match r[2]{b'l'=>match r[3]{b'l' => match r[4]{b'd'=>s(PSLLD),b'q'=>s(PSLLQ),b'w'=>s(PSLLW),_=>N}_=>N}b'u'=>match r[3]{b'b' => match r[4]{b'b'=>s(PSUBB),b'd'=>s(PSUBD),b'q'=>s(PSUBQ),b'w'=>s(PSUBW),_=>N}_=>N}b'r' => match r[3]{b'a' => match r[4]{b'd'=>s(PSRAD),b'w'=>s(PSRAW),_=>N}b'l' => match r[4]{b'd'=>s(PSRLD),b'q'=>s(PSRLQ),b'w'=>s(PSRLW),_=>N}_=>N}_=>N}_=>N}b'r' => match r[1]{b'c'=>match r[2]{b'p' => match r[3]{b'p'=>match r[4]{b's'=>s(RCPPS),_=>N}b's'=>match r[4]{b's'=>s(RCPSS),_=>N}_=>N}_=>N}b'd' => match r[2]{b'm'=>match r[3]{b's'=>match r[4]{b'r'=>s(RDMSR),_=>N}_=>N}b't'=>match r[3]{b's'=>match r[4]{b'c'=>s(RDTSC),_=>N}_=>N}b'p' => match r[3]{b'i'=>match r[4]{b'd'=>s(RDPID),_=>N}b'm'=>match r[4]{b'c'=>s(RDPMC),_=>N}_=>N}_=>N}_=>N}b's' => match r[1]{b'c'=>match r[2]{b'a'=>match r[3]{b's' => match r[4]{b'b'=>s(SCASB),b'd'=>s(SCASD),b'q'=>s(SCASQ),b'w'=>s(SCASW),_=>N}_=>N}_=>N}b'e'=>match r[2]{b't' => match r[3]{b'a'=>match r[4]{b'e'=>s(SETAE),_=>N}b'b'=>match r[4]{b'e'=>s(SETBE),_=>N}b'g'=>match r[4]{b'e'=>s(SETGE),_=>N}b'l'=>match r[4]{b'e'=>s(SETLE),_=>N}b'n' => match r[4]{b'a'=>s(SETNA),b'b'=>s(SETNB),b'c'=>s(SETNC),b'e'=>s(SETNE),b'g'=>s(SETNG),b'l'=>s(SETNL),b'o'=>s(SETNO),b'p'=>s(SETNP),b's'=>s(SETNS),b'z'=>s(SETNZ),_=>N}b'p' => match r[4]{b'e'=>s(SETPE),b'o'=>s(SETPO),_=>N}_=>N}_=>N}b't'=>match r[2]{b'o'=>match r[3]{b's' => match r[4]{b'b'=>s(STOSB),b'd'=>s(STOSD),b'q'=>s(STOSQ),b'w'=>s(STOSW),_=>N}_=>N}_=>N}b'u'=>match r[2]{b'b' => match r[3]{b'p' => match r[4]{b'd'=>s(SUBPD),b's'=>s(SUBPS),_=>N}b's' => match r[4]{b'd'=>s(SUBSD),b's'=>s(SUBSS),_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'd'=>match r[2]{b'p'=>match r[3]{b'p' => match r[4]{b'd'=>s(VDPPD),b's'=>s(VDPPS),_=>N}_=>N}_=>N}b'm'=>match r[2]{b'o'=>match r[3]{b'v' => match r[4]{b'd'=>s(VMOVD),b'q'=>s(VMOVQ),_=>N}_=>N}_=>N}b'o'=>match r[2]{b'r'=>match r[3]{b'p' => match r[4]{b'd'=>s(VORPD),b's'=>s(VORPS),_=>N}_=>N}_=>N}b'p' => match r[2]{b'a'=>match r[3]{b'n'=>match r[4]{b'd'=>s(VPAND),_=>N}_=>N}b'x'=>match r[3]{b'o'=>match r[4]{b'r'=>s(VPXOR),_=>N}_=>N}_=>N}_=>N}b'x' => match r[1]{b'l'=>match r[2]{b'a'=>match r[3]{b't'=>match r[4]{b'b'=>s(XLATB),_=>N}_=>N}_=>N}b'o'=>match r[2]{b'r'=>match r[3]{b'p' => match r[4]{b'd'=>s(XORPD),b's'=>s(XORPS),_=>N}_=>N}_=>N}b's'=>match r[2]{b'a'=>match r[3]{b'v'=>match r[4]{b'e'=>s(XSAVE),_=>N}_=>N}_=>N}b't'=>match r[2]{b'e'=>match r[3]{b's'=>match r[4]{b't'=>s(XTEST),_=>N}_=>N}_=>N}_=>N}_=>N}6=>match r[0]{b'i'=>match r[1]{b'n'=>match r[2]{b'v'=>match r[3]{b'l'=>match r[4]{b'p'=>match r[5]{b'g'=>s(INVLPG),_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[1]{b'd' => match r[2]{b'p'=>match r[3]{b'k'=>match r[4]{b'r'=>match r[5]{b'u'=>s(RDPKRU),_=>N}_=>N}_=>N}b'r'=>match r[3]{b'a'=>match r[4]{b'n'=>match r[5]{b'd'=>s(RDRAND),_=>N}_=>N}_=>N}b't'=>match r[3]{b's'=>match r[4]{b'c'=>match r[5]{b'p'=>s(RDTSCP),_=>N}_=>N}_=>N}b's' => match r[3]{b'e'=>match r[4]{b'e'=>match r[5]{b'd'=>s(RDSEED),_=>N}_=>N}b's'=>match r[4]{b'p' => match r[5]{b'd'=>s(RDSSPD),b'q'=>s(RDSSPQ),_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[1]{b'm'=>match r[2]{b'w'=>match r[3]{b'a'=>match r[4]{b'i'=>match r[5]{b't'=>s(UMWAIT),_=>N}_=>N}_=>N}_=>N}_=>N}b'a' => match r[1]{b'e'=>match r[2]{b's' => match r[3]{b'd'=>match r[4]{b'e'=>match r[5]{b'c'=>s(AESDEC),_=>N}_=>N}b'e'=>match r[4]{b'n'=>match r[5]{b'c'=>s(AESENC),_=>N}_=>N}b'i'=>match r[4]{b'm'=>match r[5]{b'c'=>s(AESIMC),_=>N}_=>N}_=>N}_=>N}b'n'=>match r[2]{b'd'=>match r[3]{b'n'=>match r[4]{b'p' => match r[5]{b'd'=>s(ANDNPD),b's'=>s(ANDNPS),_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'c'=>match r[3]{b'i'=>match r[4]{b'i'=>match r[5]{b'z'=>s(ASCIIZ),_=>N}_=>N}_=>N}_=>N}_=>N}b'b' => match r[1]{b'l'=>match r[2]{b's'=>match r[3]{b'm'=>match r[4]{b's'=>match r[5]{b'k'=>s(BLSMSK),_=>N}_=>N}_=>N}_=>N}b'y'=>match r[2]{b't'=>match r[3]{b'e' => match r[4]{b'b'=>match r[5]{b'e'=>s(BYTEBE),_=>N}b'l'=>match r[5]{b'e'=>s(BYTELE),_=>N}_=>N}_=>N}_=>N}_=>N}b'c' => match r[1]{b'm'=>match r[2]{b'o'=>match r[3]{b'v' => match r[4]{b'a'=>match r[5]{b'e'=>s(CMOVAE),_=>N}b'b'=>match r[5]{b'e'=>s(CMOVBE),_=>N}b'g'=>match r[5]{b'e'=>s(CMOVGE),_=>N}b'l'=>match r[5]{b'e'=>s(CMOVLE),_=>N}b'n' => match r[5]{b'a'=>s(CMOVNA),b'b'=>s(CMOVNB),b'c'=>s(CMOVNC),b'e'=>s(CMOVNE),b'g'=>s(CMOVNG),b'l'=>s(CMOVNL),b'o'=>s(CMOVNO),b'p'=>s(CMOVNP),b's'=>s(CMOVNS),b'z'=>s(CMOVNZ),_=>N}b'p' => match r[5]{b'e'=>s(CMOVPE),b'o'=>s(CMOVPO),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[2]{b'm'=>match r[3]{b'i'=>match r[4]{b's' => match r[5]{b'd'=>s(COMISD),b's'=>s(COMISS),_=>N}_=>N}_=>N}_=>N}_=>N}b'h' => match r[1]{b'a'=>match r[2]{b'd'=>match r[3]{b'd'=>match r[4]{b'p' => match r[5]{b'd'=>s(HADDPD),b's'=>s(HADDPS),_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b'e'=>match r[3]{b's'=>match r[4]{b'e'=>match r[5]{b't'=>s(HRESET),_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'u'=>match r[3]{b'b'=>match r[4]{b'p' => match r[5]{b'd'=>s(HSUBPD),b's'=>s(HSUBPS),_=>N}_=>N}_=>N}_=>N}_=>N}b'l' => match r[1]{b'f'=>match r[2]{b'e'=>match r[3]{b'n'=>match r[4]{b'c'=>match r[5]{b'e'=>s(LFENCE),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[2]{b'o'=>match r[3]{b'p'=>match r[4]{b'n'=>match r[5]{b'e'=>s(LOOPNE),_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[1]{b'f'=>match r[2]{b'e'=>match r[3]{b'n'=>match r[4]{b'c'=>match r[5]{b'e'=>s(MFENCE),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[2]{b'v' => match r[3]{b'a'=>match r[4]{b'p' => match r[5]{b'd'=>s(MOVAPD),b's'=>s(MOVAPS),_=>N}_=>N}b'd'=>match r[4]{b'q'=>match r[5]{b'a'=>s(MOVDQA),_=>N}_=>N}b'h'=>match r[4]{b'p' => match r[5]{b'd'=>s(MOVHPD),b's'=>s(MOVHPS),_=>N}_=>N}b'l'=>match r[4]{b'p' => match r[5]{b'd'=>s(MOVLPD),b's'=>s(MOVLPS),_=>N}_=>N}b'n'=>match r[4]{b't'=>match r[5]{b'i'=>s(MOVNTI),_=>N}_=>N}b'u'=>match r[4]{b'p' => match r[5]{b'd'=>s(MOVUPD),b's'=>s(MOVUPS),_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[1]{b'a'=>match r[2]{b'd'=>match r[3]{b'd'=>match r[4]{b's' => match r[5]{b'b'=>s(PADDSB),b'w'=>s(PADDSW),_=>N}_=>N}_=>N}_=>N}b'e'=>match r[2]{b'x'=>match r[3]{b't'=>match r[4]{b'r' => match r[5]{b'b'=>s(PEXTRB),b'd'=>s(PEXTRD),b'q'=>s(PEXTRQ),b'w'=>s(PEXTRW),_=>N}_=>N}_=>N}_=>N}b'i'=>match r[2]{b'n'=>match r[3]{b's'=>match r[4]{b'r' => match r[5]{b'b'=>s(PINSRB),b'd'=>s(PINSRD),b'q'=>s(PINSRQ),b'w'=>s(PINSRW),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[2]{b'p'=>match r[3]{b'c'=>match r[4]{b'n'=>match r[5]{b't'=>s(POPCNT),_=>N}_=>N}_=>N}_=>N}b'u'=>match r[2]{b's'=>match r[3]{b'h'=>match r[4]{b'f' => match r[5]{b'd'=>s(PUSHFD),b'q'=>s(PUSHFQ),_=>N}_=>N}_=>N}_=>N}b'h' => match r[2]{b'a'=>match r[3]{b'd'=>match r[4]{b'd' => match r[5]{b'd'=>s(PHADDD),b'w'=>s(PHADDW),_=>N}_=>N}_=>N}b's'=>match r[3]{b'u'=>match r[4]{b'b' => match r[5]{b'd'=>s(PHSUBD),b'w'=>s(PHSUBW),_=>N}_=>N}_=>N}_=>N}b'm' => match r[2]{b'a'=>match r[3]{b'x' => match r[4]{b's' => match r[5]{b'b'=>s(PMAXSB),b'd'=>s(PMAXSD),b'w'=>s(PMAXSW),_=>N}b'u' => match r[5]{b'd'=>s(PMAXUD),b'w'=>s(PMAXUW),_=>N}_=>N}_=>N}b'i'=>match r[3]{b'n' => match r[4]{b's' => match r[5]{b'b'=>s(PMINSB),b'd'=>s(PMINSD),b'w'=>s(PMINSW),_=>N}b'u'=>match r[5]{b'w'=>s(PMINUW),_=>N}_=>N}_=>N}b'u'=>match r[3]{b'l' => match r[4]{b'd'=>match r[5]{b'q'=>s(PMULDQ),_=>N}b'h'=>match r[5]{b'w'=>s(PMULHW),_=>N}b'l' => match r[5]{b'd'=>s(PMULLD),b'w'=>s(PMULLW),_=>N}_=>N}_=>N}_=>N}b's' => match r[2]{b'h'=>match r[3]{b'u'=>match r[4]{b'f' => match r[5]{b'b'=>s(PSHUFB),b'd'=>s(PSHUFD),_=>N}_=>N}_=>N}b'i'=>match r[3]{b'g'=>match r[4]{b'n' => match r[5]{b'b'=>s(PSIGNB),b'd'=>s(PSIGND),b'w'=>s(PSIGNW),_=>N}_=>N}_=>N}b'l'=>match r[3]{b'l'=>match r[4]{b'd'=>match r[5]{b'q'=>s(PSLLDQ),_=>N}_=>N}_=>N}b'r'=>match r[3]{b'l'=>match r[4]{b'd'=>match r[5]{b'q'=>s(PSRLDQ),_=>N}_=>N}_=>N}b'u'=>match r[3]{b'b'=>match r[4]{b's' => match r[5]{b'b'=>s(PSUBSB),b'w'=>s(PSUBSW),_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[1]{b'e'=>match r[2]{b't'=>match r[3]{b'n' => match r[4]{b'a'=>match r[5]{b'e'=>s(SETNAE),_=>N}b'b'=>match r[5]{b'e'=>s(SETNBE),_=>N}b'g'=>match r[5]{b'e'=>s(SETNGE),_=>N}b'l'=>match r[5]{b'e'=>s(SETNLE),_=>N}_=>N}_=>N}_=>N}b'f'=>match r[2]{b'e'=>match r[3]{b'n'=>match r[4]{b'c'=>match r[5]{b'e'=>s(SFENCE),_=>N}_=>N}_=>N}_=>N}b'h'=>match r[2]{b'u'=>match r[3]{b'f'=>match r[4]{b'p'=>match r[5]{b's'=>s(SHUFPS),_=>N}_=>N}_=>N}_=>N}b'q'=>match r[2]{b'r'=>match r[3]{b't' => match r[4]{b'p' => match r[5]{b'd'=>s(SQRTPD),b's'=>s(SQRTPS),_=>N}b's' => match r[5]{b'd'=>s(SQRTSD),b's'=>s(SQRTSS),_=>N}_=>N}_=>N}_=>N}b'y'=>match r[2]{b's'=>match r[3]{b'r'=>match r[4]{b'e'=>match r[5]{b't'=>s(SYSRET),_=>N}_=>N}_=>N}_=>N}_=>N}b't' => match r[1]{b'e'=>match r[2]{b's'=>match r[3]{b't'=>match r[4]{b'u'=>match r[5]{b'i'=>s(TESTUI),_=>N}_=>N}_=>N}_=>N}b'p'=>match r[2]{b'a'=>match r[3]{b'u'=>match r[4]{b's'=>match r[5]{b'e'=>s(TPAUSE),_=>N}_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'c'=>match r[2]{b'm'=>match r[3]{b'p' => match r[4]{b'p' => match r[5]{b'd'=>s(VCMPPD),b's'=>s(VCMPPS),_=>N}b's' => match r[5]{b'd'=>s(VCMPSD),b's'=>s(VCMPSS),_=>N}_=>N}_=>N}_=>N}b'd'=>match r[2]{b'i'=>match r[3]{b'v' => match r[4]{b'p' => match r[5]{b'd'=>s(VDIVPD),b's'=>s(VDIVPS),_=>N}b's' => match r[5]{b'd'=>s(VDIVSD),b's'=>s(VDIVSS),_=>N}_=>N}_=>N}_=>N}b'l'=>match r[2]{b'd'=>match r[3]{b'd'=>match r[4]{b'q'=>match r[5]{b'u'=>s(VLDDQU),_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b'c'=>match r[3]{b'p' => match r[4]{b'p'=>match r[5]{b's'=>s(VRCPPS),_=>N}b's'=>match r[5]{b's'=>s(VRCPSS),_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'u'=>match r[3]{b'b' => match r[4]{b'p' => match r[5]{b'd'=>s(VSUBPD),b's'=>s(VSUBPS),_=>N}b's' => match r[5]{b'd'=>s(VSUBSD),b's'=>s(VSUBSS),_=>N}_=>N}_=>N}_=>N}b'x'=>match r[2]{b'o'=>match r[3]{b'r'=>match r[4]{b'p' => match r[5]{b'd'=>s(VXORPD),b's'=>s(VXORPS),_=>N}_=>N}_=>N}_=>N}b'a' => match r[2]{b'd'=>match r[3]{b'd' => match r[4]{b'p' => match r[5]{b'd'=>s(VADDPD),b's'=>s(VADDPS),_=>N}b's' => match r[5]{b'd'=>s(VADDSD),b's'=>s(VADDSS),_=>N}_=>N}_=>N}b'n'=>match r[3]{b'd'=>match r[4]{b'p' => match r[5]{b'd'=>s(VANDPD),b's'=>s(VANDPS),_=>N}_=>N}_=>N}_=>N}b'm' => match r[2]{b'a'=>match r[3]{b'x' => match r[4]{b'p' => match r[5]{b'd'=>s(VMAXPD),b's'=>s(VMAXPS),_=>N}b's' => match r[5]{b'd'=>s(VMAXSD),b's'=>s(VMAXSS),_=>N}_=>N}_=>N}b'i'=>match r[3]{b'n' => match r[4]{b'p' => match r[5]{b'd'=>s(VMINPD),b's'=>s(VMINPS),_=>N}b's' => match r[5]{b'd'=>s(VMINSD),b's'=>s(VMINSS),_=>N}_=>N}_=>N}b'o'=>match r[3]{b'v'=>match r[4]{b's' => match r[5]{b'd'=>s(VMOVSD),b's'=>s(VMOVSS),_=>N}_=>N}_=>N}b'u'=>match r[3]{b'l' => match r[4]{b'p' => match r[5]{b'd'=>s(VMULPD),b's'=>s(VMULPS),_=>N}b's' => match r[5]{b'd'=>s(VMULSD),b's'=>s(VMULSS),_=>N}_=>N}_=>N}_=>N}b'p' => match r[2]{b't'=>match r[3]{b'e'=>match r[4]{b's'=>match r[5]{b't'=>s(VPTEST),_=>N}_=>N}_=>N}b'a' => match r[3]{b'd'=>match r[4]{b'd' => match r[5]{b'b'=>s(VPADDB),b'd'=>s(VPADDD),b'q'=>s(VPADDQ),b'w'=>s(VPADDW),_=>N}_=>N}b'n'=>match r[4]{b'd'=>match r[5]{b'n'=>s(VPANDN),_=>N}_=>N}b'v'=>match r[4]{b'g' => match r[5]{b'b'=>s(VPAVGB),b'w'=>s(VPAVGW),_=>N}_=>N}_=>N}b's' => match r[3]{b'l'=>match r[4]{b'l' => match r[5]{b'd'=>s(VPSLLD),b'q'=>s(VPSLLQ),b'w'=>s(VPSLLW),_=>N}_=>N}b'u'=>match r[4]{b'b' => match r[5]{b'b'=>s(VPSUBB),b'd'=>s(VPSUBD),b'q'=>s(VPSUBQ),b'w'=>s(VPSUBW),_=>N}_=>N}b'r' => match r[4]{b'a' => match r[5]{b'd'=>s(VPSRAD),b'w'=>s(VPSRAW),_=>N}b'l' => match r[5]{b'd'=>s(VPSRLD),b'q'=>s(VPSRLQ),b'w'=>s(VPSRLW),_=>N}_=>N}_=>N}_=>N}_=>N}b'w' => match r[1]{b'b'=>match r[2]{b'i'=>match r[3]{b'n'=>match r[4]{b'v'=>match r[5]{b'd'=>s(WBINVD),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[2]{b'r'=>match r[3]{b'd' => match r[4]{b'b'=>match r[5]{b'e'=>s(WORDBE),_=>N}b'l'=>match r[5]{b'e'=>s(WORDLE),_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b'p'=>match r[3]{b'k'=>match r[4]{b'r'=>match r[5]{b'u'=>s(WRPKRU),_=>N}_=>N}_=>N}_=>N}_=>N}b'x' => match r[1]{b'a'=>match r[2]{b'b'=>match r[3]{b'o'=>match r[4]{b'r'=>match r[5]{b't'=>s(XABORT),_=>N}_=>N}_=>N}_=>N}b'b'=>match r[2]{b'e'=>match r[3]{b'g'=>match r[4]{b'i'=>match r[5]{b'n'=>s(XBEGIN),_=>N}_=>N}_=>N}_=>N}b'g'=>match r[2]{b'e'=>match r[3]{b't'=>match r[4]{b'b'=>match r[5]{b'v'=>s(XGETBV),_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b's'=>match r[3]{b't'=>match r[4]{b'o'=>match r[5]{b'r'=>s(XRSTOR),_=>N}_=>N}_=>N}_=>N}b's' => match r[2]{b'a'=>match r[3]{b'v'=>match r[4]{b'e' => match r[5]{b'c'=>s(XSAVEC),b's'=>s(XSAVES),_=>N}_=>N}_=>N}b'e'=>match r[3]{b't'=>match r[4]{b'b'=>match r[5]{b'v'=>s(XSETBV),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}7=>match r[0]{b'b'=>match r[1]{b'l'=>match r[2]{b'e'=>match r[3]{b'n'=>match r[4]{b'd'=>match r[5]{b'p' => match r[6]{b'd'=>s(BLENDPD),b's'=>s(BLENDPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'd'=>match r[1]{b'w'=>match r[2]{b'o'=>match r[3]{b'r'=>match r[4]{b'd' => match r[5]{b'b'=>match r[6]{b'e'=>s(DWORDBE),_=>N}b'l'=>match r[6]{b'e'=>s(DWORDLE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[1]{b'n'=>match r[2]{b'd'=>match r[3]{b'b'=>match r[4]{b'r' => match r[5]{b'3'=>match r[6]{b'2'=>s(ENDBR32),_=>N}b'6'=>match r[6]{b'4'=>s(ENDBR64),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'i'=>match r[1]{b'n' => match r[2]{b'p'=>match r[3]{b'o'=>match r[4]{b'r'=>match r[5]{b't' => match r[6]{b'b'=>s(INPORTB),b'd'=>s(INPORTD),b'w'=>s(INPORTW),_=>N}_=>N}_=>N}_=>N}b'v'=>match r[3]{b'p'=>match r[4]{b'c'=>match r[5]{b'i'=>match r[6]{b'd'=>s(INVPCID),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'l'=>match r[1]{b'd'=>match r[2]{b'm'=>match r[3]{b'x'=>match r[4]{b'c'=>match r[5]{b's'=>match r[6]{b'r'=>s(LDMXCSR),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'q'=>match r[1]{b'w'=>match r[2]{b'o'=>match r[3]{b'r'=>match r[4]{b'd' => match r[5]{b'b'=>match r[6]{b'e'=>s(QWORDBE),_=>N}b'l'=>match r[6]{b'e'=>s(QWORDLE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[1]{b'c'=>match r[2]{b'o'=>match r[3]{b'm'=>match r[4]{b'i'=>match r[5]{b's' => match r[6]{b'd'=>s(UCOMISD),b's'=>s(UCOMISS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c' => match r[1]{b'l'=>match r[2]{b'f'=>match r[3]{b'l'=>match r[4]{b'u'=>match r[5]{b's'=>match r[6]{b'h'=>s(CLFLUSH),_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[2]{b'o'=>match r[3]{b'v'=>match r[4]{b'n' => match r[5]{b'a'=>match r[6]{b'e'=>s(CMOVNAE),_=>N}b'b'=>match r[6]{b'e'=>s(CMOVNBE),_=>N}b'g'=>match r[6]{b'e'=>s(CMOVNGE),_=>N}b'l'=>match r[6]{b'e'=>s(CMOVNLE),_=>N}_=>N}_=>N}_=>N}b'p' => match r[3]{b's'=>match r[4]{b't'=>match r[5]{b'r' => match r[6]{b'b'=>s(CMPSTRB),b'd'=>s(CMPSTRD),b'q'=>s(CMPSTRQ),b'w'=>s(CMPSTRW),_=>N}_=>N}_=>N}b'x'=>match r[4]{b'c'=>match r[5]{b'h'=>match r[6]{b'g'=>s(CMPXCHG),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[1]{b'o' => match r[2]{b'n'=>match r[3]{b'i'=>match r[4]{b't'=>match r[5]{b'o'=>match r[6]{b'r'=>s(MONITOR),_=>N}_=>N}_=>N}_=>N}b'v' => match r[3]{b'h'=>match r[4]{b'l'=>match r[5]{b'p'=>match r[6]{b's'=>s(MOVHLPS),_=>N}_=>N}_=>N}b'l'=>match r[4]{b'h'=>match r[5]{b'p'=>match r[6]{b's'=>s(MOVLHPS),_=>N}_=>N}_=>N}b'n'=>match r[4]{b't' => match r[5]{b'd'=>match r[6]{b'q'=>s(MOVNTDQ),_=>N}b'p'=>match r[6]{b'd'=>s(MOVNTPD),_=>N}_=>N}_=>N}b'q'=>match r[4]{b'2'=>match r[5]{b'd'=>match r[6]{b'q'=>s(MOVQ2DQ),_=>N}_=>N}_=>N}b's'=>match r[4]{b't'=>match r[5]{b'r' => match r[6]{b'b'=>s(MOVSTRB),b'd'=>s(MOVSTRD),b'q'=>s(MOVSTRQ),b'w'=>s(MOVSTRW),_=>N}_=>N}_=>N}b'd' => match r[4]{b'd'=>match r[5]{b'u'=>match r[6]{b'p'=>s(MOVDDUP),_=>N}_=>N}b'i'=>match r[5]{b'r'=>match r[6]{b'i'=>s(MOVDIRI),_=>N}_=>N}b'q'=>match r[5]{b'2'=>match r[6]{b'q'=>s(MOVDQ2Q),_=>N}_=>N}_=>N}_=>N}_=>N}b'p'=>match r[2]{b's'=>match r[3]{b'a'=>match r[4]{b'd'=>match r[5]{b'b'=>match r[6]{b'w'=>s(MPSADBW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[1]{b'b'=>match r[2]{b'l'=>match r[3]{b'e'=>match r[4]{b'n'=>match r[5]{b'd'=>match r[6]{b'w'=>s(PBLENDW),_=>N}_=>N}_=>N}_=>N}_=>N}b'c'=>match r[2]{b'm'=>match r[3]{b'p' => match r[4]{b'e'=>match r[5]{b'q' => match r[6]{b'b'=>s(PCMPEQB),b'd'=>s(PCMPEQD),b'q'=>s(PCMPEQQ),b'w'=>s(PCMPEQW),_=>N}_=>N}b'g'=>match r[5]{b't' => match r[6]{b'b'=>s(PCMPGTB),b'd'=>s(PCMPGTD),b'q'=>s(PCMPGTQ),b'w'=>s(PCMPGTW),_=>N}_=>N}_=>N}_=>N}_=>N}b'a' => match r[2]{b'd'=>match r[3]{b'd'=>match r[4]{b'u'=>match r[5]{b's' => match r[6]{b'b'=>s(PADDUSB),b'w'=>s(PADDUSW),_=>N}_=>N}_=>N}_=>N}b'l'=>match r[3]{b'i'=>match r[4]{b'g'=>match r[5]{b'n'=>match r[6]{b'r'=>s(PALIGNR),_=>N}_=>N}_=>N}_=>N}_=>N}b'h' => match r[2]{b'a'=>match r[3]{b'd'=>match r[4]{b'd'=>match r[5]{b's'=>match r[6]{b'w'=>s(PHADDSW),_=>N}_=>N}_=>N}_=>N}b's'=>match r[3]{b'u'=>match r[4]{b'b'=>match r[5]{b's'=>match r[6]{b'w'=>s(PHSUBSW),_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[2]{b'a'=>match r[3]{b'd'=>match r[4]{b'd'=>match r[5]{b'w'=>match r[6]{b'd'=>s(PMADDWD),_=>N}_=>N}_=>N}_=>N}b'u'=>match r[3]{b'l' => match r[4]{b'h'=>match r[5]{b'u'=>match r[6]{b'w'=>s(PMULHUW),_=>N}_=>N}b'u'=>match r[5]{b'd'=>match r[6]{b'q'=>s(PMULUDQ),_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[2]{b'h'=>match r[3]{b'u'=>match r[4]{b'f' => match r[5]{b'h'=>match r[6]{b'w'=>s(PSHUFHW),_=>N}b'l'=>match r[6]{b'w'=>s(PSHUFLW),_=>N}_=>N}_=>N}_=>N}b'u'=>match r[3]{b'b'=>match r[4]{b'u'=>match r[5]{b's' => match r[6]{b'b'=>s(PSUBUSB),b'w'=>s(PSUBUSW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r' => match r[1]{b'o'=>match r[2]{b'u'=>match r[3]{b'n'=>match r[4]{b'd' => match r[5]{b'p' => match r[6]{b'd'=>s(ROUNDPD),b's'=>s(ROUNDPS),_=>N}b's' => match r[6]{b'd'=>s(ROUNDSD),b's'=>s(ROUNDSS),_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'q'=>match r[3]{b'r'=>match r[4]{b't' => match r[5]{b'p'=>match r[6]{b's'=>s(RSQRTPS),_=>N}b's'=>match r[6]{b's'=>s(RSQRTSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[1]{b'e'=>match r[2]{b't'=>match r[3]{b's'=>match r[4]{b's'=>match r[5]{b'b'=>match r[6]{b'y'=>s(SETSSBY),_=>N}_=>N}_=>N}_=>N}_=>N}b't'=>match r[2]{b'm'=>match r[3]{b'x'=>match r[4]{b'c'=>match r[5]{b's'=>match r[6]{b'r'=>s(STMXCSR),_=>N}_=>N}_=>N}_=>N}_=>N}b'y'=>match r[2]{b's' => match r[3]{b'c'=>match r[4]{b'a'=>match r[5]{b'l'=>match r[6]{b'l'=>s(SYSCALL),_=>N}_=>N}_=>N}b'e'=>match r[4]{b'x'=>match r[5]{b'i'=>match r[6]{b't'=>s(SYSEXIT),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'c'=>match r[2]{b'o'=>match r[3]{b'm'=>match r[4]{b'i'=>match r[5]{b's' => match r[6]{b'd'=>s(VCOMISD),b's'=>s(VCOMISS),_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[2]{b'o'=>match r[3]{b'v' => match r[4]{b'a'=>match r[5]{b'p' => match r[6]{b'd'=>s(VMOVAPD),b's'=>s(VMOVAPS),_=>N}_=>N}b'd'=>match r[5]{b'q'=>match r[6]{b'a'=>s(VMOVDQA),_=>N}_=>N}b'h'=>match r[5]{b'p' => match r[6]{b'd'=>s(VMOVHPD),b's'=>s(VMOVHPS),_=>N}_=>N}b'l'=>match r[5]{b'p' => match r[6]{b'd'=>s(VMOVLPD),b's'=>s(VMOVLPS),_=>N}_=>N}b'u'=>match r[5]{b'p' => match r[6]{b'd'=>s(VMOVUPD),b's'=>s(VMOVUPS),_=>N}_=>N}_=>N}_=>N}_=>N}b'a' => match r[2]{b'e'=>match r[3]{b's' => match r[4]{b'd'=>match r[5]{b'e'=>match r[6]{b'c'=>s(VAESDEC),_=>N}_=>N}b'e'=>match r[5]{b'n'=>match r[6]{b'c'=>s(VAESENC),_=>N}_=>N}b'i'=>match r[5]{b'm'=>match r[6]{b'c'=>s(VAESIMC),_=>N}_=>N}_=>N}_=>N}b'n'=>match r[3]{b'd'=>match r[4]{b'n'=>match r[5]{b'p' => match r[6]{b'd'=>s(VANDNPD),b's'=>s(VANDNPS),_=>N}_=>N}_=>N}_=>N}_=>N}b'h' => match r[2]{b'a'=>match r[3]{b'd'=>match r[4]{b'd'=>match r[5]{b'p' => match r[6]{b'd'=>s(VHADDPD),b's'=>s(VHADDPS),_=>N}_=>N}_=>N}_=>N}b's'=>match r[3]{b'u'=>match r[4]{b'b'=>match r[5]{b'p' => match r[6]{b'd'=>s(VHSUBPD),b's'=>s(VHSUBPS),_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[2]{b'a'=>match r[3]{b'd'=>match r[4]{b'd'=>match r[5]{b's' => match r[6]{b'b'=>s(VPADDSB),b'w'=>s(VPADDSW),_=>N}_=>N}_=>N}_=>N}b'e'=>match r[3]{b'x'=>match r[4]{b't'=>match r[5]{b'r' => match r[6]{b'b'=>s(VPEXTRB),b'd'=>s(VPEXTRD),b'q'=>s(VPEXTRQ),b'w'=>s(VPEXTRW),_=>N}_=>N}_=>N}_=>N}b'i'=>match r[3]{b'n'=>match r[4]{b's'=>match r[5]{b'r' => match r[6]{b'b'=>s(VPINSRB),b'd'=>s(VPINSRD),b'q'=>s(VPINSRQ),b'w'=>s(VPINSRW),_=>N}_=>N}_=>N}_=>N}b'h' => match r[3]{b'a'=>match r[4]{b'd'=>match r[5]{b'd' => match r[6]{b'd'=>s(VPHADDD),b'w'=>s(VPHADDW),_=>N}_=>N}_=>N}b's'=>match r[4]{b'u'=>match r[5]{b'b' => match r[6]{b'd'=>s(VPHSUBD),b'w'=>s(VPHSUBW),_=>N}_=>N}_=>N}_=>N}b'm' => match r[3]{b'a'=>match r[4]{b'x' => match r[5]{b's' => match r[6]{b'b'=>s(VPMAXSB),b'd'=>s(VPMAXSD),b'w'=>s(VPMAXSW),_=>N}b'u' => match r[6]{b'b'=>s(VPMAXUB),b'd'=>s(VPMAXUD),b'w'=>s(VPMAXUW),_=>N}_=>N}_=>N}b'i'=>match r[4]{b'n' => match r[5]{b's' => match r[6]{b'b'=>s(VPMINSB),b'd'=>s(VPMINSD),b'w'=>s(VPMINSW),_=>N}b'u' => match r[6]{b'b'=>s(VPMINUB),b'w'=>s(VPMINUW),_=>N}_=>N}_=>N}b'u'=>match r[4]{b'l' => match r[5]{b'd'=>match r[6]{b'q'=>s(VPMULDQ),_=>N}b'h'=>match r[6]{b'w'=>s(VPMULHW),_=>N}b'l' => match r[6]{b'd'=>s(VPMULLD),b'w'=>s(VPMULLW),_=>N}_=>N}_=>N}_=>N}b's' => match r[3]{b'i'=>match r[4]{b'g'=>match r[5]{b'n' => match r[6]{b'b'=>s(VPSIGNB),b'd'=>s(VPSIGND),b'w'=>s(VPSIGNW),_=>N}_=>N}_=>N}b'r'=>match r[4]{b'l'=>match r[5]{b'd'=>match r[6]{b'q'=>s(VPSRLDQ),_=>N}_=>N}_=>N}b'u'=>match r[4]{b'b'=>match r[5]{b's' => match r[6]{b'b'=>s(VPSUBSB),b'w'=>s(VPSUBSW),_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[2]{b'h'=>match r[3]{b'u'=>match r[4]{b'f'=>match r[5]{b'p'=>match r[6]{b's'=>s(VSHUFPS),_=>N}_=>N}_=>N}_=>N}b'q'=>match r[3]{b'r'=>match r[4]{b't' => match r[5]{b'p' => match r[6]{b'd'=>s(VSQRTPD),b's'=>s(VSQRTPS),_=>N}b's' => match r[6]{b'd'=>s(VSQRTSD),b's'=>s(VSQRTSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'x' => match r[1]{b'l'=>match r[2]{b'a'=>match r[3]{b't'=>match r[4]{b'b'=>match r[5]{b'6'=>match r[6]{b'4'=>s(XLATB64),_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b's'=>match r[3]{b't'=>match r[4]{b'o'=>match r[5]{b'r'=>match r[6]{b's'=>s(XRSTORS),_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'a'=>match r[3]{b'v'=>match r[4]{b'e'=>match r[5]{b'6'=>match r[6]{b'4'=>s(XSAVE64),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}8=>match r[0]{b'a'=>match r[1]{b'd'=>match r[2]{b'd'=>match r[3]{b's'=>match r[4]{b'u'=>match r[5]{b'b'=>match r[6]{b'p' => match r[7]{b'd'=>s(ADDSUBPD),b's'=>s(ADDSUBPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'b'=>match r[1]{b'l'=>match r[2]{b'e'=>match r[3]{b'n'=>match r[4]{b'd'=>match r[5]{b'v'=>match r[6]{b'p' => match r[7]{b'd'=>s(BLENDVPD),b's'=>s(BLENDVPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'i'=>match r[1]{b'n'=>match r[2]{b's'=>match r[3]{b'e'=>match r[4]{b'r'=>match r[5]{b't'=>match r[6]{b'p'=>match r[7]{b's'=>s(INSERTPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[1]{b'o'=>match r[2]{b'v' => match r[3]{b'm'=>match r[4]{b's'=>match r[5]{b'k'=>match r[6]{b'p'=>match r[7]{b'd'=>s(MOVMSKPD),_=>N}_=>N}_=>N}_=>N}b'n'=>match r[4]{b't'=>match r[5]{b'd'=>match r[6]{b'q'=>match r[7]{b'a'=>s(MOVNTDQA),_=>N}_=>N}_=>N}_=>N}b's' => match r[4]{b'h'=>match r[5]{b'd'=>match r[6]{b'u'=>match r[7]{b'p'=>s(MOVSHDUP),_=>N}_=>N}_=>N}b'l'=>match r[5]{b'd'=>match r[6]{b'u'=>match r[7]{b'p'=>s(MOVSLDUP),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[1]{b's'=>match r[2]{b't'=>match r[3]{b'o'=>match r[4]{b'r'=>match r[5]{b's'=>match r[6]{b's'=>match r[7]{b'p'=>s(RSTORSSP),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'w'=>match r[1]{b'r' => match r[2]{b'f'=>match r[3]{b's'=>match r[4]{b'b'=>match r[5]{b'a'=>match r[6]{b's'=>match r[7]{b'e'=>s(WRFSBASE),_=>N}_=>N}_=>N}_=>N}_=>N}b'g'=>match r[3]{b's'=>match r[4]{b'b'=>match r[5]{b'a'=>match r[6]{b's'=>match r[7]{b'e'=>s(WRGSBASE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c' => match r[1]{b'v'=>match r[2]{b't' => match r[3]{b'd'=>match r[4]{b'q'=>match r[5]{b'2'=>match r[6]{b'p' => match r[7]{b'd'=>s(CVTDQ2PD),b's'=>s(CVTDQ2PS),_=>N}_=>N}_=>N}_=>N}b'p' => match r[4]{b'd'=>match r[5]{b'2' => match r[6]{b'd'=>match r[7]{b'q'=>s(CVTPD2DQ),_=>N}b'p' => match r[7]{b'i'=>s(CVTPD2PI),b's'=>s(CVTPD2PS),_=>N}_=>N}_=>N}b'i'=>match r[5]{b'2'=>match r[6]{b'p' => match r[7]{b'd'=>s(CVTPI2PD),b's'=>s(CVTPI2PS),_=>N}_=>N}_=>N}b's'=>match r[5]{b'2' => match r[6]{b'd'=>match r[7]{b'q'=>s(CVTPS2DQ),_=>N}b'p' => match r[7]{b'd'=>s(CVTPS2PD),b'i'=>s(CVTPS2PI),_=>N}_=>N}_=>N}_=>N}b's' => match r[4]{b'd'=>match r[5]{b'2'=>match r[6]{b's' => match r[7]{b'i'=>s(CVTSD2SI),b's'=>s(CVTSD2SS),_=>N}_=>N}_=>N}b'i'=>match r[5]{b'2'=>match r[6]{b's' => match r[7]{b'd'=>s(CVTSI2SD),b's'=>s(CVTSI2SS),_=>N}_=>N}_=>N}b's'=>match r[5]{b'2'=>match r[6]{b's' => match r[7]{b'd'=>s(CVTSS2SD),b'i'=>s(CVTSS2SI),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'l' => match r[2]{b'd'=>match r[3]{b'e'=>match r[4]{b'm'=>match r[5]{b'o'=>match r[6]{b't'=>match r[7]{b'e'=>s(CLDEMOTE),_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[3]{b's'=>match r[4]{b's'=>match r[5]{b'b'=>match r[6]{b's'=>match r[7]{b'y'=>s(CLRSSBSY),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[1]{b'a'=>match r[2]{b'c'=>match r[3]{b'k' => match r[4]{b's'=>match r[5]{b's' => match r[6]{b'd'=>match r[7]{b'w'=>s(PACKSSDW),_=>N}b'w'=>match r[7]{b'b'=>s(PACKSSWB),_=>N}_=>N}_=>N}b'u'=>match r[5]{b's' => match r[6]{b'd'=>match r[7]{b'w'=>s(PACKUSDW),_=>N}b'w'=>match r[7]{b'b'=>s(PACKUSWB),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'b'=>match r[2]{b'l'=>match r[3]{b'e'=>match r[4]{b'n'=>match r[5]{b'd'=>match r[6]{b'v'=>match r[7]{b'b'=>s(PBLENDVB),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[2]{b'u'=>match r[3]{b'l'=>match r[4]{b'h'=>match r[5]{b'r'=>match r[6]{b's'=>match r[7]{b'w'=>s(PMULHRSW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[1]{b'e'=>match r[2]{b'n'=>match r[3]{b'd'=>match r[4]{b'u'=>match r[5]{b'i'=>match r[6]{b'p'=>match r[7]{b'i'=>s(SENDUIPI),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'h'=>match r[2]{b'a'=>match r[3]{b'1'=>match r[4]{b'm'=>match r[5]{b's'=>match r[6]{b'g' => match r[7]{b'1'=>s(SHA1MSG1),b'2'=>s(SHA1MSG2),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'y'=>match r[2]{b's'=>match r[3]{b'e'=>match r[4]{b'n'=>match r[5]{b't'=>match r[6]{b'e'=>match r[7]{b'r'=>s(SYSENTER),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'u' => match r[1]{b'm'=>match r[2]{b'o'=>match r[3]{b'n'=>match r[4]{b'i'=>match r[5]{b't'=>match r[6]{b'o'=>match r[7]{b'r'=>s(UMONITOR),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'n'=>match r[2]{b'p'=>match r[3]{b'c'=>match r[4]{b'k' => match r[5]{b'h'=>match r[6]{b'p'=>match r[7]{b's'=>s(UNPCKHPS),_=>N}_=>N}b'l'=>match r[6]{b'p'=>match r[7]{b's'=>s(UNPCKLPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'b'=>match r[2]{b'l'=>match r[3]{b'e'=>match r[4]{b'n'=>match r[5]{b'd'=>match r[6]{b'p' => match r[7]{b'd'=>s(VBLENDPD),b's'=>s(VBLENDPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'l'=>match r[2]{b'd'=>match r[3]{b'm'=>match r[4]{b'x'=>match r[5]{b'c'=>match r[6]{b's'=>match r[7]{b'r'=>s(VLDMXCSR),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b't'=>match r[3]{b'm'=>match r[4]{b'x'=>match r[5]{b'c'=>match r[6]{b's'=>match r[7]{b'r'=>s(VSTMXCSR),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[2]{b'c'=>match r[3]{b'o'=>match r[4]{b'm'=>match r[5]{b'i'=>match r[6]{b's' => match r[7]{b'd'=>s(VUCOMISD),b's'=>s(VUCOMISS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'z'=>match r[2]{b'e'=>match r[3]{b'r'=>match r[4]{b'o'=>match r[5]{b'a'=>match r[6]{b'l'=>match r[7]{b'l'=>s(VZEROALL),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[2]{b'o'=>match r[3]{b'v' => match r[4]{b'd'=>match r[5]{b'd'=>match r[6]{b'u'=>match r[7]{b'p'=>s(VMOVDDUP),_=>N}_=>N}_=>N}b'h'=>match r[5]{b'l'=>match r[6]{b'p'=>match r[7]{b's'=>s(VMOVHLPS),_=>N}_=>N}_=>N}b'l'=>match r[5]{b'h'=>match r[6]{b'p'=>match r[7]{b's'=>s(VMOVLHPS),_=>N}_=>N}_=>N}_=>N}_=>N}b'p'=>match r[3]{b's'=>match r[4]{b'a'=>match r[5]{b'd'=>match r[6]{b'b'=>match r[7]{b'w'=>s(VMPSADBW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[2]{b'b'=>match r[3]{b'l'=>match r[4]{b'e'=>match r[5]{b'n'=>match r[6]{b'd'=>match r[7]{b'w'=>s(VPBLENDW),_=>N}_=>N}_=>N}_=>N}_=>N}b'c'=>match r[3]{b'm'=>match r[4]{b'p' => match r[5]{b'e'=>match r[6]{b'q' => match r[7]{b'b'=>s(VPCMPEQB),b'd'=>s(VPCMPEQD),b'q'=>s(VPCMPEQQ),b'w'=>s(VPCMPEQW),_=>N}_=>N}b'g'=>match r[6]{b't' => match r[7]{b'b'=>s(VPCMPGTB),b'd'=>s(VPCMPGTD),b'q'=>s(VPCMPGTQ),b'w'=>s(VPCMPGTW),_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[3]{b'u'=>match r[4]{b'b'=>match r[5]{b'u'=>match r[6]{b's' => match r[7]{b'b'=>s(VPSUBUSB),b'w'=>s(VPSUBUSW),_=>N}_=>N}_=>N}_=>N}_=>N}b'a' => match r[3]{b'd'=>match r[4]{b'd'=>match r[5]{b'u'=>match r[6]{b's' => match r[7]{b'b'=>s(VPADDUSB),b'w'=>s(VPADDUSW),_=>N}_=>N}_=>N}_=>N}b'l'=>match r[4]{b'i'=>match r[5]{b'g'=>match r[6]{b'n'=>match r[7]{b'r'=>s(VPALIGNR),_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[3]{b'a'=>match r[4]{b'd'=>match r[5]{b'd'=>match r[6]{b'w'=>match r[7]{b'd'=>s(VPMADDWD),_=>N}_=>N}_=>N}_=>N}b'u'=>match r[4]{b'l' => match r[5]{b'h'=>match r[6]{b'u'=>match r[7]{b'w'=>s(VPMULHUW),_=>N}_=>N}b'u'=>match r[6]{b'd'=>match r[7]{b'q'=>s(VPMULUDQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r' => match r[2]{b'o'=>match r[3]{b'u'=>match r[4]{b'n'=>match r[5]{b'd' => match r[6]{b'p' => match r[7]{b'd'=>s(VROUNDPD),b's'=>s(VROUNDPS),_=>N}b's' => match r[7]{b'd'=>s(VROUNDSD),b's'=>s(VROUNDSS),_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[3]{b'q'=>match r[4]{b'r'=>match r[5]{b't' => match r[6]{b'p'=>match r[7]{b's'=>s(VRSQRTPS),_=>N}b's'=>match r[7]{b's'=>s(VRSQRTSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'x' => match r[1]{b'a'=>match r[2]{b'c'=>match r[3]{b'q'=>match r[4]{b'u'=>match r[5]{b'i'=>match r[6]{b'r'=>match r[7]{b'e'=>s(XACQUIRE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'a'=>match r[3]{b'v'=>match r[4]{b'e' => match r[5]{b'c'=>match r[6]{b'6'=>match r[7]{b'4'=>s(XSAVEC64),_=>N}_=>N}b'o'=>match r[6]{b'p'=>match r[7]{b't'=>s(XSAVEOPT),_=>N}_=>N}b's'=>match r[6]{b'6'=>match r[7]{b'4'=>s(XSAVES64),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r' => match r[2]{b'e'=>match r[3]{b'l'=>match r[4]{b'e'=>match r[5]{b'a'=>match r[6]{b's'=>match r[7]{b'e'=>s(XRELEASE),_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[3]{b't'=>match r[4]{b'o'=>match r[5]{b'r'=>match r[6]{b'6'=>match r[7]{b'4'=>s(XRSTOR64),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}9=>match r[0]{b'e'=>match r[1]{b'x'=>match r[2]{b't'=>match r[3]{b'r'=>match r[4]{b'a'=>match r[5]{b'c'=>match r[6]{b't'=>match r[7]{b'p'=>match r[8]{b's'=>s(EXTRACTPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c' => match r[1]{b'm'=>match r[2]{b'p'=>match r[3]{b'x'=>match r[4]{b'c'=>match r[5]{b'h'=>match r[6]{b'g'=>match r[7]{b'8'=>match r[8]{b'b'=>s(CMPXCHG8B),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v'=>match r[2]{b't'=>match r[3]{b't' => match r[4]{b'p' => match r[5]{b'd'=>match r[6]{b'2' => match r[7]{b'd'=>match r[8]{b'q'=>s(CVTTPD2DQ),_=>N}b'p'=>match r[8]{b'i'=>s(CVTTPD2PI),_=>N}_=>N}_=>N}b's'=>match r[6]{b'2' => match r[7]{b'd'=>match r[8]{b'q'=>s(CVTTPS2DQ),_=>N}b'p'=>match r[8]{b'i'=>s(CVTTPS2PI),_=>N}_=>N}_=>N}_=>N}b's' => match r[5]{b'd'=>match r[6]{b'2'=>match r[7]{b's'=>match r[8]{b'i'=>s(CVTTSD2SI),_=>N}_=>N}_=>N}b's'=>match r[6]{b'2'=>match r[7]{b's'=>match r[8]{b'i'=>s(CVTTSS2SI),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[1]{b'm'=>match r[2]{b'a'=>match r[3]{b'd'=>match r[4]{b'd'=>match r[5]{b'u'=>match r[6]{b'b'=>match r[7]{b's'=>match r[8]{b'w'=>s(PMADDUBSW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b'e'=>match r[3]{b'f'=>match r[4]{b'e'=>match r[5]{b't'=>match r[6]{b'c'=>match r[7]{b'h' => match r[8]{b'0'=>s(PREFETCH0),b'1'=>s(PREFETCH1),b'2'=>s(PREFETCH2),b'a'=>s(PREFETCHA),b'w'=>s(PREFETCHW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[2]{b'n'=>match r[3]{b'p'=>match r[4]{b'c'=>match r[5]{b'k' => match r[6]{b'h' => match r[7]{b'b'=>match r[8]{b'w'=>s(PUNPCKHBW),_=>N}b'd'=>match r[8]{b'q'=>s(PUNPCKHDQ),_=>N}b'w'=>match r[8]{b'd'=>s(PUNPCKHWD),_=>N}_=>N}b'l' => match r[7]{b'b'=>match r[8]{b'w'=>s(PUNPCKLBW),_=>N}b'd'=>match r[8]{b'q'=>s(PUNPCKLDQ),_=>N}b'w'=>match r[8]{b'd'=>s(PUNPCKLWD),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c' => match r[2]{b'l'=>match r[3]{b'm'=>match r[4]{b'u'=>match r[5]{b'l'=>match r[6]{b'q'=>match r[7]{b'd'=>match r[8]{b'q'=>s(PCLMULQDQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[3]{b'p' => match r[4]{b'e'=>match r[5]{b's'=>match r[6]{b't'=>match r[7]{b'r' => match r[8]{b'i'=>s(PCMPESTRI),b'm'=>s(PCMPESTRM),_=>N}_=>N}_=>N}_=>N}b'i'=>match r[5]{b's'=>match r[6]{b't'=>match r[7]{b'r' => match r[8]{b'i'=>s(PCMPISTRI),b'm'=>s(PCMPISTRM),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[1]{b'e'=>match r[2]{b'r'=>match r[3]{b'i'=>match r[4]{b'a'=>match r[5]{b'l'=>match r[6]{b'i'=>match r[7]{b'z'=>match r[8]{b'e'=>s(SERIALIZE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'h'=>match r[2]{b'a'=>match r[3]{b'1' => match r[4]{b'n'=>match r[5]{b'e'=>match r[6]{b'x'=>match r[7]{b't'=>match r[8]{b'e'=>s(SHA1NEXTE),_=>N}_=>N}_=>N}_=>N}b'r'=>match r[5]{b'n'=>match r[6]{b'd'=>match r[7]{b's'=>match r[8]{b'4'=>s(SHA1RNDS4),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'a'=>match r[2]{b'd'=>match r[3]{b'd'=>match r[4]{b's'=>match r[5]{b'u'=>match r[6]{b'b'=>match r[7]{b'p' => match r[8]{b'd'=>s(VADDSUBPD),b's'=>s(VADDSUBPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'b'=>match r[2]{b'l'=>match r[3]{b'e'=>match r[4]{b'n'=>match r[5]{b'd'=>match r[6]{b'v'=>match r[7]{b'p' => match r[8]{b'd'=>s(VBLENDVPD),b's'=>s(VBLENDVPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c'=>match r[2]{b'v'=>match r[3]{b't' => match r[4]{b'd'=>match r[5]{b'q'=>match r[6]{b'2'=>match r[7]{b'p' => match r[8]{b'd'=>s(VCVTDQ2PD),b's'=>s(VCVTDQ2PS),_=>N}_=>N}_=>N}_=>N}b'p' => match r[5]{b'd'=>match r[6]{b'2' => match r[7]{b'd'=>match r[8]{b'q'=>s(VCVTPD2DQ),_=>N}b'p'=>match r[8]{b's'=>s(VCVTPD2PS),_=>N}_=>N}_=>N}b's'=>match r[6]{b'2' => match r[7]{b'd'=>match r[8]{b'q'=>s(VCVTPS2DQ),_=>N}b'p'=>match r[8]{b'd'=>s(VCVTPS2PD),_=>N}_=>N}_=>N}_=>N}b's' => match r[5]{b'd'=>match r[6]{b'2'=>match r[7]{b's' => match r[8]{b'i'=>s(VCVTSD2SI),b's'=>s(VCVTSD2SS),_=>N}_=>N}_=>N}b'i'=>match r[6]{b'2'=>match r[7]{b's' => match r[8]{b'd'=>s(VCVTSI2SD),b's'=>s(VCVTSI2SS),_=>N}_=>N}_=>N}b's'=>match r[6]{b'2'=>match r[7]{b's' => match r[8]{b'd'=>s(VCVTSS2SD),b'i'=>s(VCVTSS2SI),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'i'=>match r[2]{b'n'=>match r[3]{b's'=>match r[4]{b'e'=>match r[5]{b'r'=>match r[6]{b't'=>match r[7]{b'p'=>match r[8]{b's'=>s(VINSERTPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[2]{b'o'=>match r[3]{b'v' => match r[4]{b'm'=>match r[5]{b's'=>match r[6]{b'k'=>match r[7]{b'p' => match r[8]{b'd'=>s(VMOVMSKPD),b's'=>s(VMOVMSKPS),_=>N}_=>N}_=>N}_=>N}b'n'=>match r[5]{b't'=>match r[6]{b'd'=>match r[7]{b'q'=>match r[8]{b'a'=>s(VMOVNTDQA),_=>N}_=>N}_=>N}_=>N}b's' => match r[5]{b'h'=>match r[6]{b'd'=>match r[7]{b'u'=>match r[8]{b'p'=>s(VMOVSHDUP),_=>N}_=>N}_=>N}b'l'=>match r[6]{b'd'=>match r[7]{b'u'=>match r[8]{b'p'=>s(VMOVSLDUP),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[2]{b'n'=>match r[3]{b'p'=>match r[4]{b'c'=>match r[5]{b'k' => match r[6]{b'h'=>match r[7]{b'p'=>match r[8]{b's'=>s(VUNPCKHPS),_=>N}_=>N}b'l'=>match r[7]{b'p'=>match r[8]{b's'=>s(VUNPCKLPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[2]{b'a'=>match r[3]{b'c'=>match r[4]{b'k' => match r[5]{b's'=>match r[6]{b's' => match r[7]{b'd'=>match r[8]{b'w'=>s(VPACKSSDW),_=>N}b'w'=>match r[8]{b'b'=>s(VPACKSSWB),_=>N}_=>N}_=>N}b'u'=>match r[6]{b's' => match r[7]{b'd'=>match r[8]{b'w'=>s(VPACKUSDW),_=>N}b'w'=>match r[8]{b'b'=>s(VPACKUSWB),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'b'=>match r[3]{b'l'=>match r[4]{b'e'=>match r[5]{b'n'=>match r[6]{b'd'=>match r[7]{b'v'=>match r[8]{b'b'=>s(VPBLENDVB),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[3]{b'r'=>match r[4]{b'm'=>match r[5]{b'i'=>match r[6]{b'l'=>match r[7]{b'p' => match r[8]{b'd'=>s(VPERMILPD),b's'=>s(VPERMILPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[3]{b'u'=>match r[4]{b'l'=>match r[5]{b'h'=>match r[6]{b'r'=>match r[7]{b's'=>match r[8]{b'w'=>s(VPMULHRSW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'x' => match r[1]{b'r' => match r[2]{b'e'=>match r[3]{b's'=>match r[4]{b'l'=>match r[5]{b'd'=>match r[6]{b't'=>match r[7]{b'r'=>match r[8]{b'k'=>s(XRESLDTRK),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[3]{b't'=>match r[4]{b'o'=>match r[5]{b'r'=>match r[6]{b's'=>match r[7]{b'6'=>match r[8]{b'4'=>s(XRSTORS64),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'u'=>match r[3]{b's'=>match r[4]{b'l'=>match r[5]{b'd'=>match r[6]{b't'=>match r[7]{b'r'=>match r[8]{b'k'=>s(XSUSLDTRK),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}10=>match r[0]{b'a'=>match r[1]{b'e'=>match r[2]{b's' => match r[3]{b'd'=>match r[4]{b'e'=>match r[5]{b'c'=>match r[6]{b'l'=>match r[7]{b'a'=>match r[8]{b's'=>match r[9]{b't'=>s(AESDECLAST),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[4]{b'n'=>match r[5]{b'c'=>match r[6]{b'l'=>match r[7]{b'a'=>match r[8]{b's'=>match r[9]{b't'=>s(AESENCLAST),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c'=>match r[1]{b'm'=>match r[2]{b'p'=>match r[3]{b'x'=>match r[4]{b'c'=>match r[5]{b'h'=>match r[6]{b'g'=>match r[7]{b'1'=>match r[8]{b'6'=>match r[9]{b'b'=>s(CMPXCHG16B),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[1]{b'a'=>match r[2]{b's'=>match r[3]{b'k'=>match r[4]{b'm'=>match r[5]{b'o'=>match r[6]{b'v'=>match r[7]{b'd'=>match r[8]{b'q'=>match r[9]{b'u'=>s(MASKMOVDQU),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[1]{b'h'=>match r[2]{b'a'=>match r[3]{b'2'=>match r[4]{b'5'=>match r[5]{b'6'=>match r[6]{b'm'=>match r[7]{b's'=>match r[8]{b'g' => match r[9]{b'1'=>s(SHA256MSG1),b'2'=>s(SHA256MSG2),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'x'=>match r[1]{b's'=>match r[2]{b'a'=>match r[3]{b'v'=>match r[4]{b'e'=>match r[5]{b'o'=>match r[6]{b'p'=>match r[7]{b't'=>match r[8]{b'6'=>match r[9]{b'4'=>s(XSAVEOPT64),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[1]{b'h'=>match r[2]{b'm'=>match r[3]{b'i'=>match r[4]{b'n'=>match r[5]{b'p'=>match r[6]{b'o'=>match r[7]{b's'=>match r[8]{b'u'=>match r[9]{b'w'=>s(PHMINPOSUW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[2]{b'n'=>match r[3]{b'p'=>match r[4]{b'c'=>match r[5]{b'k' => match r[6]{b'h'=>match r[7]{b'q'=>match r[8]{b'd'=>match r[9]{b'q'=>s(PUNPCKHQDQ),_=>N}_=>N}_=>N}b'l'=>match r[7]{b'q'=>match r[8]{b'd'=>match r[9]{b'q'=>s(PUNPCKLQDQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'c'=>match r[2]{b'v'=>match r[3]{b't'=>match r[4]{b't' => match r[5]{b'p' => match r[6]{b'd'=>match r[7]{b'2'=>match r[8]{b'd'=>match r[9]{b'q'=>s(VCVTTPD2DQ),_=>N}_=>N}_=>N}b's'=>match r[7]{b'2'=>match r[8]{b'd'=>match r[9]{b'q'=>s(VCVTTPS2DQ),_=>N}_=>N}_=>N}_=>N}b's' => match r[6]{b'd'=>match r[7]{b'2'=>match r[8]{b's'=>match r[9]{b'i'=>s(VCVTTSD2SI),_=>N}_=>N}_=>N}b's'=>match r[7]{b'2'=>match r[8]{b's'=>match r[9]{b'i'=>s(VCVTTSS2SI),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[2]{b'x'=>match r[3]{b't'=>match r[4]{b'r'=>match r[5]{b'a'=>match r[6]{b'c'=>match r[7]{b't'=>match r[8]{b'p'=>match r[9]{b's'=>s(VEXTRACTPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'z'=>match r[2]{b'e'=>match r[3]{b'r'=>match r[4]{b'o'=>match r[5]{b'u'=>match r[6]{b'p'=>match r[7]{b'p'=>match r[8]{b'e'=>match r[9]{b'r'=>s(VZEROUPPER),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[2]{b'e'=>match r[3]{b'r'=>match r[4]{b'm'=>match r[5]{b'2' => match r[6]{b'f'=>match r[7]{b'1'=>match r[8]{b'2'=>match r[9]{b'8'=>s(VPERM2F128),_=>N}_=>N}_=>N}b'i'=>match r[7]{b'1'=>match r[8]{b'2'=>match r[9]{b'8'=>s(VPERM2I128),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[3]{b'n'=>match r[4]{b'p'=>match r[5]{b'c'=>match r[6]{b'k' => match r[7]{b'h' => match r[8]{b'b'=>match r[9]{b'w'=>s(VPUNPCKHBW),_=>N}b'd'=>match r[9]{b'q'=>s(VPUNPCKHDQ),_=>N}b'w'=>match r[9]{b'd'=>s(VPUNPCKHWD),_=>N}_=>N}b'l' => match r[8]{b'b'=>match r[9]{b'w'=>s(VPUNPCKLBW),_=>N}b'd'=>match r[9]{b'q'=>s(VPUNPCKLDQ),_=>N}b'w'=>match r[9]{b'd'=>s(VPUNPCKLWD),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c' => match r[3]{b'l'=>match r[4]{b'm'=>match r[5]{b'u'=>match r[6]{b'l'=>match r[7]{b'q'=>match r[8]{b'd'=>match r[9]{b'q'=>s(VPCLMULQDQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[4]{b'p' => match r[5]{b'e'=>match r[6]{b's'=>match r[7]{b't'=>match r[8]{b'r' => match r[9]{b'i'=>s(VPCMPESTRI),b'm'=>s(VPCMPESTRM),_=>N}_=>N}_=>N}_=>N}b'i'=>match r[6]{b's'=>match r[7]{b't'=>match r[8]{b'r' => match r[9]{b'i'=>s(VPCMPISTRI),b'm'=>s(VPCMPISTRM),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}11=>match r[0]{b's'=>match r[1]{b'h'=>match r[2]{b'a'=>match r[3]{b'2'=>match r[4]{b'5'=>match r[5]{b'6'=>match r[6]{b'r'=>match r[7]{b'n'=>match r[8]{b'd'=>match r[9]{b's'=>match r[10]{b'2'=>s(SHA256RNDS2),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'a'=>match r[2]{b'e'=>match r[3]{b's' => match r[4]{b'd'=>match r[5]{b'e'=>match r[6]{b'c'=>match r[7]{b'l'=>match r[8]{b'a'=>match r[9]{b's'=>match r[10]{b't'=>s(VAESDECLAST),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[5]{b'n'=>match r[6]{b'c'=>match r[7]{b'l'=>match r[8]{b'a'=>match r[9]{b's'=>match r[10]{b't'=>s(VAESENCLAST),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'f'=>match r[2]{b'm' => match r[3]{b'a'=>match r[4]{b'd'=>match r[5]{b'd' => match r[6]{b'1'=>match r[7]{b'3'=>match r[8]{b'2' => match r[9]{b'p' => match r[10]{b'd'=>s(VFMADD132PD),b's'=>s(VFMADD132PS),_=>N}b's' => match r[10]{b'd'=>s(VFMADD132SD),b's'=>s(VFMADD132SS),_=>N}_=>N}_=>N}_=>N}b'2' => match r[7]{b'1'=>match r[8]{b'3' => match r[9]{b'p' => match r[10]{b'd'=>s(VFMADD213PD),b's'=>s(VFMADD213PS),_=>N}b's' => match r[10]{b'd'=>s(VFMADD213SD),b's'=>s(VFMADD213SS),_=>N}_=>N}_=>N}b'3'=>match r[8]{b'1' => match r[9]{b'p' => match r[10]{b'd'=>s(VFMADD231PD),b's'=>s(VFMADD231PS),_=>N}b's' => match r[10]{b'd'=>s(VFMADD231SD),b's'=>s(VFMADD231SS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[4]{b'u'=>match r[5]{b'b' => match r[6]{b'1'=>match r[7]{b'3'=>match r[8]{b'2' => match r[9]{b'p' => match r[10]{b'd'=>s(VFMSUB132PD),b's'=>s(VFMSUB132PS),_=>N}b's' => match r[10]{b'd'=>s(VFMSUB132SD),b's'=>s(VFMSUB132SS),_=>N}_=>N}_=>N}_=>N}b'2' => match r[7]{b'1'=>match r[8]{b'3' => match r[9]{b'p' => match r[10]{b'd'=>s(VFMSUB213PD),b's'=>s(VFMSUB213PS),_=>N}b's' => match r[10]{b'd'=>s(VFMSUB213SD),b's'=>s(VFMSUB213SS),_=>N}_=>N}_=>N}b'3'=>match r[8]{b'1' => match r[9]{b'p' => match r[10]{b'd'=>s(VFMSUB231PD),b's'=>s(VFMSUB231PS),_=>N}b's' => match r[10]{b'd'=>s(VFMSUB231SD),b's'=>s(VFMSUB231SS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'i'=>match r[2]{b'n'=>match r[3]{b's'=>match r[4]{b'e'=>match r[5]{b'r'=>match r[6]{b't'=>match r[7]{b'f'=>match r[8]{b'1'=>match r[9]{b'2'=>match r[10]{b'8'=>s(VINSERTF128),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p'=>match r[2]{b'h'=>match r[3]{b'm'=>match r[4]{b'i'=>match r[5]{b'n'=>match r[6]{b'p'=>match r[7]{b'o'=>match r[8]{b's'=>match r[9]{b'u'=>match r[10]{b'w'=>s(VPHMINPOSUW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}12=>match r[0]{b'v' => match r[1]{b'b'=>match r[2]{b'r'=>match r[3]{b'o'=>match r[4]{b'a'=>match r[5]{b'd'=>match r[6]{b'c'=>match r[7]{b'a'=>match r[8]{b's'=>match r[9]{b't'=>match r[10]{b's' => match r[11]{b'd'=>s(VBROADCASTSD),b's'=>s(VBROADCASTSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[2]{b'x'=>match r[3]{b't'=>match r[4]{b'r'=>match r[5]{b'a'=>match r[6]{b'c'=>match r[7]{b't'=>match r[8]{b'f'=>match r[9]{b'1'=>match r[10]{b'2'=>match r[11]{b'8'=>s(VEXTRACTF128),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'f'=>match r[2]{b'n'=>match r[3]{b'm' => match r[4]{b'a'=>match r[5]{b'd'=>match r[6]{b'd' => match r[7]{b'1'=>match r[8]{b'3'=>match r[9]{b'2' => match r[10]{b'p' => match r[11]{b'd'=>s(VFNMADD132PD),b's'=>s(VFNMADD132PS),_=>N}b's' => match r[11]{b'd'=>s(VFNMADD132SD),b's'=>s(VFNMADD132SS),_=>N}_=>N}_=>N}_=>N}b'2' => match r[8]{b'1'=>match r[9]{b'3' => match r[10]{b'p' => match r[11]{b'd'=>s(VFNMADD213PD),b's'=>s(VFNMADD213PS),_=>N}b's' => match r[11]{b'd'=>s(VFNMADD213SD),b's'=>s(VFNMADD213SS),_=>N}_=>N}_=>N}b'3'=>match r[9]{b'1' => match r[10]{b'p' => match r[11]{b'd'=>s(VFNMADD231PD),b's'=>s(VFNMADD231PS),_=>N}b's' => match r[11]{b'd'=>s(VFNMADD231SD),b's'=>s(VFNMADD231SS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[5]{b'u'=>match r[6]{b'b' => match r[7]{b'1'=>match r[8]{b'3'=>match r[9]{b'2' => match r[10]{b'p' => match r[11]{b'd'=>s(VFNMSUB132PD),b's'=>s(VFNMSUB132PS),_=>N}b's' => match r[11]{b'd'=>s(VFNMSUB132SD),b's'=>s(VFNMSUB132SS),_=>N}_=>N}_=>N}_=>N}b'2' => match r[8]{b'1'=>match r[9]{b'3' => match r[10]{b'p' => match r[11]{b'd'=>s(VFNMSUB213PD),b's'=>s(VFNMSUB213PS),_=>N}b's' => match r[11]{b'd'=>s(VFNMSUB213SD),b's'=>s(VFNMSUB213SS),_=>N}_=>N}_=>N}b'3'=>match r[9]{b'1' => match r[10]{b'p' => match r[11]{b'd'=>s(VFNMSUB231PD),b's'=>s(VFNMSUB231PS),_=>N}b's' => match r[11]{b'd'=>s(VFNMSUB231SD),b's'=>s(VFNMSUB231SS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}14=>match r[0]{b'v' => match r[1]{b'b'=>match r[2]{b'r'=>match r[3]{b'o'=>match r[4]{b'a'=>match r[5]{b'd'=>match r[6]{b'c'=>match r[7]{b'a'=>match r[8]{b's'=>match r[9]{b't'=>match r[10]{b'f'=>match r[11]{b'1'=>match r[12]{b'2'=>match r[13]{b'8'=>s(VBROADCASTF128),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'f'=>match r[2]{b'm' => match r[3]{b'a'=>match r[4]{b'd'=>match r[5]{b'd'=>match r[6]{b's'=>match r[7]{b'u'=>match r[8]{b'b' => match r[9]{b'1'=>match r[10]{b'3'=>match r[11]{b'2'=>match r[12]{b'p' => match r[13]{b'd'=>s(VFMADDSUB132PD),b's'=>s(VFMADDSUB132PS),_=>N}_=>N}_=>N}_=>N}b'2' => match r[10]{b'1'=>match r[11]{b'3'=>match r[12]{b'p' => match r[13]{b'd'=>s(VFMADDSUB213PD),b's'=>s(VFMADDSUB213PS),_=>N}_=>N}_=>N}b'3'=>match r[11]{b'1'=>match r[12]{b'p' => match r[13]{b'd'=>s(VFMADDSUB231PD),b's'=>s(VFMADDSUB231PS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[4]{b'u'=>match r[5]{b'b'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd' => match r[9]{b'1'=>match r[10]{b'3'=>match r[11]{b'2'=>match r[12]{b'p' => match r[13]{b'd'=>s(VFMSUBADD132PD),b's'=>s(VFMSUBADD132PS),_=>N}_=>N}_=>N}_=>N}b'2' => match r[10]{b'1'=>match r[11]{b'3'=>match r[12]{b'p' => match r[13]{b'd'=>s(VFMSUBADD213PD),b's'=>s(VFMSUBADD213PS),_=>N}_=>N}_=>N}b'3'=>match r[11]{b'1'=>match r[12]{b'p' => match r[13]{b'd'=>s(VFMSUBADD231PD),b's'=>s(VFMSUBADD231PS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}15=>match r[0]{b'a'=>match r[1]{b'e'=>match r[2]{b's'=>match r[3]{b'k'=>match r[4]{b'e'=>match r[5]{b'y'=>match r[6]{b'g'=>match r[7]{b'e'=>match r[8]{b'n'=>match r[9]{b'a'=>match r[10]{b's'=>match r[11]{b's'=>match r[12]{b'i'=>match r[13]{b's'=>match r[14]{b't'=>s(AESKEYGENASSIST),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}16=>match r[0]{b'v'=>match r[1]{b'a'=>match r[2]{b'e'=>match r[3]{b's'=>match r[4]{b'k'=>match r[5]{b'e'=>match r[6]{b'y'=>match r[7]{b'g'=>match r[8]{b'e'=>match r[9]{b'n'=>match r[10]{b'a'=>match r[11]{b's'=>match r[12]{b's'=>match r[13]{b'i'=>match r[14]{b's'=>match r[15]{b't'=>s(VAESKEYGENASSIST),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_ =>N,
+		2=>match r[0]{b'b'=>match r[1]{b't'=>s(BT),_=>N}b'o'=>match r[1]{b'r'=>s(OR),_=>N}b'j' => match r[1]{b'a'=>s(JA),b'b'=>s(JB),b'c'=>s(JC),b'e'=>s(JE),b'g'=>s(JG),b'l'=>s(JL),b'o'=>s(JO),b'p'=>s(JP),b's'=>s(JS),b'z'=>s(JZ),_=>N}_=>N}3=>match r[0]{b'h'=>match r[1]{b'l'=>match r[2]{b't'=>s(HLT),_=>N}_=>N}b'i'=>match r[1]{b'n' => match r[2]{b'c'=>s(INC),b't'=>s(INT),_=>N}_=>N}b'p'=>match r[1]{b'o' => match r[2]{b'p'=>s(POP),b'r'=>s(POR),_=>N}_=>N}b'u'=>match r[1]{b'd' => match r[2]{b'0'=>s(UD0),b'1'=>s(UD1),b'2'=>s(UD2),_=>N}_=>N}b'x'=>match r[1]{b'o'=>match r[2]{b'r'=>s(XOR),_=>N}_=>N}b'a' => match r[1]{b'n'=>match r[2]{b'd'=>s(AND),_=>N}b'a' => match r[2]{b'a'=>s(AAA),b'd'=>s(AAD),b'm'=>s(AAM),b's'=>s(AAS),_=>N}b'd' => match r[2]{b'c'=>s(ADC),b'd'=>s(ADD),_=>N}_=>N}b'b' => match r[1]{b's' => match r[2]{b'f'=>s(BSF),b'r'=>s(BSR),_=>N}b't' => match r[2]{b'c'=>s(BTC),b'r'=>s(BTR),b's'=>s(BTS),_=>N}_=>N}b'c' => match r[1]{b'b'=>match r[2]{b'w'=>s(CBW),_=>N}b'd'=>match r[2]{b'q'=>s(CDQ),_=>N}b'q'=>match r[2]{b'o'=>s(CQO),_=>N}b'w'=>match r[2]{b'd'=>s(CWD),_=>N}b'l' => match r[2]{b'c'=>s(CLC),b'd'=>s(CLD),b'i'=>s(CLI),_=>N}b'm' => match r[2]{b'c'=>s(CMC),b'p'=>s(CMP),_=>N}_=>N}b'd' => match r[1]{b'e'=>match r[2]{b'c'=>s(DEC),_=>N}b'i'=>match r[2]{b'v'=>s(DIV),_=>N}b'a' => match r[2]{b'a'=>s(DAA),b's'=>s(DAS),_=>N}_=>N}b'f' => match r[1]{b'l'=>match r[2]{b'd'=>s(FLD),_=>N}b's'=>match r[2]{b't'=>s(FST),_=>N}_=>N}b'j' => match r[1]{b'a'=>match r[2]{b'e'=>s(JAE),_=>N}b'b'=>match r[2]{b'e'=>s(JBE),_=>N}b'g'=>match r[2]{b'e'=>s(JGE),_=>N}b'l'=>match r[2]{b'e'=>s(JLE),_=>N}b'm'=>match r[2]{b'p'=>s(JMP),_=>N}b'n' => match r[2]{b'a'=>s(JNA),b'b'=>s(JNB),b'c'=>s(JNC),b'e'=>s(JNE),b'g'=>s(JNG),b'l'=>s(JNL),b'o'=>s(JNO),b'p'=>s(JNP),b's'=>s(JNS),b'z'=>s(JNZ),_=>N}b'p' => match r[2]{b'e'=>s(JPE),b'o'=>s(JPO),_=>N}_=>N}b'l' => match r[1]{b'a'=>match r[2]{b'r'=>s(LAR),_=>N}b'e'=>match r[2]{b'a'=>s(LEA),_=>N}b's'=>match r[2]{b'l'=>s(LSL),_=>N}b't'=>match r[2]{b'r'=>s(LTR),_=>N}_=>N}b'm' => match r[1]{b'o'=>match r[2]{b'v'=>s(MOV),_=>N}b'u'=>match r[2]{b'l'=>s(MUL),_=>N}_=>N}b'n' => match r[1]{b'e'=>match r[2]{b'g'=>s(NEG),_=>N}b'o' => match r[2]{b'p'=>s(NOP),b't'=>s(NOT),_=>N}_=>N}b'r' => match r[1]{b's'=>match r[2]{b'm'=>s(RSM),_=>N}b'c' => match r[2]{b'l'=>s(RCL),b'r'=>s(RCR),_=>N}b'e' => match r[2]{b'p'=>s(REP),b't'=>s(RET),_=>N}b'o' => match r[2]{b'l'=>s(ROL),b'r'=>s(ROR),_=>N}_=>N}b's' => match r[1]{b'b'=>match r[2]{b'b'=>s(SBB),_=>N}b'u'=>match r[2]{b'b'=>s(SUB),_=>N}b'a' => match r[2]{b'l'=>s(SAL),b'r'=>s(SAR),_=>N}b'h' => match r[2]{b'l'=>s(SHL),b'r'=>s(SHR),_=>N}b't' => match r[2]{b'c'=>s(STC),b'd'=>s(STD),b'i'=>s(STI),b'r'=>s(STR),_=>N}_=>N}_=>N}4=>match r[0]{b'd'=>match r[1]{b'p'=>match r[2]{b'p' => match r[3]{b'd'=>s(DPPD),b's'=>s(DPPS),_=>N}_=>N}_=>N}b'e'=>match r[1]{b'm'=>match r[2]{b'm'=>match r[3]{b's'=>s(EMMS),_=>N}_=>N}_=>N}b'j'=>match r[1]{b'n' => match r[2]{b'a'=>match r[3]{b'e'=>s(JNAE),_=>N}b'b'=>match r[3]{b'e'=>s(JNBE),_=>N}b'g'=>match r[3]{b'e'=>s(JNGE),_=>N}b'l'=>match r[3]{b'e'=>s(JNLE),_=>N}_=>N}_=>N}b'o'=>match r[1]{b'r'=>match r[2]{b'p' => match r[3]{b'd'=>s(ORPD),b's'=>s(ORPS),_=>N}_=>N}_=>N}b't'=>match r[1]{b'e'=>match r[2]{b's'=>match r[3]{b't'=>s(TEST),_=>N}_=>N}_=>N}b'a' => match r[1]{b'n'=>match r[2]{b'd'=>match r[3]{b'n'=>s(ANDN),_=>N}_=>N}b'r'=>match r[2]{b'p'=>match r[3]{b'l'=>s(ARPL),_=>N}_=>N}b'd' => match r[2]{b'c'=>match r[3]{b'x'=>s(ADCX),_=>N}b'o'=>match r[3]{b'x'=>s(ADOX),_=>N}_=>N}_=>N}b'b' => match r[1]{b'l'=>match r[2]{b's' => match r[3]{b'i'=>s(BLSI),b'r'=>s(BLSR),_=>N}_=>N}b'y'=>match r[2]{b't'=>match r[3]{b'e'=>s(BYTE),_=>N}_=>N}b'z'=>match r[2]{b'h'=>match r[3]{b'i'=>s(BZHI),_=>N}_=>N}_=>N}b'c' => match r[1]{b'a'=>match r[2]{b'l'=>match r[3]{b'l'=>s(CALL),_=>N}_=>N}b'd'=>match r[2]{b'q'=>match r[3]{b'e'=>s(CDQE),_=>N}_=>N}b'w'=>match r[2]{b'd'=>match r[3]{b'e'=>s(CWDE),_=>N}_=>N}b'l' => match r[2]{b'a'=>match r[3]{b'c'=>s(CLAC),_=>N}b't'=>match r[3]{b's'=>s(CLTS),_=>N}b'u'=>match r[3]{b'i'=>s(CLUI),_=>N}b'w'=>match r[3]{b'b'=>s(CLWB),_=>N}_=>N}_=>N}b'f' => match r[1]{b'b'=>match r[2]{b'l'=>match r[3]{b'd'=>s(FBLD),_=>N}_=>N}b'd'=>match r[2]{b'i'=>match r[3]{b'v'=>s(FDIV),_=>N}_=>N}b'l'=>match r[2]{b'd' => match r[3]{b'1'=>s(FLD1),b'z'=>s(FLDZ),_=>N}_=>N}b'm'=>match r[2]{b'u'=>match r[3]{b'l'=>s(FMUL),_=>N}_=>N}b'n'=>match r[2]{b'o'=>match r[3]{b'p'=>s(FNOP),_=>N}_=>N}b't'=>match r[2]{b's'=>match r[3]{b't'=>s(FTST),_=>N}_=>N}b'a' => match r[2]{b'b'=>match r[3]{b's'=>s(FABS),_=>N}b'd'=>match r[3]{b'd'=>s(FADD),_=>N}_=>N}b'c' => match r[2]{b'h'=>match r[3]{b's'=>s(FCHS),_=>N}b'o' => match r[3]{b'm'=>s(FCOM),b's'=>s(FCOS),_=>N}_=>N}b'i' => match r[2]{b'l'=>match r[3]{b'd'=>s(FILD),_=>N}b's'=>match r[3]{b't'=>s(FIST),_=>N}_=>N}b's' => match r[2]{b'i'=>match r[3]{b'n'=>s(FSIN),_=>N}b't'=>match r[3]{b'p'=>s(FSTP),_=>N}b'u'=>match r[3]{b'b'=>s(FSUB),_=>N}_=>N}b'x' => match r[2]{b'a'=>match r[3]{b'm'=>s(FXAM),_=>N}b'c'=>match r[3]{b'h'=>s(FXCH),_=>N}_=>N}_=>N}b'i' => match r[1]{b'd'=>match r[2]{b'i'=>match r[3]{b'v'=>s(IDIV),_=>N}_=>N}b'm'=>match r[2]{b'u'=>match r[3]{b'l'=>s(IMUL),_=>N}_=>N}b'r'=>match r[2]{b'e'=>match r[3]{b't'=>s(IRET),_=>N}_=>N}b'n' => match r[2]{b'v'=>match r[3]{b'd'=>s(INVD),_=>N}b's' => match r[3]{b'b'=>s(INSB),b'd'=>s(INSD),b'w'=>s(INSW),_=>N}b't' => match r[3]{b'1'=>s(INT1),b'3'=>s(INT3),b'o'=>s(INTO),_=>N}_=>N}_=>N}b'l' => match r[1]{b'a'=>match r[2]{b'h'=>match r[3]{b'f'=>s(LAHF),_=>N}_=>N}b'g'=>match r[2]{b'd'=>match r[3]{b't'=>s(LGDT),_=>N}_=>N}b'i'=>match r[2]{b'd'=>match r[3]{b't'=>s(LIDT),_=>N}_=>N}b'l'=>match r[2]{b'd'=>match r[3]{b't'=>s(LLDT),_=>N}_=>N}b'm'=>match r[2]{b's'=>match r[3]{b'w'=>s(LMSW),_=>N}_=>N}b'o' => match r[2]{b'c'=>match r[3]{b'k'=>s(LOCK),_=>N}b'o'=>match r[3]{b'p'=>s(LOOP),_=>N}_=>N}_=>N}b'm' => match r[1]{b'o'=>match r[2]{b'v' => match r[3]{b'd'=>s(MOVD),b'q'=>s(MOVQ),_=>N}_=>N}b'u'=>match r[2]{b'l'=>match r[3]{b'x'=>s(MULX),_=>N}_=>N}_=>N}b'p' => match r[1]{b'a'=>match r[2]{b'n'=>match r[3]{b'd'=>s(PAND),_=>N}_=>N}b'd'=>match r[2]{b'e'=>match r[3]{b'p'=>s(PDEP),_=>N}_=>N}b'e'=>match r[2]{b'x'=>match r[3]{b't'=>s(PEXT),_=>N}_=>N}b'o'=>match r[2]{b'p' => match r[3]{b'a'=>s(POPA),b'f'=>s(POPF),_=>N}_=>N}b'u'=>match r[2]{b's'=>match r[3]{b'h'=>s(PUSH),_=>N}_=>N}b'x'=>match r[2]{b'o'=>match r[3]{b'r'=>s(PXOR),_=>N}_=>N}_=>N}b'r' => match r[1]{b'e' => match r[2]{b'p' => match r[3]{b'e'=>s(REPE),b'z'=>s(REPZ),_=>N}b't'=>match r[3]{b'f'=>s(RETF),_=>N}_=>N}b'o'=>match r[2]{b'r'=>match r[3]{b'x'=>s(RORX),_=>N}_=>N}_=>N}b's' => match r[1]{b'e'=>match r[2]{b't' => match r[3]{b'a'=>s(SETA),b'b'=>s(SETB),b'c'=>s(SETC),b'e'=>s(SETE),b'g'=>s(SETG),b'l'=>s(SETL),b'o'=>s(SETO),b'p'=>s(SETP),b's'=>s(SETS),b'z'=>s(SETZ),_=>N}_=>N}b'g'=>match r[2]{b'd'=>match r[3]{b't'=>s(SGDT),_=>N}_=>N}b'i'=>match r[2]{b'd'=>match r[3]{b't'=>s(SIDT),_=>N}_=>N}b'm'=>match r[2]{b's'=>match r[3]{b'w'=>s(SMSW),_=>N}_=>N}b'a' => match r[2]{b'h'=>match r[3]{b'f'=>s(SAHF),_=>N}b'r'=>match r[3]{b'x'=>s(SARX),_=>N}_=>N}b'h' => match r[2]{b'l' => match r[3]{b'd'=>s(SHLD),b'x'=>s(SHLX),_=>N}b'r' => match r[3]{b'd'=>s(SHRD),b'x'=>s(SHRX),_=>N}_=>N}b't' => match r[2]{b'a'=>match r[3]{b'c'=>s(STAC),_=>N}b'r'=>match r[3]{b'z'=>s(STRZ),_=>N}b'u'=>match r[3]{b'i'=>s(STUI),_=>N}_=>N}_=>N}b'v' => match r[1]{b'e'=>match r[2]{b'r' => match r[3]{b'r'=>s(VERR),b'w'=>s(VERW),_=>N}_=>N}b'p'=>match r[2]{b'o'=>match r[3]{b'r'=>s(VPOR),_=>N}_=>N}_=>N}b'w' => match r[1]{b'a'=>match r[2]{b'i'=>match r[3]{b't'=>s(WAIT),_=>N}_=>N}b'o'=>match r[2]{b'r'=>match r[3]{b'd'=>s(WORD),_=>N}_=>N}_=>N}b'x' => match r[1]{b'a'=>match r[2]{b'd'=>match r[3]{b'd'=>s(XADD),_=>N}_=>N}b'c'=>match r[2]{b'h'=>match r[3]{b'g'=>s(XCHG),_=>N}_=>N}b'e'=>match r[2]{b'n'=>match r[3]{b'd'=>s(XEND),_=>N}_=>N}b'l'=>match r[2]{b'a'=>match r[3]{b't'=>s(XLAT),_=>N}_=>N}_=>N}_=>N}5=>match r[0]{b'k'=>match r[1]{b'm'=>match r[2]{b'o'=>match r[3]{b'v'=>match r[4]{b'w'=>s(KMOVW),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[1]{b'u'=>match r[2]{b't' => match r[3]{b'i' => match r[4]{b'b'=>s(OUTIB),b'd'=>s(OUTID),b'w'=>s(OUTIW),_=>N}b'r' => match r[4]{b'b'=>s(OUTRB),b'd'=>s(OUTRD),b'w'=>s(OUTRW),_=>N}b's' => match r[4]{b'b'=>s(OUTSB),b'd'=>s(OUTSD),b'w'=>s(OUTSW),_=>N}_=>N}_=>N}_=>N}b'q'=>match r[1]{b'w'=>match r[2]{b'o'=>match r[3]{b'r'=>match r[4]{b'd'=>s(QWORD),_=>N}_=>N}_=>N}_=>N}b't'=>match r[1]{b'z'=>match r[2]{b'c'=>match r[3]{b'n'=>match r[4]{b't'=>s(TZCNT),_=>N}_=>N}_=>N}_=>N}b'u'=>match r[1]{b'i'=>match r[2]{b'r'=>match r[3]{b'e'=>match r[4]{b't'=>s(UIRET),_=>N}_=>N}_=>N}_=>N}b'w'=>match r[1]{b'r'=>match r[2]{b'm'=>match r[3]{b's'=>match r[4]{b'r'=>s(WRMSR),_=>N}_=>N}_=>N}_=>N}b'a' => match r[1]{b'd'=>match r[2]{b'd' => match r[3]{b'p' => match r[4]{b'd'=>s(ADDPD),b's'=>s(ADDPS),_=>N}b's' => match r[4]{b'd'=>s(ADDSD),b's'=>s(ADDSS),_=>N}_=>N}_=>N}b'n'=>match r[2]{b'd'=>match r[3]{b'p' => match r[4]{b'd'=>s(ANDPD),b's'=>s(ANDPS),_=>N}_=>N}_=>N}_=>N}b'b' => match r[1]{b'e'=>match r[2]{b'x'=>match r[3]{b't'=>match r[4]{b'r'=>s(BEXTR),_=>N}_=>N}_=>N}b's'=>match r[2]{b'w'=>match r[3]{b'a'=>match r[4]{b'p'=>s(BSWAP),_=>N}_=>N}_=>N}_=>N}b'c' => match r[1]{b'p'=>match r[2]{b'u'=>match r[3]{b'i'=>match r[4]{b'd'=>s(CPUID),_=>N}_=>N}_=>N}b'r'=>match r[2]{b'c'=>match r[3]{b'3'=>match r[4]{b'2'=>s(CRC32),_=>N}_=>N}_=>N}b'm' => match r[2]{b'o'=>match r[3]{b'v' => match r[4]{b'a'=>s(CMOVA),b'b'=>s(CMOVB),b'c'=>s(CMOVC),b'e'=>s(CMOVE),b'g'=>s(CMOVG),b'l'=>s(CMOVL),b'o'=>s(CMOVO),b'p'=>s(CMOVP),b's'=>s(CMOVS),b'z'=>s(CMOVZ),_=>N}_=>N}b'p' => match r[3]{b'p' => match r[4]{b'd'=>s(CMPPD),b's'=>s(CMPPS),_=>N}b's' => match r[4]{b'd'=>s(CMPSD),b's'=>s(CMPSS),_=>N}_=>N}_=>N}_=>N}b'd' => match r[1]{b'i'=>match r[2]{b'v' => match r[3]{b'p' => match r[4]{b'd'=>s(DIVPD),b's'=>s(DIVPS),_=>N}b's' => match r[4]{b'd'=>s(DIVSD),b's'=>s(DIVSS),_=>N}_=>N}_=>N}b'w'=>match r[2]{b'o'=>match r[3]{b'r'=>match r[4]{b'd'=>s(DWORD),_=>N}_=>N}_=>N}_=>N}b'e' => match r[1]{b'm'=>match r[2]{b'p'=>match r[3]{b't'=>match r[4]{b'y'=>s(EMPTY),_=>N}_=>N}_=>N}b'n'=>match r[2]{b't'=>match r[3]{b'e'=>match r[4]{b'r'=>s(ENTER),_=>N}_=>N}_=>N}_=>N}b'f' => match r[1]{b'2'=>match r[2]{b'x'=>match r[3]{b'm'=>match r[4]{b'1'=>s(F2XM1),_=>N}_=>N}_=>N}b'a'=>match r[2]{b'd'=>match r[3]{b'd'=>match r[4]{b'p'=>s(FADDP),_=>N}_=>N}_=>N}b'b'=>match r[2]{b's'=>match r[3]{b't'=>match r[4]{b'p'=>s(FBSTP),_=>N}_=>N}_=>N}b'd'=>match r[2]{b'i'=>match r[3]{b'v' => match r[4]{b'p'=>s(FDIVP),b'r'=>s(FDIVR),_=>N}_=>N}_=>N}b'f'=>match r[2]{b'r'=>match r[3]{b'e'=>match r[4]{b'e'=>s(FFREE),_=>N}_=>N}_=>N}b'l'=>match r[2]{b'd' => match r[3]{b'c'=>match r[4]{b'w'=>s(FLDCW),_=>N}b'p'=>match r[4]{b'i'=>s(FLDPI),_=>N}_=>N}_=>N}b'm'=>match r[2]{b'u'=>match r[3]{b'l'=>match r[4]{b'p'=>s(FMULP),_=>N}_=>N}_=>N}b'u'=>match r[2]{b'c'=>match r[3]{b'o'=>match r[4]{b'm'=>s(FUCOM),_=>N}_=>N}_=>N}b'w'=>match r[2]{b'a'=>match r[3]{b'i'=>match r[4]{b't'=>s(FWAIT),_=>N}_=>N}_=>N}b'y'=>match r[2]{b'l'=>match r[3]{b'2'=>match r[4]{b'x'=>s(FYL2X),_=>N}_=>N}_=>N}b'c' => match r[2]{b'l'=>match r[3]{b'e'=>match r[4]{b'x'=>s(FCLEX),_=>N}_=>N}b'o'=>match r[3]{b'm' => match r[4]{b'i'=>s(FCOMI),b'p'=>s(FCOMP),_=>N}_=>N}_=>N}b'i' => match r[2]{b'a'=>match r[3]{b'd'=>match r[4]{b'd'=>s(FIADD),_=>N}_=>N}b'c'=>match r[3]{b'o'=>match r[4]{b'm'=>s(FICOM),_=>N}_=>N}b'd'=>match r[3]{b'i'=>match r[4]{b'v'=>s(FIDIV),_=>N}_=>N}b'm'=>match r[3]{b'u'=>match r[4]{b'l'=>s(FIMUL),_=>N}_=>N}b'n'=>match r[3]{b'i'=>match r[4]{b't'=>s(FINIT),_=>N}_=>N}b's' => match r[3]{b't'=>match r[4]{b'p'=>s(FISTP),_=>N}b'u'=>match r[4]{b'b'=>s(FISUB),_=>N}_=>N}_=>N}b'p' => match r[2]{b'r'=>match r[3]{b'e'=>match r[4]{b'm'=>s(FPREM),_=>N}_=>N}b't'=>match r[3]{b'a'=>match r[4]{b'n'=>s(FPTAN),_=>N}_=>N}_=>N}b's' => match r[2]{b'a'=>match r[3]{b'v'=>match r[4]{b'e'=>s(FSAVE),_=>N}_=>N}b'q'=>match r[3]{b'r'=>match r[4]{b't'=>s(FSQRT),_=>N}_=>N}b'u'=>match r[3]{b'b' => match r[4]{b'p'=>s(FSUBP),b'r'=>s(FSUBR),_=>N}_=>N}b't' => match r[3]{b'c'=>match r[4]{b'w'=>s(FSTCW),_=>N}b's'=>match r[4]{b'w'=>s(FSTSW),_=>N}_=>N}_=>N}_=>N}b'i' => match r[1]{b'n'=>match r[2]{b'd'=>match r[3]{b'x' => match r[4]{b'b'=>s(INDXB),b'd'=>s(INDXD),b'w'=>s(INDXW),_=>N}_=>N}_=>N}b'r'=>match r[2]{b'e'=>match r[3]{b't' => match r[4]{b'd'=>s(IRETD),b'q'=>s(IRETQ),_=>N}_=>N}_=>N}_=>N}b'j' => match r[1]{b'e'=>match r[2]{b'c'=>match r[3]{b'x'=>match r[4]{b'z'=>s(JECXZ),_=>N}_=>N}_=>N}b'r'=>match r[2]{b'c'=>match r[3]{b'x'=>match r[4]{b'z'=>s(JRCXZ),_=>N}_=>N}_=>N}_=>N}b'l' => match r[1]{b'd'=>match r[2]{b'd'=>match r[3]{b'q'=>match r[4]{b'u'=>s(LDDQU),_=>N}_=>N}_=>N}b'e'=>match r[2]{b'a'=>match r[3]{b'v'=>match r[4]{b'e'=>s(LEAVE),_=>N}_=>N}_=>N}b'z'=>match r[2]{b'c'=>match r[3]{b'n'=>match r[4]{b't'=>s(LZCNT),_=>N}_=>N}_=>N}b'o' => match r[2]{b'd'=>match r[3]{b's' => match r[4]{b'b'=>s(LODSB),b'd'=>s(LODSD),b'q'=>s(LODSQ),b'w'=>s(LODSW),_=>N}_=>N}b'o'=>match r[3]{b'p'=>match r[4]{b'e'=>s(LOOPE),_=>N}_=>N}_=>N}_=>N}b'm' => match r[1]{b'a'=>match r[2]{b'x' => match r[3]{b'p' => match r[4]{b'd'=>s(MAXPD),b's'=>s(MAXPS),_=>N}b's' => match r[4]{b'd'=>s(MAXSD),b's'=>s(MAXSS),_=>N}_=>N}_=>N}b'i'=>match r[2]{b'n' => match r[3]{b'p' => match r[4]{b'd'=>s(MINPD),b's'=>s(MINPS),_=>N}b's' => match r[4]{b'd'=>s(MINSD),b's'=>s(MINSS),_=>N}_=>N}_=>N}b'o'=>match r[2]{b'v' => match r[3]{b'b'=>match r[4]{b'e'=>s(MOVBE),_=>N}b'z'=>match r[4]{b'x'=>s(MOVZX),_=>N}b's' => match r[4]{b'd'=>s(MOVSD),b's'=>s(MOVSS),b'x'=>s(MOVSX),_=>N}_=>N}_=>N}b'u'=>match r[2]{b'l' => match r[3]{b'p' => match r[4]{b'd'=>s(MULPD),b's'=>s(MULPS),_=>N}b's' => match r[4]{b'd'=>s(MULSD),b's'=>s(MULSS),_=>N}_=>N}_=>N}b'w'=>match r[2]{b'a'=>match r[3]{b'i'=>match r[4]{b't'=>s(MWAIT),_=>N}_=>N}_=>N}_=>N}b'p' => match r[1]{b'o'=>match r[2]{b'p' => match r[3]{b'a'=>match r[4]{b'd'=>s(POPAD),_=>N}b'f' => match r[4]{b'd'=>s(POPFD),b'q'=>s(POPFQ),_=>N}_=>N}_=>N}b't'=>match r[2]{b'e'=>match r[3]{b's'=>match r[4]{b't'=>s(PTEST),_=>N}_=>N}_=>N}b'u'=>match r[2]{b's'=>match r[3]{b'h' => match r[4]{b'a'=>s(PUSHA),b'f'=>s(PUSHF),_=>N}_=>N}_=>N}b'a' => match r[2]{b'b'=>match r[3]{b's' => match r[4]{b'b'=>s(PABSB),b'd'=>s(PABSD),b'w'=>s(PABSW),_=>N}_=>N}b'd'=>match r[3]{b'd' => match r[4]{b'b'=>s(PADDB),b'd'=>s(PADDD),b'q'=>s(PADDQ),b'w'=>s(PADDW),_=>N}_=>N}b'n'=>match r[3]{b'd'=>match r[4]{b'n'=>s(PANDN),_=>N}_=>N}b'u'=>match r[3]{b's'=>match r[4]{b'e'=>s(PAUSE),_=>N}_=>N}b'v'=>match r[3]{b'g' => match r[4]{b'b'=>s(PAVGB),b'w'=>s(PAVGW),_=>N}_=>N}_=>N}b's' => match r[2]{b'l'=>match r[3]{b'l' => match r[4]{b'd'=>s(PSLLD),b'q'=>s(PSLLQ),b'w'=>s(PSLLW),_=>N}_=>N}b'u'=>match r[3]{b'b' => match r[4]{b'b'=>s(PSUBB),b'd'=>s(PSUBD),b'q'=>s(PSUBQ),b'w'=>s(PSUBW),_=>N}_=>N}b'r' => match r[3]{b'a' => match r[4]{b'd'=>s(PSRAD),b'w'=>s(PSRAW),_=>N}b'l' => match r[4]{b'd'=>s(PSRLD),b'q'=>s(PSRLQ),b'w'=>s(PSRLW),_=>N}_=>N}_=>N}_=>N}b'r' => match r[1]{b'c'=>match r[2]{b'p' => match r[3]{b'p'=>match r[4]{b's'=>s(RCPPS),_=>N}b's'=>match r[4]{b's'=>s(RCPSS),_=>N}_=>N}_=>N}b'e'=>match r[2]{b'p'=>match r[3]{b'n' => match r[4]{b'e'=>s(REPNE),b'z'=>s(REPNZ),_=>N}_=>N}_=>N}b'd' => match r[2]{b'm'=>match r[3]{b's'=>match r[4]{b'r'=>s(RDMSR),_=>N}_=>N}b't'=>match r[3]{b's'=>match r[4]{b'c'=>s(RDTSC),_=>N}_=>N}b'p' => match r[3]{b'i'=>match r[4]{b'd'=>s(RDPID),_=>N}b'm'=>match r[4]{b'c'=>s(RDPMC),_=>N}_=>N}_=>N}_=>N}b's' => match r[1]{b'c'=>match r[2]{b'a'=>match r[3]{b's' => match r[4]{b'b'=>s(SCASB),b'd'=>s(SCASD),b'q'=>s(SCASQ),b'w'=>s(SCASW),_=>N}_=>N}_=>N}b'e'=>match r[2]{b't' => match r[3]{b'a'=>match r[4]{b'e'=>s(SETAE),_=>N}b'b'=>match r[4]{b'e'=>s(SETBE),_=>N}b'g'=>match r[4]{b'e'=>s(SETGE),_=>N}b'l'=>match r[4]{b'e'=>s(SETLE),_=>N}b'n' => match r[4]{b'a'=>s(SETNA),b'b'=>s(SETNB),b'c'=>s(SETNC),b'e'=>s(SETNE),b'g'=>s(SETNG),b'l'=>s(SETNL),b'o'=>s(SETNO),b'p'=>s(SETNP),b's'=>s(SETNS),b'z'=>s(SETNZ),_=>N}b'p' => match r[4]{b'e'=>s(SETPE),b'o'=>s(SETPO),_=>N}_=>N}_=>N}b't'=>match r[2]{b'o'=>match r[3]{b's' => match r[4]{b'b'=>s(STOSB),b'd'=>s(STOSD),b'q'=>s(STOSQ),b'w'=>s(STOSW),_=>N}_=>N}_=>N}b'u'=>match r[2]{b'b' => match r[3]{b'p' => match r[4]{b'd'=>s(SUBPD),b's'=>s(SUBPS),_=>N}b's' => match r[4]{b'd'=>s(SUBSD),b's'=>s(SUBSS),_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'd'=>match r[2]{b'p'=>match r[3]{b'p' => match r[4]{b'd'=>s(VDPPD),b's'=>s(VDPPS),_=>N}_=>N}_=>N}b'm'=>match r[2]{b'o'=>match r[3]{b'v' => match r[4]{b'd'=>s(VMOVD),b'q'=>s(VMOVQ),_=>N}_=>N}_=>N}b'o'=>match r[2]{b'r'=>match r[3]{b'p' => match r[4]{b'd'=>s(VORPD),b's'=>s(VORPS),_=>N}_=>N}_=>N}b'p' => match r[2]{b'a'=>match r[3]{b'n'=>match r[4]{b'd'=>s(VPAND),_=>N}_=>N}b'o'=>match r[3]{b'r' => match r[4]{b'd'=>s(VPORD),b'q'=>s(VPORQ),_=>N}_=>N}b'x'=>match r[3]{b'o'=>match r[4]{b'r'=>s(VPXOR),_=>N}_=>N}_=>N}_=>N}b'x' => match r[1]{b'l'=>match r[2]{b'a'=>match r[3]{b't'=>match r[4]{b'b'=>s(XLATB),_=>N}_=>N}_=>N}b'o'=>match r[2]{b'r'=>match r[3]{b'p' => match r[4]{b'd'=>s(XORPD),b's'=>s(XORPS),_=>N}_=>N}_=>N}b's'=>match r[2]{b'a'=>match r[3]{b'v'=>match r[4]{b'e'=>s(XSAVE),_=>N}_=>N}_=>N}b't'=>match r[2]{b'e'=>match r[3]{b's'=>match r[4]{b't'=>s(XTEST),_=>N}_=>N}_=>N}_=>N}_=>N}6=>match r[0]{b'i'=>match r[1]{b'n'=>match r[2]{b'v'=>match r[3]{b'l'=>match r[4]{b'p'=>match r[5]{b'g'=>s(INVLPG),_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[1]{b'd' => match r[2]{b'p'=>match r[3]{b'k'=>match r[4]{b'r'=>match r[5]{b'u'=>s(RDPKRU),_=>N}_=>N}_=>N}b'r'=>match r[3]{b'a'=>match r[4]{b'n'=>match r[5]{b'd'=>s(RDRAND),_=>N}_=>N}_=>N}b't'=>match r[3]{b's'=>match r[4]{b'c'=>match r[5]{b'p'=>s(RDTSCP),_=>N}_=>N}_=>N}b's' => match r[3]{b'e'=>match r[4]{b'e'=>match r[5]{b'd'=>s(RDSEED),_=>N}_=>N}b's'=>match r[4]{b'p' => match r[5]{b'd'=>s(RDSSPD),b'q'=>s(RDSSPQ),_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[1]{b'm'=>match r[2]{b'w'=>match r[3]{b'a'=>match r[4]{b'i'=>match r[5]{b't'=>s(UMWAIT),_=>N}_=>N}_=>N}_=>N}_=>N}b'a' => match r[1]{b'e'=>match r[2]{b's' => match r[3]{b'd'=>match r[4]{b'e'=>match r[5]{b'c'=>s(AESDEC),_=>N}_=>N}b'e'=>match r[4]{b'n'=>match r[5]{b'c'=>s(AESENC),_=>N}_=>N}b'i'=>match r[4]{b'm'=>match r[5]{b'c'=>s(AESIMC),_=>N}_=>N}_=>N}_=>N}b'n'=>match r[2]{b'd'=>match r[3]{b'n'=>match r[4]{b'p' => match r[5]{b'd'=>s(ANDNPD),b's'=>s(ANDNPS),_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'c'=>match r[3]{b'i'=>match r[4]{b'i'=>match r[5]{b'z'=>s(ASCIIZ),_=>N}_=>N}_=>N}_=>N}_=>N}b'b' => match r[1]{b'l'=>match r[2]{b's'=>match r[3]{b'm'=>match r[4]{b's'=>match r[5]{b'k'=>s(BLSMSK),_=>N}_=>N}_=>N}_=>N}b'y'=>match r[2]{b't'=>match r[3]{b'e' => match r[4]{b'b'=>match r[5]{b'e'=>s(BYTEBE),_=>N}b'l'=>match r[5]{b'e'=>s(BYTELE),_=>N}_=>N}_=>N}_=>N}_=>N}b'c' => match r[1]{b'm'=>match r[2]{b'o'=>match r[3]{b'v' => match r[4]{b'a'=>match r[5]{b'e'=>s(CMOVAE),_=>N}b'b'=>match r[5]{b'e'=>s(CMOVBE),_=>N}b'g'=>match r[5]{b'e'=>s(CMOVGE),_=>N}b'l'=>match r[5]{b'e'=>s(CMOVLE),_=>N}b'n' => match r[5]{b'a'=>s(CMOVNA),b'b'=>s(CMOVNB),b'c'=>s(CMOVNC),b'e'=>s(CMOVNE),b'g'=>s(CMOVNG),b'l'=>s(CMOVNL),b'o'=>s(CMOVNO),b'p'=>s(CMOVNP),b's'=>s(CMOVNS),b'z'=>s(CMOVNZ),_=>N}b'p' => match r[5]{b'e'=>s(CMOVPE),b'o'=>s(CMOVPO),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[2]{b'm'=>match r[3]{b'i'=>match r[4]{b's' => match r[5]{b'd'=>s(COMISD),b's'=>s(COMISS),_=>N}_=>N}_=>N}_=>N}_=>N}b'f' => match r[1]{b'd'=>match r[2]{b'i'=>match r[3]{b'v'=>match r[4]{b'r'=>match r[5]{b'p'=>s(FDIVRP),_=>N}_=>N}_=>N}_=>N}b'l'=>match r[2]{b'd' => match r[3]{b'e'=>match r[4]{b'n'=>match r[5]{b'v'=>s(FLDENV),_=>N}_=>N}b'l' => match r[4]{b'g'=>match r[5]{b'2'=>s(FLDLG2),_=>N}b'n'=>match r[5]{b'2'=>s(FLDLN2),_=>N}b'2' => match r[5]{b'e'=>s(FLDL2E),b't'=>s(FLDL2T),_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b's'=>match r[3]{b't'=>match r[4]{b'o'=>match r[5]{b'r'=>s(FRSTOR),_=>N}_=>N}_=>N}_=>N}b'u'=>match r[2]{b'c'=>match r[3]{b'o'=>match r[4]{b'm' => match r[5]{b'i'=>s(FUCOMI),b'p'=>s(FUCOMP),_=>N}_=>N}_=>N}_=>N}b'x'=>match r[2]{b's'=>match r[3]{b'a'=>match r[4]{b'v'=>match r[5]{b'e'=>s(FXSAVE),_=>N}_=>N}_=>N}_=>N}b'c' => match r[2]{b'm'=>match r[3]{b'o'=>match r[4]{b'v' => match r[5]{b'b'=>s(FCMOVB),b'e'=>s(FCMOVE),b'u'=>s(FCMOVU),_=>N}_=>N}_=>N}b'o'=>match r[3]{b'm' => match r[4]{b'i'=>match r[5]{b'p'=>s(FCOMIP),_=>N}b'p'=>match r[5]{b'p'=>s(FCOMPP),_=>N}_=>N}_=>N}_=>N}b'i' => match r[2]{b'c'=>match r[3]{b'o'=>match r[4]{b'm'=>match r[5]{b'p'=>s(FICOMP),_=>N}_=>N}_=>N}b'd'=>match r[3]{b'i'=>match r[4]{b'v'=>match r[5]{b'r'=>s(FIDIVR),_=>N}_=>N}_=>N}b's' => match r[3]{b't'=>match r[4]{b't'=>match r[5]{b'p'=>s(FISTTP),_=>N}_=>N}b'u'=>match r[4]{b'b'=>match r[5]{b'r'=>s(FISUBR),_=>N}_=>N}_=>N}_=>N}b'n' => match r[2]{b'c'=>match r[3]{b'l'=>match r[4]{b'e'=>match r[5]{b'x'=>s(FNCLEX),_=>N}_=>N}_=>N}b'i'=>match r[3]{b'n'=>match r[4]{b'i'=>match r[5]{b't'=>s(FNINIT),_=>N}_=>N}_=>N}b's' => match r[3]{b'a'=>match r[4]{b'v'=>match r[5]{b'e'=>s(FNSAVE),_=>N}_=>N}b't' => match r[4]{b'c'=>match r[5]{b'w'=>s(FNSTCW),_=>N}b's'=>match r[5]{b'w'=>s(FNSTSW),_=>N}_=>N}_=>N}_=>N}b'p' => match r[2]{b'a'=>match r[3]{b't'=>match r[4]{b'a'=>match r[5]{b'n'=>s(FPATAN),_=>N}_=>N}_=>N}b'r'=>match r[3]{b'e'=>match r[4]{b'm'=>match r[5]{b'1'=>s(FPREM1),_=>N}_=>N}_=>N}_=>N}b's' => match r[2]{b'c'=>match r[3]{b'a'=>match r[4]{b'l'=>match r[5]{b'e'=>s(FSCALE),_=>N}_=>N}_=>N}b't'=>match r[3]{b'e'=>match r[4]{b'n'=>match r[5]{b'v'=>s(FSTENV),_=>N}_=>N}_=>N}b'u'=>match r[3]{b'b'=>match r[4]{b'r'=>match r[5]{b'p'=>s(FSUBRP),_=>N}_=>N}_=>N}_=>N}_=>N}b'h' => match r[1]{b'a'=>match r[2]{b'd'=>match r[3]{b'd'=>match r[4]{b'p' => match r[5]{b'd'=>s(HADDPD),b's'=>s(HADDPS),_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b'e'=>match r[3]{b's'=>match r[4]{b'e'=>match r[5]{b't'=>s(HRESET),_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'u'=>match r[3]{b'b'=>match r[4]{b'p' => match r[5]{b'd'=>s(HSUBPD),b's'=>s(HSUBPS),_=>N}_=>N}_=>N}_=>N}_=>N}b'l' => match r[1]{b'f'=>match r[2]{b'e'=>match r[3]{b'n'=>match r[4]{b'c'=>match r[5]{b'e'=>s(LFENCE),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[2]{b'o'=>match r[3]{b'p'=>match r[4]{b'n'=>match r[5]{b'e'=>s(LOOPNE),_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[1]{b'f'=>match r[2]{b'e'=>match r[3]{b'n'=>match r[4]{b'c'=>match r[5]{b'e'=>s(MFENCE),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[2]{b'v' => match r[3]{b'd'=>match r[4]{b'q'=>match r[5]{b'a'=>s(MOVDQA),_=>N}_=>N}b'h'=>match r[4]{b'p' => match r[5]{b'd'=>s(MOVHPD),b's'=>s(MOVHPS),_=>N}_=>N}b'l'=>match r[4]{b'p' => match r[5]{b'd'=>s(MOVLPD),b's'=>s(MOVLPS),_=>N}_=>N}b'n'=>match r[4]{b't'=>match r[5]{b'i'=>s(MOVNTI),_=>N}_=>N}b's'=>match r[4]{b'x'=>match r[5]{b'd'=>s(MOVSXD),_=>N}_=>N}b'u'=>match r[4]{b'p' => match r[5]{b'd'=>s(MOVUPD),b's'=>s(MOVUPS),_=>N}_=>N}b'a' => match r[4]{b'b'=>match r[5]{b's'=>s(MOVABS),_=>N}b'p' => match r[5]{b'd'=>s(MOVAPD),b's'=>s(MOVAPS),_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[1]{b'a'=>match r[2]{b'd'=>match r[3]{b'd'=>match r[4]{b's' => match r[5]{b'b'=>s(PADDSB),b'w'=>s(PADDSW),_=>N}_=>N}_=>N}_=>N}b'e'=>match r[2]{b'x'=>match r[3]{b't'=>match r[4]{b'r' => match r[5]{b'b'=>s(PEXTRB),b'd'=>s(PEXTRD),b'q'=>s(PEXTRQ),b'w'=>s(PEXTRW),_=>N}_=>N}_=>N}_=>N}b'i'=>match r[2]{b'n'=>match r[3]{b's'=>match r[4]{b'r' => match r[5]{b'b'=>s(PINSRB),b'd'=>s(PINSRD),b'q'=>s(PINSRQ),b'w'=>s(PINSRW),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[2]{b'p'=>match r[3]{b'c'=>match r[4]{b'n'=>match r[5]{b't'=>s(POPCNT),_=>N}_=>N}_=>N}_=>N}b'u'=>match r[2]{b's'=>match r[3]{b'h' => match r[4]{b'a'=>match r[5]{b'd'=>s(PUSHAD),_=>N}b'f' => match r[5]{b'd'=>s(PUSHFD),b'q'=>s(PUSHFQ),_=>N}_=>N}_=>N}_=>N}b'h' => match r[2]{b'a'=>match r[3]{b'd'=>match r[4]{b'd' => match r[5]{b'd'=>s(PHADDD),b'w'=>s(PHADDW),_=>N}_=>N}_=>N}b's'=>match r[3]{b'u'=>match r[4]{b'b' => match r[5]{b'd'=>s(PHSUBD),b'w'=>s(PHSUBW),_=>N}_=>N}_=>N}_=>N}b'm' => match r[2]{b'a'=>match r[3]{b'x' => match r[4]{b's' => match r[5]{b'b'=>s(PMAXSB),b'd'=>s(PMAXSD),b'w'=>s(PMAXSW),_=>N}b'u' => match r[5]{b'd'=>s(PMAXUD),b'w'=>s(PMAXUW),_=>N}_=>N}_=>N}b'i'=>match r[3]{b'n' => match r[4]{b's' => match r[5]{b'b'=>s(PMINSB),b'd'=>s(PMINSD),b'w'=>s(PMINSW),_=>N}b'u'=>match r[5]{b'w'=>s(PMINUW),_=>N}_=>N}_=>N}b'u'=>match r[3]{b'l' => match r[4]{b'd'=>match r[5]{b'q'=>s(PMULDQ),_=>N}b'h'=>match r[5]{b'w'=>s(PMULHW),_=>N}b'l' => match r[5]{b'd'=>s(PMULLD),b'w'=>s(PMULLW),_=>N}_=>N}_=>N}_=>N}b's' => match r[2]{b'h'=>match r[3]{b'u'=>match r[4]{b'f' => match r[5]{b'b'=>s(PSHUFB),b'd'=>s(PSHUFD),_=>N}_=>N}_=>N}b'i'=>match r[3]{b'g'=>match r[4]{b'n' => match r[5]{b'b'=>s(PSIGNB),b'd'=>s(PSIGND),b'w'=>s(PSIGNW),_=>N}_=>N}_=>N}b'l'=>match r[3]{b'l'=>match r[4]{b'd'=>match r[5]{b'q'=>s(PSLLDQ),_=>N}_=>N}_=>N}b'r'=>match r[3]{b'l'=>match r[4]{b'd'=>match r[5]{b'q'=>s(PSRLDQ),_=>N}_=>N}_=>N}b'u'=>match r[3]{b'b'=>match r[4]{b's' => match r[5]{b'b'=>s(PSUBSB),b'w'=>s(PSUBSW),_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[1]{b'e'=>match r[2]{b't'=>match r[3]{b'n' => match r[4]{b'a'=>match r[5]{b'e'=>s(SETNAE),_=>N}b'b'=>match r[5]{b'e'=>s(SETNBE),_=>N}b'g'=>match r[5]{b'e'=>s(SETNGE),_=>N}b'l'=>match r[5]{b'e'=>s(SETNLE),_=>N}_=>N}_=>N}_=>N}b'f'=>match r[2]{b'e'=>match r[3]{b'n'=>match r[4]{b'c'=>match r[5]{b'e'=>s(SFENCE),_=>N}_=>N}_=>N}_=>N}b'h'=>match r[2]{b'u'=>match r[3]{b'f'=>match r[4]{b'p'=>match r[5]{b's'=>s(SHUFPS),_=>N}_=>N}_=>N}_=>N}b'q'=>match r[2]{b'r'=>match r[3]{b't' => match r[4]{b'p' => match r[5]{b'd'=>s(SQRTPD),b's'=>s(SQRTPS),_=>N}b's' => match r[5]{b'd'=>s(SQRTSD),b's'=>s(SQRTSS),_=>N}_=>N}_=>N}_=>N}b'w'=>match r[2]{b'a'=>match r[3]{b'p'=>match r[4]{b'g'=>match r[5]{b's'=>s(SWAPGS),_=>N}_=>N}_=>N}_=>N}b'y'=>match r[2]{b's'=>match r[3]{b'r'=>match r[4]{b'e'=>match r[5]{b't'=>s(SYSRET),_=>N}_=>N}_=>N}_=>N}_=>N}b't' => match r[1]{b'e'=>match r[2]{b's'=>match r[3]{b't'=>match r[4]{b'u'=>match r[5]{b'i'=>s(TESTUI),_=>N}_=>N}_=>N}_=>N}b'p'=>match r[2]{b'a'=>match r[3]{b'u'=>match r[4]{b's'=>match r[5]{b'e'=>s(TPAUSE),_=>N}_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'c'=>match r[2]{b'm'=>match r[3]{b'p' => match r[4]{b'p' => match r[5]{b'd'=>s(VCMPPD),b's'=>s(VCMPPS),_=>N}b's' => match r[5]{b'd'=>s(VCMPSD),b's'=>s(VCMPSS),_=>N}_=>N}_=>N}_=>N}b'd'=>match r[2]{b'i'=>match r[3]{b'v' => match r[4]{b'p' => match r[5]{b'd'=>s(VDIVPD),b's'=>s(VDIVPS),_=>N}b's' => match r[5]{b'd'=>s(VDIVSD),b's'=>s(VDIVSS),_=>N}_=>N}_=>N}_=>N}b'l'=>match r[2]{b'd'=>match r[3]{b'd'=>match r[4]{b'q'=>match r[5]{b'u'=>s(VLDDQU),_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b'c'=>match r[3]{b'p' => match r[4]{b'p'=>match r[5]{b's'=>s(VRCPPS),_=>N}b's'=>match r[5]{b's'=>s(VRCPSS),_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'u'=>match r[3]{b'b' => match r[4]{b'p' => match r[5]{b'd'=>s(VSUBPD),b's'=>s(VSUBPS),_=>N}b's' => match r[5]{b'd'=>s(VSUBSD),b's'=>s(VSUBSS),_=>N}_=>N}_=>N}_=>N}b'x'=>match r[2]{b'o'=>match r[3]{b'r'=>match r[4]{b'p' => match r[5]{b'd'=>s(VXORPD),b's'=>s(VXORPS),_=>N}_=>N}_=>N}_=>N}b'a' => match r[2]{b'd'=>match r[3]{b'd' => match r[4]{b'p' => match r[5]{b'd'=>s(VADDPD),b's'=>s(VADDPS),_=>N}b's' => match r[5]{b'd'=>s(VADDSD),b's'=>s(VADDSS),_=>N}_=>N}_=>N}b'n'=>match r[3]{b'd'=>match r[4]{b'p' => match r[5]{b'd'=>s(VANDPD),b's'=>s(VANDPS),_=>N}_=>N}_=>N}_=>N}b'm' => match r[2]{b'a'=>match r[3]{b'x' => match r[4]{b'p' => match r[5]{b'd'=>s(VMAXPD),b's'=>s(VMAXPS),_=>N}b's' => match r[5]{b'd'=>s(VMAXSD),b's'=>s(VMAXSS),_=>N}_=>N}_=>N}b'i'=>match r[3]{b'n' => match r[4]{b'p' => match r[5]{b'd'=>s(VMINPD),b's'=>s(VMINPS),_=>N}b's' => match r[5]{b'd'=>s(VMINSD),b's'=>s(VMINSS),_=>N}_=>N}_=>N}b'o'=>match r[3]{b'v'=>match r[4]{b's' => match r[5]{b'd'=>s(VMOVSD),b's'=>s(VMOVSS),_=>N}_=>N}_=>N}b'u'=>match r[3]{b'l' => match r[4]{b'p' => match r[5]{b'd'=>s(VMULPD),b's'=>s(VMULPS),_=>N}b's' => match r[5]{b'd'=>s(VMULSD),b's'=>s(VMULSS),_=>N}_=>N}_=>N}_=>N}b'p' => match r[2]{b'c'=>match r[3]{b'm'=>match r[4]{b'p' => match r[5]{b'd'=>s(VPCMPD),b'q'=>s(VPCMPQ),_=>N}_=>N}_=>N}b'e'=>match r[3]{b'r'=>match r[4]{b'm' => match r[5]{b'd'=>s(VPERMD),b'q'=>s(VPERMQ),_=>N}_=>N}_=>N}b't'=>match r[3]{b'e'=>match r[4]{b's'=>match r[5]{b't'=>s(VPTEST),_=>N}_=>N}_=>N}b'x'=>match r[3]{b'o'=>match r[4]{b'r' => match r[5]{b'd'=>s(VPXORD),b'q'=>s(VPXORQ),_=>N}_=>N}_=>N}b'a' => match r[3]{b'b'=>match r[4]{b's'=>match r[5]{b'q'=>s(VPABSQ),_=>N}_=>N}b'd'=>match r[4]{b'd' => match r[5]{b'b'=>s(VPADDB),b'd'=>s(VPADDD),b'q'=>s(VPADDQ),b'w'=>s(VPADDW),_=>N}_=>N}b'n'=>match r[4]{b'd' => match r[5]{b'd'=>s(VPANDD),b'n'=>s(VPANDN),b'q'=>s(VPANDQ),_=>N}_=>N}b'v'=>match r[4]{b'g' => match r[5]{b'b'=>s(VPAVGB),b'w'=>s(VPAVGW),_=>N}_=>N}_=>N}b's' => match r[3]{b'l'=>match r[4]{b'l' => match r[5]{b'd'=>s(VPSLLD),b'q'=>s(VPSLLQ),b'w'=>s(VPSLLW),_=>N}_=>N}b'u'=>match r[4]{b'b' => match r[5]{b'b'=>s(VPSUBB),b'd'=>s(VPSUBD),b'q'=>s(VPSUBQ),b'w'=>s(VPSUBW),_=>N}_=>N}b'r' => match r[4]{b'a' => match r[5]{b'd'=>s(VPSRAD),b'w'=>s(VPSRAW),_=>N}b'l' => match r[5]{b'd'=>s(VPSRLD),b'q'=>s(VPSRLQ),b'w'=>s(VPSRLW),_=>N}_=>N}_=>N}_=>N}_=>N}b'w' => match r[1]{b'b'=>match r[2]{b'i'=>match r[3]{b'n'=>match r[4]{b'v'=>match r[5]{b'd'=>s(WBINVD),_=>N}_=>N}_=>N}_=>N}b'o'=>match r[2]{b'r'=>match r[3]{b'd' => match r[4]{b'b'=>match r[5]{b'e'=>s(WORDBE),_=>N}b'l'=>match r[5]{b'e'=>s(WORDLE),_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b'p'=>match r[3]{b'k'=>match r[4]{b'r'=>match r[5]{b'u'=>s(WRPKRU),_=>N}_=>N}_=>N}_=>N}_=>N}b'x' => match r[1]{b'a'=>match r[2]{b'b'=>match r[3]{b'o'=>match r[4]{b'r'=>match r[5]{b't'=>s(XABORT),_=>N}_=>N}_=>N}_=>N}b'b'=>match r[2]{b'e'=>match r[3]{b'g'=>match r[4]{b'i'=>match r[5]{b'n'=>s(XBEGIN),_=>N}_=>N}_=>N}_=>N}b'g'=>match r[2]{b'e'=>match r[3]{b't'=>match r[4]{b'b'=>match r[5]{b'v'=>s(XGETBV),_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b's'=>match r[3]{b't'=>match r[4]{b'o'=>match r[5]{b'r'=>s(XRSTOR),_=>N}_=>N}_=>N}_=>N}b's' => match r[2]{b'a'=>match r[3]{b'v'=>match r[4]{b'e' => match r[5]{b'c'=>s(XSAVEC),b's'=>s(XSAVES),_=>N}_=>N}_=>N}b'e'=>match r[3]{b't'=>match r[4]{b'b'=>match r[5]{b'v'=>s(XSETBV),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}7=>match r[0]{b'b'=>match r[1]{b'l'=>match r[2]{b'e'=>match r[3]{b'n'=>match r[4]{b'd'=>match r[5]{b'p' => match r[6]{b'd'=>s(BLENDPD),b's'=>s(BLENDPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'd'=>match r[1]{b'w'=>match r[2]{b'o'=>match r[3]{b'r'=>match r[4]{b'd' => match r[5]{b'b'=>match r[6]{b'e'=>s(DWORDBE),_=>N}b'l'=>match r[6]{b'e'=>s(DWORDLE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[1]{b'n'=>match r[2]{b'd'=>match r[3]{b'b'=>match r[4]{b'r' => match r[5]{b'3'=>match r[6]{b'2'=>s(ENDBR32),_=>N}b'6'=>match r[6]{b'4'=>s(ENDBR64),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'i'=>match r[1]{b'n' => match r[2]{b'p'=>match r[3]{b'o'=>match r[4]{b'r'=>match r[5]{b't' => match r[6]{b'b'=>s(INPORTB),b'd'=>s(INPORTD),b'w'=>s(INPORTW),_=>N}_=>N}_=>N}_=>N}b'v'=>match r[3]{b'p'=>match r[4]{b'c'=>match r[5]{b'i'=>match r[6]{b'd'=>s(INVPCID),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'l'=>match r[1]{b'd'=>match r[2]{b'm'=>match r[3]{b'x'=>match r[4]{b'c'=>match r[5]{b's'=>match r[6]{b'r'=>s(LDMXCSR),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'q'=>match r[1]{b'w'=>match r[2]{b'o'=>match r[3]{b'r'=>match r[4]{b'd' => match r[5]{b'b'=>match r[6]{b'e'=>s(QWORDBE),_=>N}b'l'=>match r[6]{b'e'=>s(QWORDLE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[1]{b'c'=>match r[2]{b'o'=>match r[3]{b'm'=>match r[4]{b'i'=>match r[5]{b's' => match r[6]{b'd'=>s(UCOMISD),b's'=>s(UCOMISS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c' => match r[1]{b'l'=>match r[2]{b'f'=>match r[3]{b'l'=>match r[4]{b'u'=>match r[5]{b's'=>match r[6]{b'h'=>s(CLFLUSH),_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[2]{b'o'=>match r[3]{b'v'=>match r[4]{b'n' => match r[5]{b'a'=>match r[6]{b'e'=>s(CMOVNAE),_=>N}b'b'=>match r[6]{b'e'=>s(CMOVNBE),_=>N}b'g'=>match r[6]{b'e'=>s(CMOVNGE),_=>N}b'l'=>match r[6]{b'e'=>s(CMOVNLE),_=>N}_=>N}_=>N}_=>N}b'p' => match r[3]{b's'=>match r[4]{b't'=>match r[5]{b'r' => match r[6]{b'b'=>s(CMPSTRB),b'd'=>s(CMPSTRD),b'q'=>s(CMPSTRQ),b'w'=>s(CMPSTRW),_=>N}_=>N}_=>N}b'x'=>match r[4]{b'c'=>match r[5]{b'h'=>match r[6]{b'g'=>s(CMPXCHG),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'f' => match r[1]{b'c'=>match r[2]{b'm'=>match r[3]{b'o'=>match r[4]{b'v' => match r[5]{b'b'=>match r[6]{b'e'=>s(FCMOVBE),_=>N}b'n' => match r[6]{b'b'=>s(FCMOVNB),b'e'=>s(FCMOVNE),b'u'=>s(FCMOVNU),_=>N}_=>N}_=>N}_=>N}_=>N}b'd'=>match r[2]{b'e'=>match r[3]{b'c'=>match r[4]{b's'=>match r[5]{b't'=>match r[6]{b'p'=>s(FDECSTP),_=>N}_=>N}_=>N}_=>N}_=>N}b'i'=>match r[2]{b'n'=>match r[3]{b'c'=>match r[4]{b's'=>match r[5]{b't'=>match r[6]{b'p'=>s(FINCSTP),_=>N}_=>N}_=>N}_=>N}_=>N}b'n'=>match r[2]{b's'=>match r[3]{b't'=>match r[4]{b'e'=>match r[5]{b'n'=>match r[6]{b'v'=>s(FNSTENV),_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b'n'=>match r[3]{b'd'=>match r[4]{b'i'=>match r[5]{b'n'=>match r[6]{b't'=>s(FRNDINT),_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'i'=>match r[3]{b'n'=>match r[4]{b'c'=>match r[5]{b'o'=>match r[6]{b's'=>s(FSINCOS),_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[2]{b'c'=>match r[3]{b'o'=>match r[4]{b'm' => match r[5]{b'i'=>match r[6]{b'p'=>s(FUCOMIP),_=>N}b'p'=>match r[6]{b'p'=>s(FUCOMPP),_=>N}_=>N}_=>N}_=>N}_=>N}b'y'=>match r[2]{b'l'=>match r[3]{b'2'=>match r[4]{b'x'=>match r[5]{b'p'=>match r[6]{b'1'=>s(FYL2XP1),_=>N}_=>N}_=>N}_=>N}_=>N}b'x' => match r[2]{b'r'=>match r[3]{b's'=>match r[4]{b't'=>match r[5]{b'o'=>match r[6]{b'r'=>s(FXRSTOR),_=>N}_=>N}_=>N}_=>N}b't'=>match r[3]{b'r'=>match r[4]{b'a'=>match r[5]{b'c'=>match r[6]{b't'=>s(FXTRACT),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[1]{b'o' => match r[2]{b'n'=>match r[3]{b'i'=>match r[4]{b't'=>match r[5]{b'o'=>match r[6]{b'r'=>s(MONITOR),_=>N}_=>N}_=>N}_=>N}b'v' => match r[3]{b'h'=>match r[4]{b'l'=>match r[5]{b'p'=>match r[6]{b's'=>s(MOVHLPS),_=>N}_=>N}_=>N}b'l'=>match r[4]{b'h'=>match r[5]{b'p'=>match r[6]{b's'=>s(MOVLHPS),_=>N}_=>N}_=>N}b'n'=>match r[4]{b't' => match r[5]{b'd'=>match r[6]{b'q'=>s(MOVNTDQ),_=>N}b'p'=>match r[6]{b'd'=>s(MOVNTPD),_=>N}_=>N}_=>N}b'q'=>match r[4]{b'2'=>match r[5]{b'd'=>match r[6]{b'q'=>s(MOVQ2DQ),_=>N}_=>N}_=>N}b's'=>match r[4]{b't'=>match r[5]{b'r' => match r[6]{b'b'=>s(MOVSTRB),b'd'=>s(MOVSTRD),b'q'=>s(MOVSTRQ),b'w'=>s(MOVSTRW),_=>N}_=>N}_=>N}b'd' => match r[4]{b'd'=>match r[5]{b'u'=>match r[6]{b'p'=>s(MOVDDUP),_=>N}_=>N}b'i'=>match r[5]{b'r'=>match r[6]{b'i'=>s(MOVDIRI),_=>N}_=>N}b'q'=>match r[5]{b'2'=>match r[6]{b'q'=>s(MOVDQ2Q),_=>N}_=>N}_=>N}_=>N}_=>N}b'p'=>match r[2]{b's'=>match r[3]{b'a'=>match r[4]{b'd'=>match r[5]{b'b'=>match r[6]{b'w'=>s(MPSADBW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[1]{b'b'=>match r[2]{b'l'=>match r[3]{b'e'=>match r[4]{b'n'=>match r[5]{b'd'=>match r[6]{b'w'=>s(PBLENDW),_=>N}_=>N}_=>N}_=>N}_=>N}b'c'=>match r[2]{b'm'=>match r[3]{b'p' => match r[4]{b'e'=>match r[5]{b'q' => match r[6]{b'b'=>s(PCMPEQB),b'd'=>s(PCMPEQD),b'q'=>s(PCMPEQQ),b'w'=>s(PCMPEQW),_=>N}_=>N}b'g'=>match r[5]{b't' => match r[6]{b'b'=>s(PCMPGTB),b'd'=>s(PCMPGTD),b'q'=>s(PCMPGTQ),b'w'=>s(PCMPGTW),_=>N}_=>N}_=>N}_=>N}_=>N}b'a' => match r[2]{b'd'=>match r[3]{b'd'=>match r[4]{b'u'=>match r[5]{b's' => match r[6]{b'b'=>s(PADDUSB),b'w'=>s(PADDUSW),_=>N}_=>N}_=>N}_=>N}b'l'=>match r[3]{b'i'=>match r[4]{b'g'=>match r[5]{b'n'=>match r[6]{b'r'=>s(PALIGNR),_=>N}_=>N}_=>N}_=>N}_=>N}b'h' => match r[2]{b'a'=>match r[3]{b'd'=>match r[4]{b'd'=>match r[5]{b's'=>match r[6]{b'w'=>s(PHADDSW),_=>N}_=>N}_=>N}_=>N}b's'=>match r[3]{b'u'=>match r[4]{b'b'=>match r[5]{b's'=>match r[6]{b'w'=>s(PHSUBSW),_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[2]{b'a'=>match r[3]{b'd'=>match r[4]{b'd'=>match r[5]{b'w'=>match r[6]{b'd'=>s(PMADDWD),_=>N}_=>N}_=>N}_=>N}b'u'=>match r[3]{b'l' => match r[4]{b'h'=>match r[5]{b'u'=>match r[6]{b'w'=>s(PMULHUW),_=>N}_=>N}b'u'=>match r[5]{b'd'=>match r[6]{b'q'=>s(PMULUDQ),_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[2]{b'h'=>match r[3]{b'u'=>match r[4]{b'f' => match r[5]{b'h'=>match r[6]{b'w'=>s(PSHUFHW),_=>N}b'l'=>match r[6]{b'w'=>s(PSHUFLW),_=>N}_=>N}_=>N}_=>N}b'u'=>match r[3]{b'b'=>match r[4]{b'u'=>match r[5]{b's' => match r[6]{b'b'=>s(PSUBUSB),b'w'=>s(PSUBUSW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r' => match r[1]{b'o'=>match r[2]{b'u'=>match r[3]{b'n'=>match r[4]{b'd' => match r[5]{b'p' => match r[6]{b'd'=>s(ROUNDPD),b's'=>s(ROUNDPS),_=>N}b's' => match r[6]{b'd'=>s(ROUNDSD),b's'=>s(ROUNDSS),_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'q'=>match r[3]{b'r'=>match r[4]{b't' => match r[5]{b'p'=>match r[6]{b's'=>s(RSQRTPS),_=>N}b's'=>match r[6]{b's'=>s(RSQRTSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[1]{b'e'=>match r[2]{b't'=>match r[3]{b's'=>match r[4]{b's'=>match r[5]{b'b'=>match r[6]{b'y'=>s(SETSSBY),_=>N}_=>N}_=>N}_=>N}_=>N}b't'=>match r[2]{b'm'=>match r[3]{b'x'=>match r[4]{b'c'=>match r[5]{b's'=>match r[6]{b'r'=>s(STMXCSR),_=>N}_=>N}_=>N}_=>N}_=>N}b'y'=>match r[2]{b's' => match r[3]{b'c'=>match r[4]{b'a'=>match r[5]{b'l'=>match r[6]{b'l'=>s(SYSCALL),_=>N}_=>N}_=>N}b'e'=>match r[4]{b'x'=>match r[5]{b'i'=>match r[6]{b't'=>s(SYSEXIT),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'c'=>match r[2]{b'o'=>match r[3]{b'm'=>match r[4]{b'i'=>match r[5]{b's' => match r[6]{b'd'=>s(VCOMISD),b's'=>s(VCOMISS),_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[2]{b'o'=>match r[3]{b'v' => match r[4]{b'a'=>match r[5]{b'p' => match r[6]{b'd'=>s(VMOVAPD),b's'=>s(VMOVAPS),_=>N}_=>N}b'd'=>match r[5]{b'q'=>match r[6]{b'a'=>s(VMOVDQA),_=>N}_=>N}b'h'=>match r[5]{b'p' => match r[6]{b'd'=>s(VMOVHPD),b's'=>s(VMOVHPS),_=>N}_=>N}b'l'=>match r[5]{b'p' => match r[6]{b'd'=>s(VMOVLPD),b's'=>s(VMOVLPS),_=>N}_=>N}b'u'=>match r[5]{b'p' => match r[6]{b'd'=>s(VMOVUPD),b's'=>s(VMOVUPS),_=>N}_=>N}_=>N}_=>N}_=>N}b'a' => match r[2]{b'e'=>match r[3]{b's' => match r[4]{b'd'=>match r[5]{b'e'=>match r[6]{b'c'=>s(VAESDEC),_=>N}_=>N}b'e'=>match r[5]{b'n'=>match r[6]{b'c'=>s(VAESENC),_=>N}_=>N}b'i'=>match r[5]{b'm'=>match r[6]{b'c'=>s(VAESIMC),_=>N}_=>N}_=>N}_=>N}b'l'=>match r[3]{b'i'=>match r[4]{b'g'=>match r[5]{b'n' => match r[6]{b'd'=>s(VALIGND),b'q'=>s(VALIGNQ),_=>N}_=>N}_=>N}_=>N}b'n'=>match r[3]{b'd'=>match r[4]{b'n'=>match r[5]{b'p' => match r[6]{b'd'=>s(VANDNPD),b's'=>s(VANDNPS),_=>N}_=>N}_=>N}_=>N}_=>N}b'h' => match r[2]{b'a'=>match r[3]{b'd'=>match r[4]{b'd'=>match r[5]{b'p' => match r[6]{b'd'=>s(VHADDPD),b's'=>s(VHADDPS),_=>N}_=>N}_=>N}_=>N}b's'=>match r[3]{b'u'=>match r[4]{b'b'=>match r[5]{b'p' => match r[6]{b'd'=>s(VHSUBPD),b's'=>s(VHSUBPS),_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[2]{b'c'=>match r[3]{b'm'=>match r[4]{b'p'=>match r[5]{b'u' => match r[6]{b'd'=>s(VPCMPUD),b'q'=>s(VPCMPUQ),_=>N}_=>N}_=>N}_=>N}b'i'=>match r[3]{b'n'=>match r[4]{b's'=>match r[5]{b'r' => match r[6]{b'b'=>s(VPINSRB),b'd'=>s(VPINSRD),b'q'=>s(VPINSRQ),b'w'=>s(VPINSRW),_=>N}_=>N}_=>N}_=>N}b'a' => match r[3]{b'd'=>match r[4]{b'd'=>match r[5]{b's' => match r[6]{b'b'=>s(VPADDSB),b'w'=>s(VPADDSW),_=>N}_=>N}_=>N}b'n'=>match r[4]{b'd'=>match r[5]{b'n' => match r[6]{b'd'=>s(VPANDND),b'q'=>s(VPANDNQ),_=>N}_=>N}_=>N}_=>N}b'e' => match r[3]{b'r'=>match r[4]{b'm'=>match r[5]{b'p' => match r[6]{b'd'=>s(VPERMPD),b's'=>s(VPERMPS),_=>N}_=>N}_=>N}b'x'=>match r[4]{b't'=>match r[5]{b'r' => match r[6]{b'b'=>s(VPEXTRB),b'd'=>s(VPEXTRD),b'q'=>s(VPEXTRQ),b'w'=>s(VPEXTRW),_=>N}_=>N}_=>N}_=>N}b'h' => match r[3]{b'a'=>match r[4]{b'd'=>match r[5]{b'd' => match r[6]{b'd'=>s(VPHADDD),b'w'=>s(VPHADDW),_=>N}_=>N}_=>N}b's'=>match r[4]{b'u'=>match r[5]{b'b' => match r[6]{b'd'=>s(VPHSUBD),b'w'=>s(VPHSUBW),_=>N}_=>N}_=>N}_=>N}b'm' => match r[3]{b'a'=>match r[4]{b'x' => match r[5]{b's' => match r[6]{b'b'=>s(VPMAXSB),b'd'=>s(VPMAXSD),b'q'=>s(VPMAXSQ),b'w'=>s(VPMAXSW),_=>N}b'u' => match r[6]{b'b'=>s(VPMAXUB),b'd'=>s(VPMAXUD),b'q'=>s(VPMAXUQ),b'w'=>s(VPMAXUW),_=>N}_=>N}_=>N}b'i'=>match r[4]{b'n' => match r[5]{b's' => match r[6]{b'b'=>s(VPMINSB),b'd'=>s(VPMINSD),b'q'=>s(VPMINSQ),b'w'=>s(VPMINSW),_=>N}b'u' => match r[6]{b'b'=>s(VPMINUB),b'q'=>s(VPMINUQ),b'w'=>s(VPMINUW),_=>N}_=>N}_=>N}b'u'=>match r[4]{b'l' => match r[5]{b'd'=>match r[6]{b'q'=>s(VPMULDQ),_=>N}b'h'=>match r[6]{b'w'=>s(VPMULHW),_=>N}b'l' => match r[6]{b'd'=>s(VPMULLD),b'w'=>s(VPMULLW),_=>N}_=>N}_=>N}_=>N}b's' => match r[3]{b'i'=>match r[4]{b'g'=>match r[5]{b'n' => match r[6]{b'b'=>s(VPSIGNB),b'd'=>s(VPSIGND),b'w'=>s(VPSIGNW),_=>N}_=>N}_=>N}b'l'=>match r[4]{b'l'=>match r[5]{b'v' => match r[6]{b'd'=>s(VPSLLVD),b'q'=>s(VPSLLVQ),_=>N}_=>N}_=>N}b'u'=>match r[4]{b'b'=>match r[5]{b's' => match r[6]{b'b'=>s(VPSUBSB),b'w'=>s(VPSUBSW),_=>N}_=>N}_=>N}b'r' => match r[4]{b'a'=>match r[5]{b'v'=>match r[6]{b'd'=>s(VPSRAVD),_=>N}_=>N}b'l' => match r[5]{b'd'=>match r[6]{b'q'=>s(VPSRLDQ),_=>N}b'v' => match r[6]{b'd'=>s(VPSRLVD),b'q'=>s(VPSRLVQ),_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[2]{b'h'=>match r[3]{b'u'=>match r[4]{b'f'=>match r[5]{b'p'=>match r[6]{b's'=>s(VSHUFPS),_=>N}_=>N}_=>N}_=>N}b'q'=>match r[3]{b'r'=>match r[4]{b't' => match r[5]{b'p' => match r[6]{b'd'=>s(VSQRTPD),b's'=>s(VSQRTPS),_=>N}b's' => match r[6]{b'd'=>s(VSQRTSD),b's'=>s(VSQRTSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'x' => match r[1]{b'l'=>match r[2]{b'a'=>match r[3]{b't'=>match r[4]{b'b'=>match r[5]{b'6'=>match r[6]{b'4'=>s(XLATB64),_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b's'=>match r[3]{b't'=>match r[4]{b'o'=>match r[5]{b'r'=>match r[6]{b's'=>s(XRSTORS),_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'a'=>match r[3]{b'v'=>match r[4]{b'e'=>match r[5]{b'6'=>match r[6]{b'4'=>s(XSAVE64),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}8=>match r[0]{b'a'=>match r[1]{b'd'=>match r[2]{b'd'=>match r[3]{b's'=>match r[4]{b'u'=>match r[5]{b'b'=>match r[6]{b'p' => match r[7]{b'd'=>s(ADDSUBPD),b's'=>s(ADDSUBPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'b'=>match r[1]{b'l'=>match r[2]{b'e'=>match r[3]{b'n'=>match r[4]{b'd'=>match r[5]{b'v'=>match r[6]{b'p' => match r[7]{b'd'=>s(BLENDVPD),b's'=>s(BLENDVPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'i'=>match r[1]{b'n'=>match r[2]{b's'=>match r[3]{b'e'=>match r[4]{b'r'=>match r[5]{b't'=>match r[6]{b'p'=>match r[7]{b's'=>s(INSERTPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[1]{b'o'=>match r[2]{b'v' => match r[3]{b'm'=>match r[4]{b's'=>match r[5]{b'k'=>match r[6]{b'p'=>match r[7]{b'd'=>s(MOVMSKPD),_=>N}_=>N}_=>N}_=>N}b'n'=>match r[4]{b't'=>match r[5]{b'd'=>match r[6]{b'q'=>match r[7]{b'a'=>s(MOVNTDQA),_=>N}_=>N}_=>N}_=>N}b's' => match r[4]{b'h'=>match r[5]{b'd'=>match r[6]{b'u'=>match r[7]{b'p'=>s(MOVSHDUP),_=>N}_=>N}_=>N}b'l'=>match r[5]{b'd'=>match r[6]{b'u'=>match r[7]{b'p'=>s(MOVSLDUP),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'w'=>match r[1]{b'r' => match r[2]{b'f'=>match r[3]{b's'=>match r[4]{b'b'=>match r[5]{b'a'=>match r[6]{b's'=>match r[7]{b'e'=>s(WRFSBASE),_=>N}_=>N}_=>N}_=>N}_=>N}b'g'=>match r[3]{b's'=>match r[4]{b'b'=>match r[5]{b'a'=>match r[6]{b's'=>match r[7]{b'e'=>s(WRGSBASE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c' => match r[1]{b'v'=>match r[2]{b't' => match r[3]{b'd'=>match r[4]{b'q'=>match r[5]{b'2'=>match r[6]{b'p' => match r[7]{b'd'=>s(CVTDQ2PD),b's'=>s(CVTDQ2PS),_=>N}_=>N}_=>N}_=>N}b'p' => match r[4]{b'd'=>match r[5]{b'2' => match r[6]{b'd'=>match r[7]{b'q'=>s(CVTPD2DQ),_=>N}b'p' => match r[7]{b'i'=>s(CVTPD2PI),b's'=>s(CVTPD2PS),_=>N}_=>N}_=>N}b'i'=>match r[5]{b'2'=>match r[6]{b'p' => match r[7]{b'd'=>s(CVTPI2PD),b's'=>s(CVTPI2PS),_=>N}_=>N}_=>N}b's'=>match r[5]{b'2' => match r[6]{b'd'=>match r[7]{b'q'=>s(CVTPS2DQ),_=>N}b'p' => match r[7]{b'd'=>s(CVTPS2PD),b'i'=>s(CVTPS2PI),_=>N}_=>N}_=>N}_=>N}b's' => match r[4]{b'd'=>match r[5]{b'2'=>match r[6]{b's' => match r[7]{b'i'=>s(CVTSD2SI),b's'=>s(CVTSD2SS),_=>N}_=>N}_=>N}b'i'=>match r[5]{b'2'=>match r[6]{b's' => match r[7]{b'd'=>s(CVTSI2SD),b's'=>s(CVTSI2SS),_=>N}_=>N}_=>N}b's'=>match r[5]{b'2'=>match r[6]{b's' => match r[7]{b'd'=>s(CVTSS2SD),b'i'=>s(CVTSS2SI),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'l' => match r[2]{b'd'=>match r[3]{b'e'=>match r[4]{b'm'=>match r[5]{b'o'=>match r[6]{b't'=>match r[7]{b'e'=>s(CLDEMOTE),_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[3]{b's'=>match r[4]{b's'=>match r[5]{b'b'=>match r[6]{b's'=>match r[7]{b'y'=>s(CLRSSBSY),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'f' => match r[1]{b'c'=>match r[2]{b'm'=>match r[3]{b'o'=>match r[4]{b'v'=>match r[5]{b'n'=>match r[6]{b'b'=>match r[7]{b'e'=>s(FCMOVNBE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'x'=>match r[2]{b's'=>match r[3]{b'a'=>match r[4]{b'v'=>match r[5]{b'e'=>match r[6]{b'6'=>match r[7]{b'4'=>s(FXSAVE64),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[1]{b'a'=>match r[2]{b'c'=>match r[3]{b'k' => match r[4]{b's'=>match r[5]{b's' => match r[6]{b'd'=>match r[7]{b'w'=>s(PACKSSDW),_=>N}b'w'=>match r[7]{b'b'=>s(PACKSSWB),_=>N}_=>N}_=>N}b'u'=>match r[5]{b's' => match r[6]{b'd'=>match r[7]{b'w'=>s(PACKUSDW),_=>N}b'w'=>match r[7]{b'b'=>s(PACKUSWB),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'b'=>match r[2]{b'l'=>match r[3]{b'e'=>match r[4]{b'n'=>match r[5]{b'd'=>match r[6]{b'v'=>match r[7]{b'b'=>s(PBLENDVB),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[2]{b'u'=>match r[3]{b'l'=>match r[4]{b'h'=>match r[5]{b'r'=>match r[6]{b's'=>match r[7]{b'w'=>s(PMULHRSW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r' => match r[1]{b'd' => match r[2]{b'f'=>match r[3]{b's'=>match r[4]{b'b'=>match r[5]{b'a'=>match r[6]{b's'=>match r[7]{b'e'=>s(RDFSBASE),_=>N}_=>N}_=>N}_=>N}_=>N}b'g'=>match r[3]{b's'=>match r[4]{b'b'=>match r[5]{b'a'=>match r[6]{b's'=>match r[7]{b'e'=>s(RDGSBASE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b't'=>match r[3]{b'o'=>match r[4]{b'r'=>match r[5]{b's'=>match r[6]{b's'=>match r[7]{b'p'=>s(RSTORSSP),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[1]{b'e'=>match r[2]{b'n'=>match r[3]{b'd'=>match r[4]{b'u'=>match r[5]{b'i'=>match r[6]{b'p'=>match r[7]{b'i'=>s(SENDUIPI),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'h'=>match r[2]{b'a'=>match r[3]{b'1'=>match r[4]{b'm'=>match r[5]{b's'=>match r[6]{b'g' => match r[7]{b'1'=>s(SHA1MSG1),b'2'=>s(SHA1MSG2),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'y'=>match r[2]{b's'=>match r[3]{b'e'=>match r[4]{b'n'=>match r[5]{b't'=>match r[6]{b'e'=>match r[7]{b'r'=>s(SYSENTER),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'u' => match r[1]{b'm'=>match r[2]{b'o'=>match r[3]{b'n'=>match r[4]{b'i'=>match r[5]{b't'=>match r[6]{b'o'=>match r[7]{b'r'=>s(UMONITOR),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'n'=>match r[2]{b'p'=>match r[3]{b'c'=>match r[4]{b'k' => match r[5]{b'h'=>match r[6]{b'p'=>match r[7]{b's'=>s(UNPCKHPS),_=>N}_=>N}b'l'=>match r[6]{b'p'=>match r[7]{b's'=>s(UNPCKLPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'b'=>match r[2]{b'l'=>match r[3]{b'e'=>match r[4]{b'n'=>match r[5]{b'd'=>match r[6]{b'p' => match r[7]{b'd'=>s(VBLENDPD),b's'=>s(VBLENDPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'l'=>match r[2]{b'd'=>match r[3]{b'm'=>match r[4]{b'x'=>match r[5]{b'c'=>match r[6]{b's'=>match r[7]{b'r'=>s(VLDMXCSR),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b't'=>match r[3]{b'm'=>match r[4]{b'x'=>match r[5]{b'c'=>match r[6]{b's'=>match r[7]{b'r'=>s(VSTMXCSR),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[2]{b'c'=>match r[3]{b'o'=>match r[4]{b'm'=>match r[5]{b'i'=>match r[6]{b's' => match r[7]{b'd'=>s(VUCOMISD),b's'=>s(VUCOMISS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'z'=>match r[2]{b'e'=>match r[3]{b'r'=>match r[4]{b'o'=>match r[5]{b'a'=>match r[6]{b'l'=>match r[7]{b'l'=>s(VZEROALL),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[2]{b'o'=>match r[3]{b'v' => match r[4]{b'd'=>match r[5]{b'd'=>match r[6]{b'u'=>match r[7]{b'p'=>s(VMOVDDUP),_=>N}_=>N}_=>N}b'h'=>match r[5]{b'l'=>match r[6]{b'p'=>match r[7]{b's'=>s(VMOVHLPS),_=>N}_=>N}_=>N}b'l'=>match r[5]{b'h'=>match r[6]{b'p'=>match r[7]{b's'=>s(VMOVLHPS),_=>N}_=>N}_=>N}_=>N}_=>N}b'p'=>match r[3]{b's'=>match r[4]{b'a'=>match r[5]{b'd'=>match r[6]{b'b'=>match r[7]{b'w'=>s(VMPSADBW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[2]{b'b'=>match r[3]{b'l'=>match r[4]{b'e'=>match r[5]{b'n'=>match r[6]{b'd'=>match r[7]{b'w'=>s(VPBLENDW),_=>N}_=>N}_=>N}_=>N}_=>N}b'c'=>match r[3]{b'm'=>match r[4]{b'p' => match r[5]{b'e'=>match r[6]{b'q' => match r[7]{b'b'=>s(VPCMPEQB),b'd'=>s(VPCMPEQD),b'q'=>s(VPCMPEQQ),b'w'=>s(VPCMPEQW),_=>N}_=>N}b'g'=>match r[6]{b't' => match r[7]{b'b'=>s(VPCMPGTB),b'd'=>s(VPCMPGTD),b'q'=>s(VPCMPGTQ),b'w'=>s(VPCMPGTW),_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[3]{b'r'=>match r[4]{b'm' => match r[5]{b'i'=>match r[6]{b'2' => match r[7]{b'd'=>s(VPERMI2D),b'q'=>s(VPERMI2Q),_=>N}_=>N}b't'=>match r[6]{b'2' => match r[7]{b'd'=>s(VPERMT2D),b'q'=>s(VPERMT2Q),_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[3]{b'u'=>match r[4]{b'b'=>match r[5]{b'u'=>match r[6]{b's' => match r[7]{b'b'=>s(VPSUBUSB),b'w'=>s(VPSUBUSW),_=>N}_=>N}_=>N}_=>N}_=>N}b'a' => match r[3]{b'd'=>match r[4]{b'd'=>match r[5]{b'u'=>match r[6]{b's' => match r[7]{b'b'=>s(VPADDUSB),b'w'=>s(VPADDUSW),_=>N}_=>N}_=>N}_=>N}b'l'=>match r[4]{b'i'=>match r[5]{b'g'=>match r[6]{b'n'=>match r[7]{b'r'=>s(VPALIGNR),_=>N}_=>N}_=>N}_=>N}_=>N}b'm' => match r[3]{b'a'=>match r[4]{b'd'=>match r[5]{b'd'=>match r[6]{b'w'=>match r[7]{b'd'=>s(VPMADDWD),_=>N}_=>N}_=>N}_=>N}b'u'=>match r[4]{b'l' => match r[5]{b'h'=>match r[6]{b'u'=>match r[7]{b'w'=>s(VPMULHUW),_=>N}_=>N}b'u'=>match r[6]{b'd'=>match r[7]{b'q'=>s(VPMULUDQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r' => match r[2]{b'o'=>match r[3]{b'u'=>match r[4]{b'n'=>match r[5]{b'd' => match r[6]{b'p' => match r[7]{b'd'=>s(VROUNDPD),b's'=>s(VROUNDPS),_=>N}b's' => match r[7]{b'd'=>s(VROUNDSD),b's'=>s(VROUNDSS),_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[3]{b'q'=>match r[4]{b'r'=>match r[5]{b't' => match r[6]{b'p'=>match r[7]{b's'=>s(VRSQRTPS),_=>N}b's'=>match r[7]{b's'=>s(VRSQRTSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'x' => match r[1]{b'a'=>match r[2]{b'c'=>match r[3]{b'q'=>match r[4]{b'u'=>match r[5]{b'i'=>match r[6]{b'r'=>match r[7]{b'e'=>s(XACQUIRE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'a'=>match r[3]{b'v'=>match r[4]{b'e' => match r[5]{b'c'=>match r[6]{b'6'=>match r[7]{b'4'=>s(XSAVEC64),_=>N}_=>N}b'o'=>match r[6]{b'p'=>match r[7]{b't'=>s(XSAVEOPT),_=>N}_=>N}b's'=>match r[6]{b'6'=>match r[7]{b'4'=>s(XSAVES64),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r' => match r[2]{b'e'=>match r[3]{b'l'=>match r[4]{b'e'=>match r[5]{b'a'=>match r[6]{b's'=>match r[7]{b'e'=>s(XRELEASE),_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[3]{b't'=>match r[4]{b'o'=>match r[5]{b'r'=>match r[6]{b'6'=>match r[7]{b'4'=>s(XRSTOR64),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}9=>match r[0]{b'e'=>match r[1]{b'x'=>match r[2]{b't'=>match r[3]{b'r'=>match r[4]{b'a'=>match r[5]{b'c'=>match r[6]{b't'=>match r[7]{b'p'=>match r[8]{b's'=>s(EXTRACTPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'f'=>match r[1]{b'x'=>match r[2]{b'r'=>match r[3]{b's'=>match r[4]{b't'=>match r[5]{b'o'=>match r[6]{b'r'=>match r[7]{b'6'=>match r[8]{b'4'=>s(FXRSTOR64),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c' => match r[1]{b'm'=>match r[2]{b'p'=>match r[3]{b'x'=>match r[4]{b'c'=>match r[5]{b'h'=>match r[6]{b'g'=>match r[7]{b'8'=>match r[8]{b'b'=>s(CMPXCHG8B),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v'=>match r[2]{b't'=>match r[3]{b't' => match r[4]{b'p' => match r[5]{b'd'=>match r[6]{b'2' => match r[7]{b'd'=>match r[8]{b'q'=>s(CVTTPD2DQ),_=>N}b'p'=>match r[8]{b'i'=>s(CVTTPD2PI),_=>N}_=>N}_=>N}b's'=>match r[6]{b'2' => match r[7]{b'd'=>match r[8]{b'q'=>s(CVTTPS2DQ),_=>N}b'p'=>match r[8]{b'i'=>s(CVTTPS2PI),_=>N}_=>N}_=>N}_=>N}b's' => match r[5]{b'd'=>match r[6]{b'2'=>match r[7]{b's'=>match r[8]{b'i'=>s(CVTTSD2SI),_=>N}_=>N}_=>N}b's'=>match r[6]{b'2'=>match r[7]{b's'=>match r[8]{b'i'=>s(CVTTSS2SI),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[1]{b'm'=>match r[2]{b'a'=>match r[3]{b'd'=>match r[4]{b'd'=>match r[5]{b'u'=>match r[6]{b'b'=>match r[7]{b's'=>match r[8]{b'w'=>s(PMADDUBSW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'r'=>match r[2]{b'e'=>match r[3]{b'f'=>match r[4]{b'e'=>match r[5]{b't'=>match r[6]{b'c'=>match r[7]{b'h' => match r[8]{b'0'=>s(PREFETCH0),b'1'=>s(PREFETCH1),b'2'=>s(PREFETCH2),b'a'=>s(PREFETCHA),b'w'=>s(PREFETCHW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[2]{b'n'=>match r[3]{b'p'=>match r[4]{b'c'=>match r[5]{b'k' => match r[6]{b'h' => match r[7]{b'b'=>match r[8]{b'w'=>s(PUNPCKHBW),_=>N}b'd'=>match r[8]{b'q'=>s(PUNPCKHDQ),_=>N}b'w'=>match r[8]{b'd'=>s(PUNPCKHWD),_=>N}_=>N}b'l' => match r[7]{b'b'=>match r[8]{b'w'=>s(PUNPCKLBW),_=>N}b'd'=>match r[8]{b'q'=>s(PUNPCKLDQ),_=>N}b'w'=>match r[8]{b'd'=>s(PUNPCKLWD),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c' => match r[2]{b'l'=>match r[3]{b'm'=>match r[4]{b'u'=>match r[5]{b'l'=>match r[6]{b'q'=>match r[7]{b'd'=>match r[8]{b'q'=>s(PCLMULQDQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[3]{b'p' => match r[4]{b'e'=>match r[5]{b's'=>match r[6]{b't'=>match r[7]{b'r' => match r[8]{b'i'=>s(PCMPESTRI),b'm'=>s(PCMPESTRM),_=>N}_=>N}_=>N}_=>N}b'i'=>match r[5]{b's'=>match r[6]{b't'=>match r[7]{b'r' => match r[8]{b'i'=>s(PCMPISTRI),b'm'=>s(PCMPISTRM),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's' => match r[1]{b'e'=>match r[2]{b'r'=>match r[3]{b'i'=>match r[4]{b'a'=>match r[5]{b'l'=>match r[6]{b'i'=>match r[7]{b'z'=>match r[8]{b'e'=>s(SERIALIZE),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'h'=>match r[2]{b'a'=>match r[3]{b'1' => match r[4]{b'n'=>match r[5]{b'e'=>match r[6]{b'x'=>match r[7]{b't'=>match r[8]{b'e'=>s(SHA1NEXTE),_=>N}_=>N}_=>N}_=>N}b'r'=>match r[5]{b'n'=>match r[6]{b'd'=>match r[7]{b's'=>match r[8]{b'4'=>s(SHA1RNDS4),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'a'=>match r[2]{b'd'=>match r[3]{b'd'=>match r[4]{b's'=>match r[5]{b'u'=>match r[6]{b'b'=>match r[7]{b'p' => match r[8]{b'd'=>s(VADDSUBPD),b's'=>s(VADDSUBPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'b'=>match r[2]{b'l'=>match r[3]{b'e'=>match r[4]{b'n'=>match r[5]{b'd'=>match r[6]{b'v'=>match r[7]{b'p' => match r[8]{b'd'=>s(VBLENDVPD),b's'=>s(VBLENDVPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c'=>match r[2]{b'v'=>match r[3]{b't' => match r[4]{b'd'=>match r[5]{b'q'=>match r[6]{b'2'=>match r[7]{b'p' => match r[8]{b'd'=>s(VCVTDQ2PD),b's'=>s(VCVTDQ2PS),_=>N}_=>N}_=>N}_=>N}b'p' => match r[5]{b'd'=>match r[6]{b'2' => match r[7]{b'd'=>match r[8]{b'q'=>s(VCVTPD2DQ),_=>N}b'p'=>match r[8]{b's'=>s(VCVTPD2PS),_=>N}_=>N}_=>N}b'h'=>match r[6]{b'2'=>match r[7]{b'p'=>match r[8]{b's'=>s(VCVTPH2PS),_=>N}_=>N}_=>N}b's'=>match r[6]{b'2' => match r[7]{b'd'=>match r[8]{b'q'=>s(VCVTPS2DQ),_=>N}b'p' => match r[8]{b'd'=>s(VCVTPS2PD),b'h'=>s(VCVTPS2PH),_=>N}_=>N}_=>N}_=>N}b's' => match r[5]{b'd'=>match r[6]{b'2'=>match r[7]{b's' => match r[8]{b'i'=>s(VCVTSD2SI),b's'=>s(VCVTSD2SS),_=>N}_=>N}_=>N}b'i'=>match r[6]{b'2'=>match r[7]{b's' => match r[8]{b'd'=>s(VCVTSI2SD),b's'=>s(VCVTSI2SS),_=>N}_=>N}_=>N}b's'=>match r[6]{b'2'=>match r[7]{b's' => match r[8]{b'd'=>s(VCVTSS2SD),b'i'=>s(VCVTSS2SI),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'i'=>match r[2]{b'n'=>match r[3]{b's'=>match r[4]{b'e'=>match r[5]{b'r'=>match r[6]{b't'=>match r[7]{b'p'=>match r[8]{b's'=>s(VINSERTPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[2]{b'o'=>match r[3]{b'v' => match r[4]{b'd'=>match r[5]{b'q' => match r[6]{b'a' => match r[7]{b'3'=>match r[8]{b'2'=>s(VMOVDQA32),_=>N}b'6'=>match r[8]{b'4'=>s(VMOVDQA64),_=>N}_=>N}b'u' => match r[7]{b'3'=>match r[8]{b'2'=>s(VMOVDQU32),_=>N}b'6'=>match r[8]{b'4'=>s(VMOVDQU64),_=>N}_=>N}_=>N}_=>N}b'm'=>match r[5]{b's'=>match r[6]{b'k'=>match r[7]{b'p' => match r[8]{b'd'=>s(VMOVMSKPD),b's'=>s(VMOVMSKPS),_=>N}_=>N}_=>N}_=>N}b'n'=>match r[5]{b't'=>match r[6]{b'd'=>match r[7]{b'q'=>match r[8]{b'a'=>s(VMOVNTDQA),_=>N}_=>N}_=>N}_=>N}b's' => match r[5]{b'h'=>match r[6]{b'd'=>match r[7]{b'u'=>match r[8]{b'p'=>s(VMOVSHDUP),_=>N}_=>N}_=>N}b'l'=>match r[6]{b'd'=>match r[7]{b'u'=>match r[8]{b'p'=>s(VMOVSLDUP),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[2]{b'n'=>match r[3]{b'p'=>match r[4]{b'c'=>match r[5]{b'k' => match r[6]{b'h'=>match r[7]{b'p'=>match r[8]{b's'=>s(VUNPCKHPS),_=>N}_=>N}b'l'=>match r[7]{b'p'=>match r[8]{b's'=>s(VUNPCKLPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[2]{b'a'=>match r[3]{b'c'=>match r[4]{b'k' => match r[5]{b's'=>match r[6]{b's' => match r[7]{b'd'=>match r[8]{b'w'=>s(VPACKSSDW),_=>N}b'w'=>match r[8]{b'b'=>s(VPACKSSWB),_=>N}_=>N}_=>N}b'u'=>match r[6]{b's' => match r[7]{b'd'=>match r[8]{b'w'=>s(VPACKUSDW),_=>N}b'w'=>match r[8]{b'b'=>s(VPACKUSWB),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'b'=>match r[3]{b'l'=>match r[4]{b'e'=>match r[5]{b'n'=>match r[6]{b'd'=>match r[7]{b'v'=>match r[8]{b'b'=>s(VPBLENDVB),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[3]{b'r'=>match r[4]{b'm' => match r[5]{b't'=>match r[6]{b'2'=>match r[7]{b'p' => match r[8]{b'd'=>s(VPERMT2PD),b's'=>s(VPERMT2PS),_=>N}_=>N}_=>N}b'i' => match r[6]{b'2'=>match r[7]{b'p' => match r[8]{b'd'=>s(VPERMI2PD),b's'=>s(VPERMI2PS),_=>N}_=>N}b'l'=>match r[7]{b'p' => match r[8]{b'd'=>s(VPERMILPD),b's'=>s(VPERMILPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[3]{b'u'=>match r[4]{b'l'=>match r[5]{b'h'=>match r[6]{b'r'=>match r[7]{b's'=>match r[8]{b'w'=>s(VPMULHRSW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'x' => match r[1]{b'r' => match r[2]{b'e'=>match r[3]{b's'=>match r[4]{b'l'=>match r[5]{b'd'=>match r[6]{b't'=>match r[7]{b'r'=>match r[8]{b'k'=>s(XRESLDTRK),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[3]{b't'=>match r[4]{b'o'=>match r[5]{b'r'=>match r[6]{b's'=>match r[7]{b'6'=>match r[8]{b'4'=>s(XRSTORS64),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[2]{b'u'=>match r[3]{b's'=>match r[4]{b'l'=>match r[5]{b'd'=>match r[6]{b't'=>match r[7]{b'r'=>match r[8]{b'k'=>s(XSUSLDTRK),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}10=>match r[0]{b'a'=>match r[1]{b'e'=>match r[2]{b's' => match r[3]{b'd'=>match r[4]{b'e'=>match r[5]{b'c'=>match r[6]{b'l'=>match r[7]{b'a'=>match r[8]{b's'=>match r[9]{b't'=>s(AESDECLAST),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[4]{b'n'=>match r[5]{b'c'=>match r[6]{b'l'=>match r[7]{b'a'=>match r[8]{b's'=>match r[9]{b't'=>s(AESENCLAST),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c'=>match r[1]{b'm'=>match r[2]{b'p'=>match r[3]{b'x'=>match r[4]{b'c'=>match r[5]{b'h'=>match r[6]{b'g'=>match r[7]{b'1'=>match r[8]{b'6'=>match r[9]{b'b'=>s(CMPXCHG16B),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[1]{b'a'=>match r[2]{b's'=>match r[3]{b'k'=>match r[4]{b'm'=>match r[5]{b'o'=>match r[6]{b'v'=>match r[7]{b'd'=>match r[8]{b'q'=>match r[9]{b'u'=>s(MASKMOVDQU),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[1]{b'h'=>match r[2]{b'a'=>match r[3]{b'2'=>match r[4]{b'5'=>match r[5]{b'6'=>match r[6]{b'm'=>match r[7]{b's'=>match r[8]{b'g' => match r[9]{b'1'=>s(SHA256MSG1),b'2'=>s(SHA256MSG2),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'x'=>match r[1]{b's'=>match r[2]{b'a'=>match r[3]{b'v'=>match r[4]{b'e'=>match r[5]{b'o'=>match r[6]{b'p'=>match r[7]{b't'=>match r[8]{b'6'=>match r[9]{b'4'=>s(XSAVEOPT64),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[1]{b'h'=>match r[2]{b'm'=>match r[3]{b'i'=>match r[4]{b'n'=>match r[5]{b'p'=>match r[6]{b'o'=>match r[7]{b's'=>match r[8]{b'u'=>match r[9]{b'w'=>s(PHMINPOSUW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[2]{b'n'=>match r[3]{b'p'=>match r[4]{b'c'=>match r[5]{b'k' => match r[6]{b'h'=>match r[7]{b'q'=>match r[8]{b'd'=>match r[9]{b'q'=>s(PUNPCKHQDQ),_=>N}_=>N}_=>N}b'l'=>match r[7]{b'q'=>match r[8]{b'd'=>match r[9]{b'q'=>s(PUNPCKLQDQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'c'=>match r[2]{b'v'=>match r[3]{b't'=>match r[4]{b't' => match r[5]{b'p' => match r[6]{b'd'=>match r[7]{b'2'=>match r[8]{b'd'=>match r[9]{b'q'=>s(VCVTTPD2DQ),_=>N}_=>N}_=>N}b's'=>match r[7]{b'2'=>match r[8]{b'd'=>match r[9]{b'q'=>s(VCVTTPS2DQ),_=>N}_=>N}_=>N}_=>N}b's' => match r[6]{b'd'=>match r[7]{b'2'=>match r[8]{b's'=>match r[9]{b'i'=>s(VCVTTSD2SI),_=>N}_=>N}_=>N}b's'=>match r[7]{b'2'=>match r[8]{b's'=>match r[9]{b'i'=>s(VCVTTSS2SI),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[2]{b'x'=>match r[3]{b't'=>match r[4]{b'r'=>match r[5]{b'a'=>match r[6]{b'c'=>match r[7]{b't'=>match r[8]{b'p'=>match r[9]{b's'=>s(VEXTRACTPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'g'=>match r[2]{b'a'=>match r[3]{b't'=>match r[4]{b'h'=>match r[5]{b'e'=>match r[6]{b'r' => match r[7]{b'd'=>match r[8]{b'p' => match r[9]{b'd'=>s(VGATHERDPD),b's'=>s(VGATHERDPS),_=>N}_=>N}b'q'=>match r[8]{b'p' => match r[9]{b'd'=>s(VGATHERQPD),b's'=>s(VGATHERQPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[2]{b'a'=>match r[3]{b's'=>match r[4]{b'k'=>match r[5]{b'm'=>match r[6]{b'o'=>match r[7]{b'v'=>match r[8]{b'p'=>match r[9]{b's'=>s(VMASKMOVPS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'z'=>match r[2]{b'e'=>match r[3]{b'r'=>match r[4]{b'o'=>match r[5]{b'u'=>match r[6]{b'p'=>match r[7]{b'p'=>match r[8]{b'e'=>match r[9]{b'r'=>s(VZEROUPPER),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p' => match r[2]{b'e'=>match r[3]{b'r'=>match r[4]{b'm'=>match r[5]{b'2' => match r[6]{b'f'=>match r[7]{b'1'=>match r[8]{b'2'=>match r[9]{b'8'=>s(VPERM2F128),_=>N}_=>N}_=>N}b'i'=>match r[7]{b'1'=>match r[8]{b'2'=>match r[9]{b'8'=>s(VPERM2I128),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'g'=>match r[3]{b'a'=>match r[4]{b't'=>match r[5]{b'h'=>match r[6]{b'e'=>match r[7]{b'r' => match r[8]{b'd' => match r[9]{b'd'=>s(VPGATHERDD),b'q'=>s(VPGATHERDQ),_=>N}b'q' => match r[9]{b'd'=>s(VPGATHERQD),b'q'=>s(VPGATHERQQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[3]{b'a'=>match r[4]{b's'=>match r[5]{b'k'=>match r[6]{b'm'=>match r[7]{b'o'=>match r[8]{b'v' => match r[9]{b'd'=>s(VPMASKMOVD),b'q'=>s(VPMASKMOVQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b't'=>match r[3]{b'e'=>match r[4]{b'r'=>match r[5]{b'n'=>match r[6]{b'l'=>match r[7]{b'o'=>match r[8]{b'g' => match r[9]{b'd'=>s(VPTERNLOGD),b'q'=>s(VPTERNLOGQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'u'=>match r[3]{b'n'=>match r[4]{b'p'=>match r[5]{b'c'=>match r[6]{b'k' => match r[7]{b'h' => match r[8]{b'b'=>match r[9]{b'w'=>s(VPUNPCKHBW),_=>N}b'd'=>match r[9]{b'q'=>s(VPUNPCKHDQ),_=>N}b'w'=>match r[9]{b'd'=>s(VPUNPCKHWD),_=>N}_=>N}b'l' => match r[8]{b'b'=>match r[9]{b'w'=>s(VPUNPCKLBW),_=>N}b'd'=>match r[9]{b'q'=>s(VPUNPCKLDQ),_=>N}b'w'=>match r[9]{b'd'=>s(VPUNPCKLWD),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'c' => match r[3]{b'l'=>match r[4]{b'm'=>match r[5]{b'u'=>match r[6]{b'l'=>match r[7]{b'q'=>match r[8]{b'd'=>match r[9]{b'q'=>s(VPCLMULQDQ),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'm'=>match r[4]{b'p' => match r[5]{b'e'=>match r[6]{b's'=>match r[7]{b't'=>match r[8]{b'r' => match r[9]{b'i'=>s(VPCMPESTRI),b'm'=>s(VPCMPESTRM),_=>N}_=>N}_=>N}_=>N}b'i'=>match r[6]{b's'=>match r[7]{b't'=>match r[8]{b'r' => match r[9]{b'i'=>s(VPCMPISTRI),b'm'=>s(VPCMPISTRM),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}11=>match r[0]{b's'=>match r[1]{b'h'=>match r[2]{b'a'=>match r[3]{b'2'=>match r[4]{b'5'=>match r[5]{b'6'=>match r[6]{b'r'=>match r[7]{b'n'=>match r[8]{b'd'=>match r[9]{b's'=>match r[10]{b'2'=>s(SHA256RNDS2),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'v' => match r[1]{b'a'=>match r[2]{b'e'=>match r[3]{b's' => match r[4]{b'd'=>match r[5]{b'e'=>match r[6]{b'c'=>match r[7]{b'l'=>match r[8]{b'a'=>match r[9]{b's'=>match r[10]{b't'=>s(VAESDECLAST),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[5]{b'n'=>match r[6]{b'c'=>match r[7]{b'l'=>match r[8]{b'a'=>match r[9]{b's'=>match r[10]{b't'=>s(VAESENCLAST),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'f'=>match r[2]{b'm' => match r[3]{b'a'=>match r[4]{b'd'=>match r[5]{b'd' => match r[6]{b'1'=>match r[7]{b'3'=>match r[8]{b'2' => match r[9]{b'p' => match r[10]{b'd'=>s(VFMADD132PD),b's'=>s(VFMADD132PS),_=>N}b's' => match r[10]{b'd'=>s(VFMADD132SD),b's'=>s(VFMADD132SS),_=>N}_=>N}_=>N}_=>N}b'2' => match r[7]{b'1'=>match r[8]{b'3' => match r[9]{b'p' => match r[10]{b'd'=>s(VFMADD213PD),b's'=>s(VFMADD213PS),_=>N}b's' => match r[10]{b'd'=>s(VFMADD213SD),b's'=>s(VFMADD213SS),_=>N}_=>N}_=>N}b'3'=>match r[8]{b'1' => match r[9]{b'p' => match r[10]{b'd'=>s(VFMADD231PD),b's'=>s(VFMADD231PS),_=>N}b's' => match r[10]{b'd'=>s(VFMADD231SD),b's'=>s(VFMADD231SS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[4]{b'u'=>match r[5]{b'b' => match r[6]{b'1'=>match r[7]{b'3'=>match r[8]{b'2' => match r[9]{b'p' => match r[10]{b'd'=>s(VFMSUB132PD),b's'=>s(VFMSUB132PS),_=>N}b's' => match r[10]{b'd'=>s(VFMSUB132SD),b's'=>s(VFMSUB132SS),_=>N}_=>N}_=>N}_=>N}b'2' => match r[7]{b'1'=>match r[8]{b'3' => match r[9]{b'p' => match r[10]{b'd'=>s(VFMSUB213PD),b's'=>s(VFMSUB213PS),_=>N}b's' => match r[10]{b'd'=>s(VFMSUB213SD),b's'=>s(VFMSUB213SS),_=>N}_=>N}_=>N}b'3'=>match r[8]{b'1' => match r[9]{b'p' => match r[10]{b'd'=>s(VFMSUB231PD),b's'=>s(VFMSUB231PS),_=>N}b's' => match r[10]{b'd'=>s(VFMSUB231SD),b's'=>s(VFMSUB231SS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'i'=>match r[2]{b'n'=>match r[3]{b's'=>match r[4]{b'e'=>match r[5]{b'r'=>match r[6]{b't' => match r[7]{b'f'=>match r[8]{b'1'=>match r[9]{b'2'=>match r[10]{b'8'=>s(VINSERTF128),_=>N}_=>N}_=>N}b'i'=>match r[8]{b'1'=>match r[9]{b'2'=>match r[10]{b'8'=>s(VINSERTI128),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p'=>match r[2]{b'h'=>match r[3]{b'm'=>match r[4]{b'i'=>match r[5]{b'n'=>match r[6]{b'p'=>match r[7]{b'o'=>match r[8]{b's'=>match r[9]{b'u'=>match r[10]{b'w'=>s(VPHMINPOSUW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}12=>match r[0]{b'v' => match r[1]{b'b'=>match r[2]{b'r'=>match r[3]{b'o'=>match r[4]{b'a'=>match r[5]{b'd'=>match r[6]{b'c'=>match r[7]{b'a'=>match r[8]{b's'=>match r[9]{b't'=>match r[10]{b's' => match r[11]{b'd'=>s(VBROADCASTSD),b's'=>s(VBROADCASTSS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'e'=>match r[2]{b'x'=>match r[3]{b't'=>match r[4]{b'r'=>match r[5]{b'a'=>match r[6]{b'c'=>match r[7]{b't' => match r[8]{b'f'=>match r[9]{b'1'=>match r[10]{b'2'=>match r[11]{b'8'=>s(VEXTRACTF128),_=>N}_=>N}_=>N}b'i'=>match r[9]{b'1'=>match r[10]{b'2'=>match r[11]{b'8'=>s(VEXTRACTI128),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'f'=>match r[2]{b'n'=>match r[3]{b'm' => match r[4]{b'a'=>match r[5]{b'd'=>match r[6]{b'd' => match r[7]{b'1'=>match r[8]{b'3'=>match r[9]{b'2' => match r[10]{b'p' => match r[11]{b'd'=>s(VFNMADD132PD),b's'=>s(VFNMADD132PS),_=>N}b's' => match r[11]{b'd'=>s(VFNMADD132SD),b's'=>s(VFNMADD132SS),_=>N}_=>N}_=>N}_=>N}b'2' => match r[8]{b'1'=>match r[9]{b'3' => match r[10]{b'p' => match r[11]{b'd'=>s(VFNMADD213PD),b's'=>s(VFNMADD213PS),_=>N}b's' => match r[11]{b'd'=>s(VFNMADD213SD),b's'=>s(VFNMADD213SS),_=>N}_=>N}_=>N}b'3'=>match r[9]{b'1' => match r[10]{b'p' => match r[11]{b'd'=>s(VFNMADD231PD),b's'=>s(VFNMADD231PS),_=>N}b's' => match r[11]{b'd'=>s(VFNMADD231SD),b's'=>s(VFNMADD231SS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[5]{b'u'=>match r[6]{b'b' => match r[7]{b'1'=>match r[8]{b'3'=>match r[9]{b'2' => match r[10]{b'p' => match r[11]{b'd'=>s(VFNMSUB132PD),b's'=>s(VFNMSUB132PS),_=>N}b's' => match r[11]{b'd'=>s(VFNMSUB132SD),b's'=>s(VFNMSUB132SS),_=>N}_=>N}_=>N}_=>N}b'2' => match r[8]{b'1'=>match r[9]{b'3' => match r[10]{b'p' => match r[11]{b'd'=>s(VFNMSUB213PD),b's'=>s(VFNMSUB213PS),_=>N}b's' => match r[11]{b'd'=>s(VFNMSUB213SD),b's'=>s(VFNMSUB213SS),_=>N}_=>N}_=>N}b'3'=>match r[9]{b'1' => match r[10]{b'p' => match r[11]{b'd'=>s(VFNMSUB231PD),b's'=>s(VFNMSUB231PS),_=>N}b's' => match r[11]{b'd'=>s(VFNMSUB231SD),b's'=>s(VFNMSUB231SS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'p'=>match r[2]{b'b'=>match r[3]{b'r'=>match r[4]{b'o'=>match r[5]{b'a'=>match r[6]{b'd'=>match r[7]{b'c'=>match r[8]{b'a'=>match r[9]{b's'=>match r[10]{b't' => match r[11]{b'b'=>s(VPBROADCASTB),b'd'=>s(VPBROADCASTD),b'q'=>s(VPBROADCASTQ),b'w'=>s(VPBROADCASTW),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}14=>match r[0]{b'v' => match r[1]{b'b'=>match r[2]{b'r'=>match r[3]{b'o'=>match r[4]{b'a'=>match r[5]{b'd'=>match r[6]{b'c'=>match r[7]{b'a'=>match r[8]{b's'=>match r[9]{b't' => match r[10]{b'f'=>match r[11]{b'1'=>match r[12]{b'2'=>match r[13]{b'8'=>s(VBROADCASTF128),_=>N}_=>N}_=>N}b'i'=>match r[11]{b'1'=>match r[12]{b'2'=>match r[13]{b'8'=>s(VBROADCASTI128),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b'f'=>match r[2]{b'm' => match r[3]{b'a'=>match r[4]{b'd'=>match r[5]{b'd'=>match r[6]{b's'=>match r[7]{b'u'=>match r[8]{b'b' => match r[9]{b'1'=>match r[10]{b'3'=>match r[11]{b'2'=>match r[12]{b'p' => match r[13]{b'd'=>s(VFMADDSUB132PD),b's'=>s(VFMADDSUB132PS),_=>N}_=>N}_=>N}_=>N}b'2' => match r[10]{b'1'=>match r[11]{b'3'=>match r[12]{b'p' => match r[13]{b'd'=>s(VFMADDSUB213PD),b's'=>s(VFMADDSUB213PS),_=>N}_=>N}_=>N}b'3'=>match r[11]{b'1'=>match r[12]{b'p' => match r[13]{b'd'=>s(VFMADDSUB231PD),b's'=>s(VFMADDSUB231PS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}b's'=>match r[4]{b'u'=>match r[5]{b'b'=>match r[6]{b'a'=>match r[7]{b'd'=>match r[8]{b'd' => match r[9]{b'1'=>match r[10]{b'3'=>match r[11]{b'2'=>match r[12]{b'p' => match r[13]{b'd'=>s(VFMSUBADD132PD),b's'=>s(VFMSUBADD132PS),_=>N}_=>N}_=>N}_=>N}b'2' => match r[10]{b'1'=>match r[11]{b'3'=>match r[12]{b'p' => match r[13]{b'd'=>s(VFMSUBADD213PD),b's'=>s(VFMSUBADD213PS),_=>N}_=>N}_=>N}b'3'=>match r[11]{b'1'=>match r[12]{b'p' => match r[13]{b'd'=>s(VFMSUBADD231PD),b's'=>s(VFMSUBADD231PS),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}15=>match r[0]{b'a'=>match r[1]{b'e'=>match r[2]{b's'=>match r[3]{b'k'=>match r[4]{b'e'=>match r[5]{b'y'=>match r[6]{b'g'=>match r[7]{b'e'=>match r[8]{b'n'=>match r[9]{b'a'=>match r[10]{b's'=>match r[11]{b's'=>match r[12]{b'i'=>match r[13]{b's'=>match r[14]{b't'=>s(AESKEYGENASSIST),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}16=>match r[0]{b'v'=>match r[1]{b'a'=>match r[2]{b'e'=>match r[3]{b's'=>match r[4]{b'k'=>match r[5]{b'e'=>match r[6]{b'y'=>match r[7]{b'g'=>match r[8]{b'e'=>match r[9]{b'n'=>match r[10]{b'a'=>match r[11]{b's'=>match r[12]{b's'=>match r[13]{b'i'=>match r[14]{b's'=>match r[15]{b't'=>s(VAESKEYGENASSIST),_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_=>N}_ =>N,
 	}
 }
@@ -0,0 +1,258 @@
+// rasmx86_64 - src/shr/isa.rs
+// ---------------------------
+// made by matissoss
+// licensed under MPL 2.0
+
+use crate::shr::error::RASMError;
+use std::str::FromStr;
+
+// ISA extension that instruction belongs to;
+// value is index of bit in Cpu's feature set
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum Extension {
+    Base, // general purpose instructions available on every x86-64 CPU
+    X87,
+    MMX,
+    SSE,
+    SSE2,
+
+    // x86-64-v2
+    SSE3,
+    SSSE3,
+    SSE4_1,
+    SSE4_2,
+    POPCNT,
+    CX16,
+
+    // x86-64-v3
+    AVX,
+    AVX2,
+    FMA,
+    F16C,
+    BMI1,
+    BMI2,
+    LZCNT,
+    MOVBE,
+    XSAVE,
+
+    // x86-64-v4
+    AVX512F,
+    AVX512VL,
+
+    // not a part of any x86-64 level
+    AES,
+    PCLMUL,
+    VAES,
+    VPCLMULQDQ,
+    SHA,
+    ADX,
+    RDRAND,
+    RDSEED,
+    FSGSBASE,
+    RDTSCP,
+    RDPID,
+    PKU,
+    SMAP,
+    CET,
+    RTM,
+    TSXLDTRK,
+    WAITPKG,
+    UINTR,
+    SERIALIZE,
+    HRESET,
+    CLDEMOTE,
+    CLWB,
+    MOVDIRI,
+    INVPCID,
+    PREFETCHW,
+    XSAVEOPT,
+    XSAVEC,
+    XSAVES,
+}
+
+impl Extension {
+    pub const ALL: [Self; 50] = [
+        Self::Base,
+        Self::X87,
+        Self::MMX,
+        Self::SSE,
+        Self::SSE2,
+        Self::SSE3,
+        Self::SSSE3,
+        Self::SSE4_1,
+        Self::SSE4_2,
+        Self::POPCNT,
+        Self::CX16,
+        Self::AVX,
+        Self::AVX2,
+        Self::FMA,
+        Self::F16C,
+        Self::BMI1,
+        Self::BMI2,
+        Self::LZCNT,
+        Self::MOVBE,
+        Self::XSAVE,
+        Self::AVX512F,
+        Self::AVX512VL,
+        Self::AES,
+        Self::PCLMUL,
+        Self::VAES,
+        Self::VPCLMULQDQ,
+        Self::SHA,
+        Self::ADX,
+        Self::RDRAND,
+        Self::RDSEED,
+        Self::FSGSBASE,
+        Self::RDTSCP,
+        Self::RDPID,
+        Self::PKU,
+        Self::SMAP,
+        Self::CET,
+        Self::RTM,
+        Self::TSXLDTRK,
+        Self::WAITPKG,
+        Self::UINTR,
+        Self::SERIALIZE,
+        Self::HRESET,
+        Self::CLDEMOTE,
+        Self::CLWB,
+        Self::MOVDIRI,
+        Self::INVPCID,
+        Self::PREFETCHW,
+        Self::XSAVEOPT,
+        Self::XSAVEC,
+        Self::XSAVES,
+    ];
+}
+
+impl FromStr for Extension {
+    type Err = ();
+    fn from_str(str: &str) -> Result<Self, <Self as FromStr>::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|e| e.to_string() == str)
+            .ok_or(())
+    }
+}
+
+#[allow(clippy::to_string_trait_impl)]
+impl ToString for Extension {
+    fn to_string(&self) -> String {
+        format!("{:?}", self).to_lowercase()
+    }
+}
+
+// x86-64 microarchitecture levels (extensions that are added on top of previous level)
+const X86_64: &[Extension] = &[
+    Extension::Base,
+    Extension::X87,
+    Extension::MMX,
+    Extension::SSE,
+    Extension::SSE2,
+];
+const X86_64_V2: &[Extension] = &[
+    Extension::SSE3,
+    Extension::SSSE3,
+    Extension::SSE4_1,
+    Extension::SSE4_2,
+    Extension::POPCNT,
+    Extension::CX16,
+];
+const X86_64_V3: &[Extension] = &[
+    Extension::AVX,
+    Extension::AVX2,
+    Extension::FMA,
+    Extension::F16C,
+    Extension::BMI1,
+    Extension::BMI2,
+    Extension::LZCNT,
+    Extension::MOVBE,
+    Extension::XSAVE,
+];
+const X86_64_V4: &[Extension] = &[Extension::AVX512F, Extension::AVX512VL];
+
+// set of extensions that target CPU supports (selected with `.cpu` or `-march=`)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cpu {
+    exts: u64,
+}
+
+impl Default for Cpu {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+impl Cpu {
+    pub const fn all() -> Self {
+        Self { exts: u64::MAX }
+    }
+    pub fn has(&self, ext: Extension) -> bool {
+        self.exts & (1 << ext as u8) != 0
+    }
+    fn add(&mut self, exts: &[Extension]) {
+        for e in exts {
+            self.exts |= 1 << *e as u8;
+        }
+    }
+}
+
+// comma separated list of x86-64 levels and extensions (like `x86-64-v2, aes`)
+impl FromStr for Cpu {
+    type Err = RASMError;
+    fn from_str(str: &str) -> Result<Self, <Self as FromStr>::Err> {
+        let mut cpu = Self { exts: 0 };
+        for s in str.split(',').map(|s| s.trim()) {
+            match s {
+                "all" => cpu = Self::all(),
+                "x86-64" => cpu.add(X86_64),
+                "x86-64-v2" => {
+                    cpu.add(X86_64);
+                    cpu.add(X86_64_V2);
+                }
+                "x86-64-v3" => {
+                    cpu.add(X86_64);
+                    cpu.add(X86_64_V2);
+                    cpu.add(X86_64_V3);
+                }
+                "x86-64-v4" => {
+                    cpu.add(X86_64);
+                    cpu.add(X86_64_V2);
+                    cpu.add(X86_64_V3);
+                    cpu.add(X86_64_V4);
+                }
+                _ => match Extension::from_str(s) {
+                    Ok(e) => cpu.add(&[e]),
+                    Err(_) => {
+                        return Err(RASMError::with_tip(
+                            None,
+                            Some(format!("Unknown CPU or extension `{s}`")),
+                            Some("Expected x86-64 level (`x86-64`, `x86-64-v2`, `x86-64-v3`, `x86-64-v4`), `all` or extension name (like `avx2`)"),
+                        ))
+                    }
+                },
+            }
+        }
+        // general purpose instructions are always available
+        cpu.add(&[Extension::Base]);
+        Ok(cpu)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn cpu_test() {
+        let v2 = Cpu::from_str("x86-64-v2").unwrap();
+        assert!(v2.has(Extension::SSE4_2) && v2.has(Extension::SSE2));
+        assert!(!v2.has(Extension::AVX));
+        let v2 = Cpu::from_str("x86-64-v2, aes").unwrap();
+        assert!(v2.has(Extension::AES) && !v2.has(Extension::AVX2));
+        assert!(Cpu::from_str("x86-64-v4").unwrap().has(Extension::AVX512F));
+        assert!(Cpu::from_str("sse4_1").unwrap().has(Extension::Base));
+        assert!(Cpu::from_str("x86-64-v5").is_err());
+        assert_eq!(Extension::from_str("sse4_2"), Ok(Extension::SSE4_2));
+    }
+}
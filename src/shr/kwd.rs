@@ -13,8 +13,13 @@ pub enum Keyword {
     Any,
     Qword,
     Dword,
+    Tword,
     Xword,
     Yword,
+    Zword,
+    Short,
+    Near,
+    Far,
     Entry,
     Global,
     Extern,
@@ -28,6 +33,7 @@ pub enum Keyword {
     Alloc,
 
     Math,
+    Cpu,
 }
 
 // keyword is equal
@@ -55,11 +61,17 @@ impl FromStr for Keyword {
         let kwd = kwd_raw;
         match kwd_raw.len() {
             // experimental
-            3 => kwd_ie(kwd, b"any", 0, 2, Keyword::Any),
+            3 => match kwd_raw[0] as char {
+                'a' => kwd_ie(kwd, b"any", 1, 2, Keyword::Any),
+                'f' => kwd_ie(kwd, b"far", 1, 2, Keyword::Far),
+                'c' => kwd_ie(kwd, b"cpu", 1, 2, Keyword::Cpu),
+                _ => Err(()),
+            },
 
             4 => match kwd_raw[0] as char {
                 'e' => kwd_ie(kwd, b"exec", 1, 3, Keyword::Exec),
                 'm' => kwd_ie(kwd, b"math", 1, 3, Keyword::Math),
+                'n' => kwd_ie(kwd, b"near", 1, 3, Keyword::Near),
                 'b' => match kwd_raw[1] as char {
                     'y' => kwd_ie(kwd, b"byte", 2, 3, Keyword::Byte),
                     'i' => kwd_ie(kwd, b"bits", 2, 3, Keyword::Bits),
@@ -80,9 +92,12 @@ impl FromStr for Keyword {
                 'w' => kwd_ie(kwd, b"write", 1, 4, Keyword::Write),
                 'x' => kwd_ie(kwd, b"xword", 1, 4, Keyword::Xword),
                 'y' => kwd_ie(kwd, b"yword", 1, 4, Keyword::Yword),
+                'z' => kwd_ie(kwd, b"zword", 1, 4, Keyword::Zword),
                 'q' => kwd_ie(kwd, b"qword", 1, 4, Keyword::Qword),
+                't' => kwd_ie(kwd, b"tword", 1, 4, Keyword::Tword),
                 'd' => kwd_ie(kwd, b"dword", 1, 4, Keyword::Dword),
                 'e' => kwd_ie(kwd, b"entry", 1, 4, Keyword::Entry),
+                's' => kwd_ie(kwd, b"short", 1, 4, Keyword::Short),
                 _ => Err(()),
             },
             6 => match kwd_raw[0] as char {
@@ -111,6 +126,7 @@ impl ToString for Keyword {
             Self::Alloc => String::from("alloc"),
             Self::Section => String::from("section"),
             Self::Math => String::from("math"),
+            Self::Cpu => String::from("cpu"),
             Self::Qword => String::from("qword"),
             Self::Any => String::from("any"),
             Self::Dword => String::from("dword"),
@@ -120,8 +136,13 @@ impl ToString for Keyword {
             Self::Global => String::from("global"),
             Self::Extern => String::from("extern"),
             Self::Bits => String::from("bits"),
+            Self::Tword => String::from("tword"),
             Self::Xword => String::from("xword"),
             Self::Yword => String::from("yword"),
+            Self::Zword => String::from("zword"),
+            Self::Short => String::from("short"),
+            Self::Near => String::from("near"),
+            Self::Far => String::from("far"),
         }
     }
 }
@@ -0,0 +1,34 @@
+// rasmx86_64 - src/shr/mask.rs
+// ----------------------------
+// made by matissoss
+// licensed under MPL 2.0
+
+use crate::shr::reg::Register;
+
+// AVX-512 write-mask decoration (like: `%zmm0:k1:z`)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mask {
+    pub mask: Register,
+    pub zeroing: bool,
+}
+
+impl Mask {
+    pub const fn new(mask: Register, zeroing: bool) -> Self {
+        Self { mask, zeroing }
+    }
+    // EVEX.aaa
+    pub fn aaa(&self) -> u8 {
+        self.mask.to_byte()
+    }
+}
+
+#[allow(clippy::to_string_trait_impl)]
+impl ToString for Mask {
+    fn to_string(&self) -> String {
+        if self.zeroing {
+            format!("{}:z", self.mask.to_string())
+        } else {
+            self.mask.to_string()
+        }
+    }
+}
@@ -16,6 +16,16 @@ use std::str::FromStr;
 
 pub const RIP_ADDRESSING: u8 = 0x0;
 pub const OBY_OFFSET: u8 = 0x1;
+pub const BROADCAST: u8 = 0x2;
+// displacement is symbol (offset is then used as addend)
+pub const SYMBOL: u8 = 0x3;
+// memory has no base (SIB.base = 101 with disp32)
+pub const INDEX_ONLY: u8 = 0x4;
+// VSIB: index is xmm/ymm register
+pub const VSIB_XMM: u8 = 0x5;
+pub const VSIB_YMM: u8 = 0x6;
+// memory has only displacement (used as RIP-relative or as moffs by movabs)
+pub const OFFSET_ONLY: u8 = 0x7;
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 #[repr(C)]
@@ -37,7 +47,7 @@ pub struct Mem {
     //      BBB - size of used registers (byte, word, dword or qword registers)
     //      C - base uses REX
     //      D - index uses REX
-    //      FFF - reserved
+    //      FFF - log2(N) of AVX-512 broadcast ({1toN}) if BROADCAST flag is set
     //  - 8th: flags
     offset: i32,
     regs: u8,
@@ -67,8 +77,17 @@ impl Mem {
         }
     }
     // type
+    // 16-bit addressing doesn't use SIB
     pub fn is_sib(&self) -> bool {
-        self.index().is_some() && self.scale().is_some() && self.base().is_some()
+        self.index().is_some()
+            && self.scale().is_some()
+            && self.base().is_some()
+            && self.addrsize() != Some(Size::Word)
+    }
+    // true if memory uses 16-bit addressing (16-bit registers or only offset in 16-bit mode)
+    pub fn is_addr16(&self, bits: u8) -> bool {
+        self.addrsize() == Some(Size::Word)
+            || (bits == 16 && self.get_flag(OFFSET_ONLY).unwrap_or(false))
     }
 
     // getters
@@ -115,19 +134,29 @@ impl Mem {
         }
         if self.regs & 0b0100_0000 == 0b0100_0000 {
             let val = self.regs & 0b0000_0111;
-            Self::compressed_reg(
-                self.addrsize().unwrap_or(Size::Unknown),
-                self.index_rex(),
-                val,
-            )
+            let size = if self.flags.get(VSIB_XMM).unwrap_or(false) {
+                Size::Xword
+            } else if self.flags.get(VSIB_YMM).unwrap_or(false) {
+                Size::Yword
+            } else {
+                self.addrsize().unwrap_or(Size::Unknown)
+            };
+            Self::compressed_reg(size, self.index_rex(), val)
         } else {
             None
         }
     }
     pub fn offset_x86(&self) -> Option<([u8; 4], usize)> {
         if let Some(off) = self.offset() {
-            let size = if self.flags.get(OBY_OFFSET).unwrap_or(false) {
+            // RIP-relative addressing, index-only memory and symbols always use disp32
+            let size = if self.flags.get(OBY_OFFSET).unwrap_or(false)
+                && !self.flags.get(RIP_ADDRESSING).unwrap_or(false)
+                && !self.flags.get(SYMBOL).unwrap_or(false)
+                && !self.flags.get(INDEX_ONLY).unwrap_or(false)
+            {
                 1
+            } else if self.addrsize() == Some(Size::Word) {
+                2
             } else {
                 4
             };
@@ -144,9 +173,37 @@ impl Mem {
             None
         }
     }
+    // returns MODRM.rm of 16-bit addressing or None if base and index can't be paired
+    pub fn rm16(&self) -> Option<u8> {
+        use Register::*;
+        if self.get_flag(INDEX_ONLY).unwrap_or(false)
+            || (self.index().is_some() && self.scale() != Some(Size::Byte))
+        {
+            return None;
+        }
+        match (self.base(), self.index()) {
+            (Some(BX), Some(SI)) | (Some(SI), Some(BX)) => Some(0b000),
+            (Some(BX), Some(DI)) | (Some(DI), Some(BX)) => Some(0b001),
+            (Some(BP), Some(SI)) | (Some(SI), Some(BP)) => Some(0b010),
+            (Some(BP), Some(DI)) | (Some(DI), Some(BP)) => Some(0b011),
+            (Some(SI), None) => Some(0b100),
+            (Some(DI), None) => Some(0b101),
+            (Some(BP), None) => Some(0b110),
+            (Some(BX), None) => Some(0b111),
+            _ => None,
+        }
+    }
     pub fn scale(&self) -> Option<Size> {
         Self::compressed_size((self.metadata_1 & 0b0000_1110) >> 1)
     }
+    // returns N of broadcast ({1toN})
+    pub fn bcst(&self) -> Option<u8> {
+        if self.flags.get(BROADCAST).unwrap_or(false) {
+            Some(1 << ((self.metadata_2 & 0b0001_1100) >> 2))
+        } else {
+            None
+        }
+    }
     pub fn get_flag(&self, idx: u8) -> Option<bool> {
         self.flags.get(idx)
     }
@@ -181,14 +238,20 @@ impl Mem {
         true
     }
     // false if it fails
-    // fails, if addrsize != base.size()
+    // fails, if addrsize != index.size() (and index isn't xmm/ymm register)
     pub fn set_index(&mut self, index: Register) -> bool {
         let bb = index.to_byte();
         let rx = index.needs_rex() as u8;
 
         // check
-        if self.addrsize().unwrap_or(Size::Unknown) != index.size() {
-            return false;
+        match index.purpose() {
+            RPurpose::F128 => self.flags.set(VSIB_XMM, true),
+            RPurpose::F256 => self.flags.set(VSIB_YMM, true),
+            _ => {
+                if self.addrsize().unwrap_or(Size::Unknown) != index.size() {
+                    return false;
+                }
+            }
         }
         // set guardian
         self.regs = (self.regs & !0b0100_0000) | 0b0100_0000;
@@ -221,6 +284,15 @@ impl Mem {
         let mask = !0b0000_1110;
         self.metadata_1 = (self.metadata_1 & mask) | (sz << 1);
     }
+    // sets broadcast ({1toN}); returns false if N is invalid
+    pub fn set_bcst(&mut self, n: u8) -> bool {
+        if !matches!(n, 2 | 4 | 8 | 16) {
+            return false;
+        }
+        self.flags.set(BROADCAST, true);
+        self.metadata_2 = (self.metadata_2 & !0b0001_1100) | (n.trailing_zeros() as u8) << 2;
+        true
+    }
     pub fn set_flag(&mut self, idx: u8) {
         self.flags.set(idx, true)
     }
@@ -340,6 +412,44 @@ impl Mem {
                     }
                 }
             }
+            Size::Xword => match (rex, reg) {
+                (false, 0b000) => Some(XMM0),
+                (false, 0b001) => Some(XMM1),
+                (false, 0b010) => Some(XMM2),
+                (false, 0b011) => Some(XMM3),
+                (false, 0b100) => Some(XMM4),
+                (false, 0b101) => Some(XMM5),
+                (false, 0b110) => Some(XMM6),
+                (false, 0b111) => Some(XMM7),
+                (true, 0b000) => Some(XMM8),
+                (true, 0b001) => Some(XMM9),
+                (true, 0b010) => Some(XMM10),
+                (true, 0b011) => Some(XMM11),
+                (true, 0b100) => Some(XMM12),
+                (true, 0b101) => Some(XMM13),
+                (true, 0b110) => Some(XMM14),
+                (true, 0b111) => Some(XMM15),
+                _ => None,
+            },
+            Size::Yword => match (rex, reg) {
+                (false, 0b000) => Some(YMM0),
+                (false, 0b001) => Some(YMM1),
+                (false, 0b010) => Some(YMM2),
+                (false, 0b011) => Some(YMM3),
+                (false, 0b100) => Some(YMM4),
+                (false, 0b101) => Some(YMM5),
+                (false, 0b110) => Some(YMM6),
+                (false, 0b111) => Some(YMM7),
+                (true, 0b000) => Some(YMM8),
+                (true, 0b001) => Some(YMM9),
+                (true, 0b010) => Some(YMM10),
+                (true, 0b011) => Some(YMM11),
+                (true, 0b100) => Some(YMM12),
+                (true, 0b101) => Some(YMM13),
+                (true, 0b110) => Some(YMM14),
+                (true, 0b111) => Some(YMM15),
+                _ => None,
+            },
             _ => None,
         }
     }
@@ -351,6 +461,8 @@ impl Mem {
             Size::Qword => Some(0b0011),
             Size::Xword => Some(0b0100),
             Size::Yword => Some(0b0101),
+            Size::Zword => Some(0b0110),
+            Size::Tword => Some(0b0111),
             Size::Any => Some(0b1111),
             _ => None,
         }
@@ -363,6 +475,8 @@ impl Mem {
             0b0011 => Some(Size::Qword),
             0b0100 => Some(Size::Xword),
             0b0101 => Some(Size::Yword),
+            0b0110 => Some(Size::Zword),
+            0b0111 => Some(Size::Tword),
             0b1111 => Some(Size::Any),
             _ => None,
         }
@@ -404,6 +518,7 @@ fn mem_chk(mem: &mut Mem) {
     }
     if let (None, None, Some(_)) = (base, index, offset) {
         mem.set_flag(RIP_ADDRESSING);
+        mem.set_flag(OFFSET_ONLY);
     }
 }
 
@@ -419,9 +534,11 @@ fn mem_par_new(toks: Vec<Token>) -> Result<Mem, Error> {
     // if number was prefixed with *
     let mut mul_modf = false;
     let mut num_ismin = false;
+    let mut rip = false;
     for tok in toks {
         match tok {
             Token::Error(e) => return Err(e),
+            Token::Register(Register::RIP) => rip = true,
             Token::Register(r) => {
                 if unspec_reg.is_none() {
                     unspec_reg = Some(r);
@@ -439,7 +556,11 @@ fn mem_par_new(toks: Vec<Token>) -> Result<Mem, Error> {
             Token::Mul => mul_modf = true,
             Token::Add | Token::Sub => {
                 if unspec_reg.is_some() {
-                    base = unspec_reg;
+                    if base.is_none() {
+                        base = unspec_reg;
+                    } else {
+                        index = unspec_reg;
+                    }
                     unspec_reg = None;
                 }
                 if tok == Token::Sub {
@@ -477,6 +598,56 @@ fn mem_par_new(toks: Vec<Token>) -> Result<Mem, Error> {
             }
         }
     }
+    if rip {
+        if base.is_some() || index.is_some() || unspec_reg.is_some() {
+            return Err(Error::no_tip(
+                None,
+                Some("RIP-relative memory declaration cannot use other registers!"),
+            ));
+        }
+        mem.set_flag(RIP_ADDRESSING);
+        mem.set_offset(offset.unwrap_or(0));
+        return Ok(mem);
+    }
+    let vsib = [base, index, unspec_reg]
+        .into_iter()
+        .flatten()
+        .any(|r| r.purpose() != RPurpose::General);
+    if vsib {
+        if let Some(r) = base {
+            if r.purpose() != RPurpose::General {
+                return Err(Error::no_tip(
+                    None,
+                    Some("xmm/ymm register can only be used as index of memory declaration (VSIB)"),
+                ));
+            }
+        }
+        let index =
+            match (index, unspec_reg) {
+                (Some(r), _) | (None, Some(r)) if r.purpose() != RPurpose::General => r,
+                _ => return Err(Error::no_tip(
+                    None,
+                    Some("xmm/ymm register can only be used as index of memory declaration (VSIB)"),
+                )),
+            };
+        match base {
+            Some(base) => {
+                mem.set_addrsize(base.size());
+                let _ = mem.set_base(base);
+            }
+            None => {
+                mem.set_addrsize(Size::Qword);
+                let _ = mem.set_base(Register::RBP);
+                mem.set_flag(INDEX_ONLY);
+            }
+        }
+        let _ = mem.set_index(index);
+        mem.set_scale(scale.unwrap_or(Size::Byte));
+        if let Some(offset) = offset {
+            mem.set_offset(offset);
+        }
+        return Ok(mem);
+    }
     if let (Some(base), Some(index)) = (base, index) {
         if base.size() != index.size() {
             return Err(Error::no_tip(
@@ -503,6 +674,7 @@ fn mem_par_new(toks: Vec<Token>) -> Result<Mem, Error> {
         };
         let _ = mem.set_base(base.unwrap());
         let _ = mem.set_index(index);
+        mem.set_flag(INDEX_ONLY);
     } else if let Some(base) = base {
         mem.set_addrsize(base.size());
         let _ = mem.set_base(base);
@@ -576,10 +748,12 @@ fn mem_tok_from_buf(buf: &[u8]) -> Option<Token> {
         let utf8_buf = String::from_utf8_lossy(buf);
         if prefix == &b'%' {
             if let Ok(reg) = Register::from_str(&utf8_buf[1..]) {
-                if reg.purpose() != RPurpose::General {
+                let vsib =
+                    matches!(reg.purpose(), RPurpose::F128 | RPurpose::F256) && !reg.needs_evex();
+                if reg.purpose() != RPurpose::General && reg != Register::RIP && !vsib {
                     Some(Token::Error(Error::no_tip(
                         None,
-                        Some("Tried to use register which purpose isn't general (like *ax, *bx, etc.)")
+                        Some("Tried to use register which purpose isn't general (like *ax, *bx, etc.) or xmm0-15/ymm0-15 (as VSIB index)")
                     )))
                 } else {
                     Some(Token::Register(reg))
@@ -666,6 +840,13 @@ mod new_test {
         mem.set_addrsize(Size::Qword);
         mem.set_index(Register::RCX);
         assert_eq!(mem.index(), Some(Register::RCX));
+        assert_eq!(mem.bcst(), None);
+        assert!(!mem.set_bcst(3));
+        assert!(mem.set_bcst(16));
+        assert_eq!(mem.bcst(), Some(16));
+        assert!(mem.set_bcst(2));
+        assert_eq!(mem.bcst(), Some(2));
+        assert_eq!(mem.index(), Some(Register::RCX));
     }
     #[test]
     fn mem_par_check() {
@@ -712,6 +893,8 @@ mod new_test {
         assert_eq!(mem.index(), Some(Register::RCX));
         assert_eq!(mem.scale(), Some(Size::Dword));
         assert_eq!(mem.offset(), Some(20));
+        assert_eq!(mem.get_flag(INDEX_ONLY), Some(true));
+        assert_eq!(mem.offset_x86(), Some(([20, 0, 0, 0], 4)));
         let str = "-0xFF";
         let mem = Mem::new(str, Size::Qword);
         assert!(mem.is_ok());
@@ -722,5 +905,38 @@ mod new_test {
         assert_eq!(mem.base(), Some(Register::EAX));
         assert_eq!(mem.index(), Some(Register::EBX));
         assert_eq!(mem.scale(), Some(Size::Byte));
+        let mem = Mem::new("%rax", Size::Tword).unwrap();
+        assert_eq!(mem.size(), Some(Size::Tword));
+        let mem = Mem::new("%rip + $8", Size::Qword).unwrap();
+        assert_eq!(mem.base(), Some(Register::RIP));
+        assert_eq!(mem.index(), None);
+        assert_eq!(mem.offset_x86(), Some(([8, 0, 0, 0], 4)));
+        let mem = Mem::new("%rip", Size::Qword).unwrap();
+        assert_eq!(mem.offset(), Some(0));
+        assert!(Mem::new("%rip + %rax", Size::Qword).is_err());
+        let mem = Mem::new("%rax + %ymm9 * $4 + $8", Size::Any).unwrap();
+        assert_eq!(mem.base(), Some(Register::RAX));
+        assert_eq!(mem.index(), Some(Register::YMM9));
+        assert_eq!(mem.scale(), Some(Size::Dword));
+        assert_eq!(mem.needs_rex(), (false, true));
+        let mem = Mem::new("%xmm3 * $8", Size::Any).unwrap();
+        assert_eq!(mem.index(), Some(Register::XMM3));
+        assert_eq!(mem.get_flag(INDEX_ONLY), Some(true));
+        assert!(Mem::new("%xmm3 + %rax", Size::Any).is_err());
+        let mem = Mem::new("%rbx + %rsi + $4", Size::Qword).unwrap();
+        assert_eq!(mem.base(), Some(Register::RBX));
+        assert_eq!(mem.index(), Some(Register::RSI));
+        assert_eq!(mem.offset(), Some(4));
+        let mem = Mem::new("%bx + %si + $4", Size::Word).unwrap();
+        assert!(!mem.is_sib());
+        assert_eq!(mem.rm16(), Some(0b000));
+        assert_eq!(mem.offset_x86(), Some(([4, 0, 0, 0], 1)));
+        let mem = Mem::new("%di + %bp + $0x1234", Size::Word).unwrap();
+        assert_eq!(mem.rm16(), Some(0b011));
+        assert_eq!(mem.offset_x86(), Some(([0x34, 0x12, 0, 0], 2)));
+        assert_eq!(Mem::new("%bp", Size::Word).unwrap().rm16(), Some(0b110));
+        assert_eq!(Mem::new("%bx + %bp", Size::Word).unwrap().rm16(), None);
+        assert_eq!(Mem::new("%ax", Size::Word).unwrap().rm16(), None);
+        assert_eq!(Mem::new("%bx + %si * $2", Size::Word).unwrap().rm16(), None);
     }
 }
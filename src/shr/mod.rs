@@ -4,12 +4,16 @@ pub mod booltable;
 pub mod error;
 pub mod ins;
 pub mod ins_switch;
+pub mod isa;
 pub mod kwd;
+pub mod mask;
 pub mod math;
 pub mod mem;
 pub mod num;
+pub mod pseudo;
 pub mod reg;
 pub mod reloc;
+pub mod rounding;
 pub mod rpanic;
 pub mod section;
 pub mod segment;
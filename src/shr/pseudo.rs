@@ -0,0 +1,52 @@
+// rasmx86_64 - src/shr/pseudo.rs
+// ------------------------------
+// made by matissoss
+// licensed under MPL 2.0
+
+use std::str::FromStr;
+
+// encoding-override pseudo prefix (like: `{vex}` or `{disp32}`);
+// value is index in Instruction's pseudo prefix table
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+pub enum Pseudo {
+    Vex,    // VEX form of legacy SSE instruction
+    Vex3,   // 3-byte VEX even if 2-byte one would do
+    Rex,    // REX prefix even if it is not needed
+    Disp8,  // memory with 8-bit displacement
+    Disp32, // memory with 32-bit displacement
+    Load,   // reg-reg move uses load opcode (ModRM.reg = destination)
+    Store,  // reg-reg move uses store opcode (ModRM.rm = destination)
+}
+
+impl FromStr for Pseudo {
+    type Err = ();
+    fn from_str(str: &str) -> Result<Self, <Self as FromStr>::Err> {
+        match str {
+            "vex" => Ok(Self::Vex),
+            "vex3" => Ok(Self::Vex3),
+            "rex" => Ok(Self::Rex),
+            "disp8" => Ok(Self::Disp8),
+            "disp32" => Ok(Self::Disp32),
+            "load" => Ok(Self::Load),
+            "store" => Ok(Self::Store),
+            _ => Err(()),
+        }
+    }
+}
+
+#[allow(clippy::to_string_trait_impl)]
+impl ToString for Pseudo {
+    fn to_string(&self) -> String {
+        match self {
+            Self::Vex => "{vex}",
+            Self::Vex3 => "{vex3}",
+            Self::Rex => "{rex}",
+            Self::Disp8 => "{disp8}",
+            Self::Disp32 => "{disp32}",
+            Self::Load => "{load}",
+            Self::Store => "{store}",
+        }
+        .to_string()
+    }
+}
@@ -18,7 +18,10 @@ pub enum Purpose {
     Mmx,   // mmX
     F128,  // xmmX
     F256,  // ymmX
+    F512,  // zmmX
     Sgmnt, // segment registers (cs, ss, ds, es, ...)
+    Mask,  // kX (AVX-512 opmask)
+    Fpu,   // stX (x87 register stack)
 }
 
 #[rustfmt::skip]
@@ -87,13 +90,41 @@ pub enum Register {
     XMM8 , XMM9 , XMM10, XMM11,
     XMM12, XMM13, XMM14, XMM15,
 
-    // CURRENTLY UNSUPPORTED:
+    // AVX-512
+    XMM16, XMM17, XMM18, XMM19,
+    XMM20, XMM21, XMM22, XMM23,
+    XMM24, XMM25, XMM26, XMM27,
+    XMM28, XMM29, XMM30, XMM31,
+
     //  AVX
     YMM0, YMM1, YMM2, YMM3,
     YMM4, YMM5, YMM6, YMM7,
     
     YMM8 , YMM9 , YMM10, YMM11,
     YMM12, YMM13, YMM14, YMM15,
+
+    // AVX-512
+    YMM16, YMM17, YMM18, YMM19,
+    YMM20, YMM21, YMM22, YMM23,
+    YMM24, YMM25, YMM26, YMM27,
+    YMM28, YMM29, YMM30, YMM31,
+
+    ZMM0 , ZMM1 , ZMM2 , ZMM3 ,
+    ZMM4 , ZMM5 , ZMM6 , ZMM7 ,
+    ZMM8 , ZMM9 , ZMM10, ZMM11,
+    ZMM12, ZMM13, ZMM14, ZMM15,
+    ZMM16, ZMM17, ZMM18, ZMM19,
+    ZMM20, ZMM21, ZMM22, ZMM23,
+    ZMM24, ZMM25, ZMM26, ZMM27,
+    ZMM28, ZMM29, ZMM30, ZMM31,
+
+    // AVX-512 opmask
+    K0, K1, K2, K3,
+    K4, K5, K6, K7,
+
+    // x87 FPU register stack
+    ST0, ST1, ST2, ST3,
+    ST4, ST5, ST6, ST7,
 }
 
 #[inline(always)]
@@ -162,8 +193,16 @@ impl FromStr for Register {
                     'g' => Ok(Register::GS),
                     _ => Err(()),
                 },
-                '8' => Ok(Register::R8),
-                '9' => Ok(Register::R9),
+                '0' => reg_ie(byte_str, b"k0", 0, 1, Register::K0),
+                '1' => reg_ie(byte_str, b"k1", 0, 1, Register::K1),
+                '2' => reg_ie(byte_str, b"k2", 0, 1, Register::K2),
+                '3' => reg_ie(byte_str, b"k3", 0, 1, Register::K3),
+                '4' => reg_ie(byte_str, b"k4", 0, 1, Register::K4),
+                '5' => reg_ie(byte_str, b"k5", 0, 1, Register::K5),
+                '6' => reg_ie(byte_str, b"k6", 0, 1, Register::K6),
+                '7' => reg_ie(byte_str, b"k7", 0, 1, Register::K7),
+                '8' => reg_ie(byte_str, b"r8", 0, 1, Register::R8),
+                '9' => reg_ie(byte_str, b"r9", 0, 1, Register::R9),
                 _ => Err(()),
             },
             // prev = 2; byte_str.len()
@@ -248,6 +287,17 @@ impl FromStr for Register {
                     's' => match byte_str[1] as char {
                         'p' => reg_ie(str, b"spl", 2, 2, Register::SPL),
                         'i' => reg_ie(str, b"sil", 2, 2, Register::SIL),
+                        't' => match byte_str[2] as char {
+                            '0' => Ok(Register::ST0),
+                            '1' => Ok(Register::ST1),
+                            '2' => Ok(Register::ST2),
+                            '3' => Ok(Register::ST3),
+                            '4' => Ok(Register::ST4),
+                            '5' => Ok(Register::ST5),
+                            '6' => Ok(Register::ST6),
+                            '7' => Ok(Register::ST7),
+                            _ => Err(()),
+                        },
                         _ => Err(()),
                     },
                     'b' => reg_ie(str, b"bpl", 1, 2, Register::BPL),
@@ -366,25 +416,122 @@ impl FromStr for Register {
                     '9' => reg_ie(str, b"ymm9", 1, 2, Register::YMM9),
                     _ => Err(()),
                 },
+                'z' => match byte_str[3] as char {
+                    '0' => reg_ie(str, b"zmm0", 1, 2, Register::ZMM0),
+                    '1' => reg_ie(str, b"zmm1", 1, 2, Register::ZMM1),
+                    '2' => reg_ie(str, b"zmm2", 1, 2, Register::ZMM2),
+                    '3' => reg_ie(str, b"zmm3", 1, 2, Register::ZMM3),
+                    '4' => reg_ie(str, b"zmm4", 1, 2, Register::ZMM4),
+                    '5' => reg_ie(str, b"zmm5", 1, 2, Register::ZMM5),
+                    '6' => reg_ie(str, b"zmm6", 1, 2, Register::ZMM6),
+                    '7' => reg_ie(str, b"zmm7", 1, 2, Register::ZMM7),
+                    '8' => reg_ie(str, b"zmm8", 1, 2, Register::ZMM8),
+                    '9' => reg_ie(str, b"zmm9", 1, 2, Register::ZMM9),
+                    _ => Err(()),
+                },
                 _ => Err(()),
             },
             5 => match byte_str[0] as char {
-                'x' => match byte_str[4] as char {
-                    '0' => reg_ie(str, b"xmm10", 1, 3, Register::XMM10),
-                    '1' => reg_ie(str, b"xmm11", 1, 3, Register::XMM11),
-                    '2' => reg_ie(str, b"xmm12", 1, 3, Register::XMM12),
-                    '3' => reg_ie(str, b"xmm13", 1, 3, Register::XMM13),
-                    '4' => reg_ie(str, b"xmm14", 1, 3, Register::XMM14),
-                    '5' => reg_ie(str, b"xmm15", 1, 3, Register::XMM15),
+                'x' => match byte_str[3] as char {
+                    '1' => match byte_str[4] as char {
+                        '0' => reg_ie(str, b"xmm10", 1, 3, Register::XMM10),
+                        '1' => reg_ie(str, b"xmm11", 1, 3, Register::XMM11),
+                        '2' => reg_ie(str, b"xmm12", 1, 3, Register::XMM12),
+                        '3' => reg_ie(str, b"xmm13", 1, 3, Register::XMM13),
+                        '4' => reg_ie(str, b"xmm14", 1, 3, Register::XMM14),
+                        '5' => reg_ie(str, b"xmm15", 1, 3, Register::XMM15),
+                        '6' => reg_ie(str, b"xmm16", 1, 3, Register::XMM16),
+                        '7' => reg_ie(str, b"xmm17", 1, 3, Register::XMM17),
+                        '8' => reg_ie(str, b"xmm18", 1, 3, Register::XMM18),
+                        '9' => reg_ie(str, b"xmm19", 1, 3, Register::XMM19),
+                        _ => Err(()),
+                    },
+                    '2' => match byte_str[4] as char {
+                        '0' => reg_ie(str, b"xmm20", 1, 3, Register::XMM20),
+                        '1' => reg_ie(str, b"xmm21", 1, 3, Register::XMM21),
+                        '2' => reg_ie(str, b"xmm22", 1, 3, Register::XMM22),
+                        '3' => reg_ie(str, b"xmm23", 1, 3, Register::XMM23),
+                        '4' => reg_ie(str, b"xmm24", 1, 3, Register::XMM24),
+                        '5' => reg_ie(str, b"xmm25", 1, 3, Register::XMM25),
+                        '6' => reg_ie(str, b"xmm26", 1, 3, Register::XMM26),
+                        '7' => reg_ie(str, b"xmm27", 1, 3, Register::XMM27),
+                        '8' => reg_ie(str, b"xmm28", 1, 3, Register::XMM28),
+                        '9' => reg_ie(str, b"xmm29", 1, 3, Register::XMM29),
+                        _ => Err(()),
+                    },
+                    '3' => match byte_str[4] as char {
+                        '0' => reg_ie(str, b"xmm30", 1, 3, Register::XMM30),
+                        '1' => reg_ie(str, b"xmm31", 1, 3, Register::XMM31),
+                        _ => Err(()),
+                    },
+                    _ => Err(()),
+                },
+                'y' => match byte_str[3] as char {
+                    '1' => match byte_str[4] as char {
+                        '0' => reg_ie(str, b"ymm10", 1, 3, Register::YMM10),
+                        '1' => reg_ie(str, b"ymm11", 1, 3, Register::YMM11),
+                        '2' => reg_ie(str, b"ymm12", 1, 3, Register::YMM12),
+                        '3' => reg_ie(str, b"ymm13", 1, 3, Register::YMM13),
+                        '4' => reg_ie(str, b"ymm14", 1, 3, Register::YMM14),
+                        '5' => reg_ie(str, b"ymm15", 1, 3, Register::YMM15),
+                        '6' => reg_ie(str, b"ymm16", 1, 3, Register::YMM16),
+                        '7' => reg_ie(str, b"ymm17", 1, 3, Register::YMM17),
+                        '8' => reg_ie(str, b"ymm18", 1, 3, Register::YMM18),
+                        '9' => reg_ie(str, b"ymm19", 1, 3, Register::YMM19),
+                        _ => Err(()),
+                    },
+                    '2' => match byte_str[4] as char {
+                        '0' => reg_ie(str, b"ymm20", 1, 3, Register::YMM20),
+                        '1' => reg_ie(str, b"ymm21", 1, 3, Register::YMM21),
+                        '2' => reg_ie(str, b"ymm22", 1, 3, Register::YMM22),
+                        '3' => reg_ie(str, b"ymm23", 1, 3, Register::YMM23),
+                        '4' => reg_ie(str, b"ymm24", 1, 3, Register::YMM24),
+                        '5' => reg_ie(str, b"ymm25", 1, 3, Register::YMM25),
+                        '6' => reg_ie(str, b"ymm26", 1, 3, Register::YMM26),
+                        '7' => reg_ie(str, b"ymm27", 1, 3, Register::YMM27),
+                        '8' => reg_ie(str, b"ymm28", 1, 3, Register::YMM28),
+                        '9' => reg_ie(str, b"ymm29", 1, 3, Register::YMM29),
+                        _ => Err(()),
+                    },
+                    '3' => match byte_str[4] as char {
+                        '0' => reg_ie(str, b"ymm30", 1, 3, Register::YMM30),
+                        '1' => reg_ie(str, b"ymm31", 1, 3, Register::YMM31),
+                        _ => Err(()),
+                    },
                     _ => Err(()),
                 },
-                'y' => match byte_str[4] as char {
-                    '0' => reg_ie(str, b"ymm10", 1, 3, Register::YMM10),
-                    '1' => reg_ie(str, b"ymm11", 1, 3, Register::YMM11),
-                    '2' => reg_ie(str, b"ymm12", 1, 3, Register::YMM12),
-                    '3' => reg_ie(str, b"ymm13", 1, 3, Register::YMM13),
-                    '4' => reg_ie(str, b"ymm14", 1, 3, Register::YMM14),
-                    '5' => reg_ie(str, b"ymm15", 1, 3, Register::YMM15),
+                'z' => match byte_str[3] as char {
+                    '1' => match byte_str[4] as char {
+                        '0' => reg_ie(str, b"zmm10", 1, 3, Register::ZMM10),
+                        '1' => reg_ie(str, b"zmm11", 1, 3, Register::ZMM11),
+                        '2' => reg_ie(str, b"zmm12", 1, 3, Register::ZMM12),
+                        '3' => reg_ie(str, b"zmm13", 1, 3, Register::ZMM13),
+                        '4' => reg_ie(str, b"zmm14", 1, 3, Register::ZMM14),
+                        '5' => reg_ie(str, b"zmm15", 1, 3, Register::ZMM15),
+                        '6' => reg_ie(str, b"zmm16", 1, 3, Register::ZMM16),
+                        '7' => reg_ie(str, b"zmm17", 1, 3, Register::ZMM17),
+                        '8' => reg_ie(str, b"zmm18", 1, 3, Register::ZMM18),
+                        '9' => reg_ie(str, b"zmm19", 1, 3, Register::ZMM19),
+                        _ => Err(()),
+                    },
+                    '2' => match byte_str[4] as char {
+                        '0' => reg_ie(str, b"zmm20", 1, 3, Register::ZMM20),
+                        '1' => reg_ie(str, b"zmm21", 1, 3, Register::ZMM21),
+                        '2' => reg_ie(str, b"zmm22", 1, 3, Register::ZMM22),
+                        '3' => reg_ie(str, b"zmm23", 1, 3, Register::ZMM23),
+                        '4' => reg_ie(str, b"zmm24", 1, 3, Register::ZMM24),
+                        '5' => reg_ie(str, b"zmm25", 1, 3, Register::ZMM25),
+                        '6' => reg_ie(str, b"zmm26", 1, 3, Register::ZMM26),
+                        '7' => reg_ie(str, b"zmm27", 1, 3, Register::ZMM27),
+                        '8' => reg_ie(str, b"zmm28", 1, 3, Register::ZMM28),
+                        '9' => reg_ie(str, b"zmm29", 1, 3, Register::ZMM29),
+                        _ => Err(()),
+                    },
+                    '3' => match byte_str[4] as char {
+                        '0' => reg_ie(str, b"zmm30", 1, 3, Register::ZMM30),
+                        '1' => reg_ie(str, b"zmm31", 1, 3, Register::ZMM31),
+                        _ => Err(()),
+                    },
                     _ => Err(()),
                 },
                 _ => Err(()),
@@ -442,17 +589,39 @@ impl Register {
             Self::RIP | Self::R12 | Self::R13 | Self::R14 |
             Self::MM0 | Self::MM1 | Self::MM2 | Self::MM3 |
             Self::MM4 | Self::MM5 | Self::MM6 | Self::MM7 |
+            Self::K0  | Self::K1  | Self::K2  | Self::K3  |
+            Self::K4  | Self::K5  | Self::K6  | Self::K7  |
             Self::R15 => Size::Qword,
 
             Self::XMM0 | Self::XMM1 | Self::XMM2 | Self::XMM3 |
             Self::XMM4 | Self::XMM5 | Self::XMM6 | Self::XMM7 |
             Self::XMM8 | Self::XMM9 | Self::XMM10| Self::XMM11|
-            Self::XMM12| Self::XMM13| Self::XMM14| Self::XMM15 => Size::Xword,
+            Self::XMM12| Self::XMM13| Self::XMM14| Self::XMM15|
+            Self::XMM16| Self::XMM17| Self::XMM18| Self::XMM19|
+            Self::XMM20| Self::XMM21| Self::XMM22| Self::XMM23|
+            Self::XMM24| Self::XMM25| Self::XMM26| Self::XMM27|
+            Self::XMM28| Self::XMM29| Self::XMM30| Self::XMM31 => Size::Xword,
 
             Self::YMM0 | Self::YMM1 | Self::YMM2 | Self::YMM3 |
             Self::YMM4 | Self::YMM5 | Self::YMM6 | Self::YMM7 |
             Self::YMM8 | Self::YMM9 | Self::YMM10| Self::YMM11|
-            Self::YMM12| Self::YMM13| Self::YMM14| Self::YMM15 => Size::Yword,
+            Self::YMM12| Self::YMM13| Self::YMM14| Self::YMM15|
+            Self::YMM16| Self::YMM17| Self::YMM18| Self::YMM19|
+            Self::YMM20| Self::YMM21| Self::YMM22| Self::YMM23|
+            Self::YMM24| Self::YMM25| Self::YMM26| Self::YMM27|
+            Self::YMM28| Self::YMM29| Self::YMM30| Self::YMM31 => Size::Yword,
+
+            Self::ZMM0 | Self::ZMM1 | Self::ZMM2 | Self::ZMM3 |
+            Self::ZMM4 | Self::ZMM5 | Self::ZMM6 | Self::ZMM7 |
+            Self::ZMM8 | Self::ZMM9 | Self::ZMM10| Self::ZMM11|
+            Self::ZMM12| Self::ZMM13| Self::ZMM14| Self::ZMM15|
+            Self::ZMM16| Self::ZMM17| Self::ZMM18| Self::ZMM19|
+            Self::ZMM20| Self::ZMM21| Self::ZMM22| Self::ZMM23|
+            Self::ZMM24| Self::ZMM25| Self::ZMM26| Self::ZMM27|
+            Self::ZMM28| Self::ZMM29| Self::ZMM30| Self::ZMM31 => Size::Zword,
+
+            Self::ST0 | Self::ST1 | Self::ST2 | Self::ST3 |
+            Self::ST4 | Self::ST5 | Self::ST6 | Self::ST7 => Size::Tword,
         }
     }
     #[rustfmt::skip]
@@ -475,63 +644,107 @@ impl Register {
             Self::CR12 | Self::CR13 | Self::CR14 | Self::CR15 |
             Self::DR8  | Self::DR9  | Self::DR10 | Self::DR11 |
             Self::DR12 | Self::DR13 | Self::DR14 | Self::DR15 |
-            Self::YMM12| Self::YMM13| Self::YMM14| Self::YMM15
+            Self::YMM12| Self::YMM13| Self::YMM14| Self::YMM15|
+            Self::XMM24| Self::XMM25| Self::XMM26| Self::XMM27|
+            Self::XMM28| Self::XMM29| Self::XMM30| Self::XMM31|
+            Self::YMM24| Self::YMM25| Self::YMM26| Self::YMM27|
+            Self::YMM28| Self::YMM29| Self::YMM30| Self::YMM31|
+            Self::ZMM8 | Self::ZMM9 | Self::ZMM10| Self::ZMM11|
+            Self::ZMM12| Self::ZMM13| Self::ZMM14| Self::ZMM15|
+            Self::ZMM24| Self::ZMM25| Self::ZMM26| Self::ZMM27|
+            Self::ZMM28| Self::ZMM29| Self::ZMM30| Self::ZMM31
+        )
+    }
+    // registers 16-31 (need EVEX.R'/EVEX.V'/EVEX.X)
+    #[rustfmt::skip]
+    pub fn needs_evex(&self) -> bool {
+        matches!(
+            self,
+            Self::XMM16| Self::XMM17| Self::XMM18| Self::XMM19|
+            Self::XMM20| Self::XMM21| Self::XMM22| Self::XMM23|
+            Self::XMM24| Self::XMM25| Self::XMM26| Self::XMM27|
+            Self::XMM28| Self::XMM29| Self::XMM30| Self::XMM31|
+            Self::YMM16| Self::YMM17| Self::YMM18| Self::YMM19|
+            Self::YMM20| Self::YMM21| Self::YMM22| Self::YMM23|
+            Self::YMM24| Self::YMM25| Self::YMM26| Self::YMM27|
+            Self::YMM28| Self::YMM29| Self::YMM30| Self::YMM31|
+            Self::ZMM16| Self::ZMM17| Self::ZMM18| Self::ZMM19|
+            Self::ZMM20| Self::ZMM21| Self::ZMM22| Self::ZMM23|
+            Self::ZMM24| Self::ZMM25| Self::ZMM26| Self::ZMM27|
+            Self::ZMM28| Self::ZMM29| Self::ZMM30| Self::ZMM31
         )
     }
     #[rustfmt::skip]
     pub fn to_byte(&self) -> u8 {
         match &self {
-            Self::ES   | Self::MM0 |
+            Self::ES   | Self::MM0 | Self::K0   | Self::ST0   |
             Self::R8   | Self::R8B | Self::R8W  | Self::R8D   |
             Self::XMM8 | Self::YMM8| Self::AL   | Self::AX    |
             Self::EAX  | Self::CR0 | Self::CR8  | Self::DR0   |
+            Self::XMM16| Self::XMM24| Self::YMM16| Self::YMM24|
+            Self::ZMM0 | Self::ZMM8 | Self::ZMM16| Self::ZMM24|
             Self::DR8  | Self::RAX | Self::XMM0 | Self::YMM0   => 0b000,
 
-            Self::CS   | Self::MM1 |
+            Self::CS   | Self::MM1 | Self::K1   | Self::ST1   |
             Self::R9   | Self::R9B | Self::R9W  | Self::R9D   |
             Self::CL   | Self::CX  | Self::ECX  | Self::RCX   |
             Self::XMM1 | Self::YMM1| Self::XMM9 | Self::CR1   |
+            Self::XMM17| Self::XMM25| Self::YMM17| Self::YMM25|
+            Self::ZMM1 | Self::ZMM9 | Self::ZMM17| Self::ZMM25|
             Self::YMM9 | Self::CR9 | Self::DR1  | Self::DR9    => 0b001,
 
-            Self::SS   | Self::MM2 |
+            Self::SS   | Self::MM2 | Self::K2   | Self::ST2   |
             Self::R10  | Self::R10B| Self::R10W | Self::R10D  |
             Self::DL   | Self::DX  | Self::EDX  | Self::XMM2  |
             Self::RDX  | Self::CR2 | Self::CR10 | Self::DR2   |
+            Self::XMM18| Self::XMM26| Self::YMM18| Self::YMM26|
+            Self::ZMM2 | Self::ZMM10| Self::ZMM18| Self::ZMM26|
             Self::DR10 | Self::YMM2| Self::XMM10| Self::YMM10  => 0b010,
 
-            Self::DS   | Self::MM3 |
+            Self::DS   | Self::MM3 | Self::K3   | Self::ST3  |
             Self::R11  | Self::R11B| Self::R11W | Self::R11D |
             Self::BL   | Self::BX  | Self::EBX  | Self::XMM3 |
             Self::RBX  | Self::CR3 | Self::CR11 | Self::DR3  |
+            Self::XMM19| Self::XMM27| Self::YMM19| Self::YMM27|
+            Self::ZMM3 | Self::ZMM11| Self::ZMM19| Self::ZMM27|
             Self::DR11 | Self::YMM3| Self::XMM11| Self::YMM11 => 0b011,
 
-            Self::FS  | Self::MM4  |
+            Self::FS  | Self::MM4  | Self::K4   | Self::ST4  |
             Self::R12 | Self::R12B | Self::R12W | Self::R12D |
             Self::AH  | Self::SP   | Self::ESP  | Self::XMM4 |
             Self::SPL | Self::RSP  | Self::CR4  | Self::CR12 |
             Self::DR4 | Self::DR12 | Self::YMM4 | Self::XMM12|
+            Self::XMM20| Self::XMM28| Self::YMM20| Self::YMM28|
+            Self::ZMM4 | Self::ZMM12| Self::ZMM20| Self::ZMM28|
             Self::YMM12                                       => 0b100,
 
-            Self::GS  | Self::MM5  |
+            Self::GS  | Self::MM5  | Self::K5   | Self::ST5  |
             Self::R13 | Self::R13B | Self::R13W | Self::R13D |
             Self::CH  | Self::BP   | Self::EBP  | Self::XMM5 |
             Self::BPL | Self::RBP  | Self::CR5  | Self::CR13 |
             Self::DR5 | Self::DR13 | Self::YMM5 | Self::XMM13|
+            Self::XMM21| Self::XMM29| Self::YMM21| Self::YMM29|
+            Self::ZMM5 | Self::ZMM13| Self::ZMM21| Self::ZMM29|
             Self::YMM13                                       => 0b101,
 
             Self::R14   | Self::R14B | Self::R14W | Self::R14D |
             Self::DH    | Self::SI   | Self::ESI  | Self::XMM6 |
             Self::SIL   | Self::RSI  | Self::CR6  | Self::CR14 |
             Self::DR6   | Self::DR14 | Self::YMM6 | Self::XMM14|
-            Self::YMM14 | Self::MM6                 => 0b110,
+            Self::XMM22| Self::XMM30| Self::YMM22| Self::YMM30|
+            Self::ZMM6 | Self::ZMM14| Self::ZMM22| Self::ZMM30|
+            Self::YMM14 | Self::MM6  | Self::K6   | Self::ST6  => 0b110,
 
             Self::R15   | Self::R15B | Self::R15W | Self::R15D |
             Self::BH    | Self::DI   | Self::EDI  | Self::XMM7 |
             Self::DIL   | Self::RDI  | Self::CR7  | Self::CR15 |
             Self::DR7   | Self::DR15 | Self::YMM7 | Self::XMM15|
-            Self::YMM15 | Self::MM7                  => 0b111,
+            Self::XMM23| Self::XMM31| Self::YMM23| Self::YMM31|
+            Self::ZMM7 | Self::ZMM15| Self::ZMM23| Self::ZMM31|
+            Self::YMM15 | Self::MM7  | Self::K7   | Self::ST7  => 0b111,
 
-            Self::IP | Self::EIP | Self::RIP => 0b000
+            // ModRM.rm of RIP-relative addressing
+            Self::IP | Self::EIP | Self::RIP => 0b101
         }
     }
     #[rustfmt::skip]
@@ -573,15 +786,38 @@ impl Register {
             Self::XMM0 | Self::XMM1 | Self::XMM2 | Self::XMM3  |
             Self::XMM4 | Self::XMM5 | Self::XMM6 | Self::XMM7  |
             Self::XMM8 | Self::XMM9 | Self::XMM10| Self::XMM11 |
-            Self::XMM12| Self::XMM13| Self::XMM14| Self::XMM15 => Purpose::F128,
+            Self::XMM12| Self::XMM13| Self::XMM14| Self::XMM15 |
+            Self::XMM16| Self::XMM17| Self::XMM18| Self::XMM19 |
+            Self::XMM20| Self::XMM21| Self::XMM22| Self::XMM23 |
+            Self::XMM24| Self::XMM25| Self::XMM26| Self::XMM27 |
+            Self::XMM28| Self::XMM29| Self::XMM30| Self::XMM31 => Purpose::F128,
 
             Self::YMM0 | Self::YMM1 | Self::YMM2 | Self::YMM3  |
             Self::YMM4 | Self::YMM5 | Self::YMM6 | Self::YMM7  |
             Self::YMM8 | Self::YMM9 | Self::YMM10| Self::YMM11 |
-            Self::YMM12| Self::YMM13| Self::YMM14| Self::YMM15 => Purpose::F256,
+            Self::YMM12| Self::YMM13| Self::YMM14| Self::YMM15 |
+            Self::YMM16| Self::YMM17| Self::YMM18| Self::YMM19 |
+            Self::YMM20| Self::YMM21| Self::YMM22| Self::YMM23 |
+            Self::YMM24| Self::YMM25| Self::YMM26| Self::YMM27 |
+            Self::YMM28| Self::YMM29| Self::YMM30| Self::YMM31 => Purpose::F256,
+
+            Self::ZMM0 | Self::ZMM1 | Self::ZMM2 | Self::ZMM3  |
+            Self::ZMM4 | Self::ZMM5 | Self::ZMM6 | Self::ZMM7  |
+            Self::ZMM8 | Self::ZMM9 | Self::ZMM10| Self::ZMM11 |
+            Self::ZMM12| Self::ZMM13| Self::ZMM14| Self::ZMM15 |
+            Self::ZMM16| Self::ZMM17| Self::ZMM18| Self::ZMM19 |
+            Self::ZMM20| Self::ZMM21| Self::ZMM22| Self::ZMM23 |
+            Self::ZMM24| Self::ZMM25| Self::ZMM26| Self::ZMM27 |
+            Self::ZMM28| Self::ZMM29| Self::ZMM30| Self::ZMM31 => Purpose::F512,
 
             Self::MM0 | Self::MM1 | Self::MM2 | Self::MM3  |
             Self::MM4 | Self::MM5 | Self::MM6 | Self::MM7  => Purpose::Mmx,
+
+            Self::K0  | Self::K1  | Self::K2  | Self::K3   |
+            Self::K4  | Self::K5  | Self::K6  | Self::K7   => Purpose::Mask,
+
+            Self::ST0 | Self::ST1 | Self::ST2 | Self::ST3  |
+            Self::ST4 | Self::ST5 | Self::ST6 | Self::ST7  => Purpose::Fpu,
         }
     }
 }
@@ -594,10 +830,13 @@ impl ToString for Purpose {
             Self::Mmx => "mmx".to_string(),
             Self::F128 => "sse (xmm)".to_string(),
             Self::F256 => "avx (ymm)".to_string(),
+            Self::F512 => "avx-512 (zmm)".to_string(),
             Self::Sgmnt => "segment".to_string(),
             Self::IPtr => "instruction pointer".to_string(),
             Self::Dbg => "debug".to_string(),
             Self::Ctrl => "control".to_string(),
+            Self::Mask => "opmask (k)".to_string(),
+            Self::Fpu => "x87 fpu (st)".to_string(),
         }
     }
 }
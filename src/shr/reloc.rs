@@ -8,29 +8,45 @@ use crate::shr::{error::RASMError, symbol::Symbol};
 impl RelType {
     pub fn to_elf64_rtype(&self) -> u64 {
         match self {
+            Self::ABS64 => 1,
             Self::ABS32 => 11,
+            Self::ABS16 => 12,
             Self::REL32 => 2,
+            Self::REL8 => 15,
         }
     }
     pub fn to_elf32_rtype(&self) -> u32 {
         match self {
+            // there is no 64-bit relocation in 32-bit ELF (rejected in obj/elf.rs)
+            Self::ABS64 => unreachable!(),
             Self::ABS32 => 1,
+            Self::ABS16 => 20,
             Self::REL32 => 2,
+            Self::REL8 => 23,
         }
     }
 }
 
 #[derive(PartialEq, Clone, Debug, Copy)]
 pub enum RelType {
+    // used by movabs (imm64/moffs64)
+    ABS64,
     ABS32,
+    // used by 16-bit far pointers (ptr16:16)
+    ABS16,
     REL32,
+    // used by short (rel8) jumps
+    REL8,
 }
 
 impl RelType {
     pub const fn size(&self) -> usize {
         match self {
+            Self::ABS64 => 8,
             Self::ABS32 => 4,
+            Self::ABS16 => 2,
             Self::REL32 => 4,
+            Self::REL8 => 1,
         }
     }
 }
@@ -46,13 +62,13 @@ pub struct Relocation<'a> {
 
 impl Relocation<'_> {
     pub fn lea(&self, addr: u32) -> u32 {
-        // this might not work very well with larger numbers, so
-        // later i might need to cast as i64/u64.
-        if self.reltype == RelType::REL32 {
+        if let RelType::REL32 | RelType::REL8 = self.reltype {
             // S + A - P
-            (self.offset as i32 + self.addend) as u32 - addr
+            addr.wrapping_add_signed(self.addend)
+                .wrapping_sub(self.offset)
         } else {
-            (self.offset as i32 + self.addend) as u32
+            // S + A
+            addr.wrapping_add_signed(self.addend)
         }
     }
 }
@@ -80,7 +96,11 @@ pub fn relocate<'a>(
             "Tried to do relocation with non-existent symbol",
         ));
     };
-    let addr = rel.lea(symbol.offset).to_le_bytes();
+    let addr = match rel.reltype {
+        // S + A is sign-extended to 64 bits
+        RelType::ABS64 => (symbol.offset as i64 + rel.addend as i64).to_le_bytes(),
+        _ => (rel.lea(symbol.offset) as u64).to_le_bytes(),
+    };
     let buf_offset = rel.offset as usize;
 
     if buf.len() < buf_offset {
@@ -100,6 +120,7 @@ pub fn relocate<'a>(
 mod tests {
     use super::*;
     #[test]
+    #[allow(clippy::cloned_ref_to_slice_refs)]
     fn rel_test() {
         use crate::shr::symbol::{SymbolType, Visibility};
         // we assert here that Symbol is defined as second (idx 1)
@@ -122,9 +143,9 @@ mod tests {
             reltype: RelType::REL32,
             shidx: 0,
         };
-        assert_eq!(relocation.lea(0x01), 1);
+        assert_eq!(relocation.lea(0x01), -1i32 as u32);
         assert_eq!(relocate(&mut bytes, relocation, &[symbol.clone()]), Ok(()));
-        assert_eq!(bytes, [0x00, 0x71, 0x01, 0x00, 0x00, 0x00, 0x81, 0x91]);
+        assert_eq!(bytes, [0x00, 0x71, 0xFF, 0xFF, 0xFF, 0xFF, 0x81, 0x91]);
         let relocation = Relocation {
             symbol: &"Symbol".to_string(),
             offset: 0x03,
@@ -132,7 +153,33 @@ mod tests {
             reltype: RelType::REL32,
             shidx: 0,
         };
+        assert_eq!(relocate(&mut bytes, relocation, &[symbol.clone()]), Ok(()));
+        assert_eq!(bytes, [0x00, 0x71, 0xFF, 0xFD, 0xFF, 0xFF, 0xFF, 0x91]);
+        let relocation = Relocation {
+            symbol: &"Symbol".to_string(),
+            offset: 0x00,
+            addend: 4,
+            reltype: RelType::ABS64,
+            shidx: 0,
+        };
+        assert_eq!(relocate(&mut bytes, relocation, &[symbol.clone()]), Ok(()));
+        assert_eq!(bytes, [0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        let relocation = Relocation {
+            symbol: &"Symbol".to_string(),
+            offset: 0x00,
+            addend: -8,
+            reltype: RelType::ABS64,
+            shidx: 0,
+        };
         assert_eq!(relocate(&mut bytes, relocation, &[symbol]), Ok(()));
-        assert_eq!(bytes, [0x00, 0x71, 0x01, 0x01, 0x00, 0x00, 0x00, 0x91]);
+        assert_eq!(bytes, [0xF9, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]);
+        let relocation = Relocation {
+            symbol: &"Symbol".to_string(),
+            offset: 0x03,
+            addend: 2,
+            reltype: RelType::ABS32,
+            shidx: 0,
+        };
+        assert_eq!(relocation.lea(0x01), 3);
     }
 }
@@ -0,0 +1,59 @@
+// rasmx86_64 - src/shr/rounding.rs
+// --------------------------------
+// made by matissoss
+// licensed under MPL 2.0
+
+use std::str::FromStr;
+
+// AVX-512 static rounding/suppress-all-exceptions decoration (like: `{rn-sae}`)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Rounding {
+    RnSae, // round to nearest (even)
+    RdSae, // round down (toward -inf)
+    RuSae, // round up (toward +inf)
+    RzSae, // round toward zero
+    Sae,   // only suppress all exceptions
+}
+
+impl Rounding {
+    // EVEX.L'L (when EVEX.b is set and instruction is reg-only)
+    pub const fn rc(&self) -> u8 {
+        match self {
+            Self::RnSae | Self::Sae => 0b00,
+            Self::RdSae => 0b01,
+            Self::RuSae => 0b10,
+            Self::RzSae => 0b11,
+        }
+    }
+    pub const fn is_sae(&self) -> bool {
+        matches!(self, Self::Sae)
+    }
+}
+
+impl FromStr for Rounding {
+    type Err = ();
+    fn from_str(str: &str) -> Result<Self, <Self as FromStr>::Err> {
+        match str {
+            "rn-sae" => Ok(Self::RnSae),
+            "rd-sae" => Ok(Self::RdSae),
+            "ru-sae" => Ok(Self::RuSae),
+            "rz-sae" => Ok(Self::RzSae),
+            "sae" => Ok(Self::Sae),
+            _ => Err(()),
+        }
+    }
+}
+
+#[allow(clippy::to_string_trait_impl)]
+impl ToString for Rounding {
+    fn to_string(&self) -> String {
+        match self {
+            Self::RnSae => "{rn-sae}",
+            Self::RdSae => "{rd-sae}",
+            Self::RuSae => "{ru-sae}",
+            Self::RzSae => "{rz-sae}",
+            Self::Sae => "{sae}",
+        }
+        .to_string()
+    }
+}
@@ -8,6 +8,7 @@ use crate::shr::{
     mem::Mem,
     reg::Register,
     size::Size,
+    symbol::SymbolRef,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -21,3 +22,18 @@ impl ToAType for Segment {
         AType::Memory(self.address.size().unwrap_or(Size::Unknown))
     }
 }
+
+// far pointer (`ptr16:16`/`ptr16:32`) used by far jmp and call (like `$0x08:@pm_entry`);
+// if symbol is used, offset is ignored (symbol's addend is used instead)
+#[derive(Debug, Clone, PartialEq)]
+pub struct FarPtr {
+    pub selector: u16,
+    pub offset: u32,
+    pub symbol: Option<SymbolRef>,
+}
+
+impl ToAType for FarPtr {
+    fn atype(&self) -> AType {
+        AType::FarPtr
+    }
+}
@@ -14,8 +14,10 @@ pub enum Size {
     Word,
     Dword,
     Qword,
+    Tword, // st0-7 (x87)
     Xword, // xmm0-15
     Yword, // ymm0-15
+    Zword, // zmm0-31
     #[default]
     Unknown,
     Any,
@@ -29,8 +31,10 @@ impl From<Size> for u8 {
             Size::Word => 2,
             Size::Dword => 4,
             Size::Qword => 8,
+            Size::Tword => 10,
             Size::Xword => 16,
             Size::Yword => 32,
+            Size::Zword => 64,
         }
     }
 }
@@ -43,8 +47,10 @@ impl TryFrom<u16> for Size {
             2 => Ok(Self::Word),
             4 => Ok(Self::Dword),
             8 => Ok(Self::Qword),
+            10 => Ok(Self::Tword),
             16 => Ok(Self::Xword),
             32 => Ok(Self::Yword),
+            64 => Ok(Self::Zword),
             _ => Err(()),
         }
     }
@@ -57,8 +63,10 @@ impl Display for Size {
             Self::Word => write!(form, "word"),
             Self::Dword => write!(form, "dword"),
             Self::Qword => write!(form, "qword"),
+            Self::Tword => write!(form, "tword"),
             Self::Xword => write!(form, "xword"),
             Self::Yword => write!(form, "yword"),
+            Self::Zword => write!(form, "zword"),
             Self::Any => write!(form, "{{any}}"),
             Self::Unknown => write!(form, "{{unknown}}"),
         }
@@ -98,8 +106,10 @@ impl TryFrom<Keyword> for Size {
             Keyword::Word => Ok(Self::Word),
             Keyword::Dword => Ok(Self::Dword),
             Keyword::Qword => Ok(Self::Qword),
+            Keyword::Tword => Ok(Self::Tword),
             Keyword::Xword => Ok(Self::Xword),
             Keyword::Yword => Ok(Self::Yword),
+            Keyword::Zword => Ok(Self::Zword),
             Keyword::Any => Ok(Self::Any),
             _ => Err(()),
         }
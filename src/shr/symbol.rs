@@ -66,6 +66,8 @@ fn new_tok(str: String) -> Token {
             Token::RelType(RelType::ABS32)
         } else if str == "rel" {
             Token::RelType(RelType::REL32)
+        } else if str == "rel8" {
+            Token::RelType(RelType::REL8)
         } else {
             Token::String(str)
         }
@@ -130,10 +132,12 @@ impl ToString for SymbolRef {
     fn to_string(&self) -> String {
         let mut string = self.symbol.clone();
         string.push(':');
-        string.push_str(if self.reltype == RelType::REL32 {
-            "rel"
-        } else {
-            "abs"
+        string.push_str(match self.reltype {
+            RelType::REL32 => "rel",
+            RelType::REL8 => "rel8",
+            RelType::ABS32 => "abs",
+            RelType::ABS16 => "abs16",
+            RelType::ABS64 => "abs64",
         });
         if self.addend != 0 {
             string.push(':');
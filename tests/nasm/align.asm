@@ -0,0 +1,25 @@
+section .text
+	bits 64
+	global _start
+_start:
+	nop
+	; multi-byte nop padding (9 + 6 bytes)
+	db 0x66, 0x0F, 0x1F, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00
+	db 0x66, 0x0F, 0x1F, 0x44, 0x00, 0x00
+_a:
+	ret
+	db 0x66, 0x0F, 0x1F, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00
+	db 0x66, 0x0F, 0x1F, 0x44, 0x00, 0x00
+_b:
+	mov eax, ebx
+	add eax, ecx
+	xor eax, eax
+	; custom fill byte
+	db 0xCC, 0xCC
+_c:
+	ret
+	db 0x66, 0x0F, 0x1F, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00
+	db 0x66, 0x0F, 0x1F, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00
+	db 0x0F, 0x1F, 0x44, 0x00, 0x00
+_d:
+	ret
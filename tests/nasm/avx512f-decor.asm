@@ -0,0 +1,36 @@
+section .text
+	bits 64
+	global _start
+_start:
+	vaddps zmm0, zmm1, [rax]{1to16}
+	vaddps zmm0, zmm1, dword [rax]{1to16}
+	vaddps xmm0{k1}, xmm1, [rax]{1to4}
+	vaddpd ymm0, ymm1, qword [rax]{1to4}
+	vsubpd zmm0{k2}{z}, zmm1, [rax + rcx * 8]{1to8}
+	vmulps zmm3, zmm4, [r8]{1to16}
+	vdivpd zmm3, zmm4, [r8]{1to8}
+	vminps zmm1, zmm2, [rax]{1to16}
+	vmaxpd zmm1, zmm2, [rax]{1to8}
+	vsqrtpd zmm0{k1}, [rax]{1to8}
+	vpaddd zmm1, zmm2, [rax]{1to16}
+	vpaddq zmm1, zmm2, [rax]{1to8}
+	vpandd ymm1, ymm2, [rax]{1to8}
+	vpxorq xmm1, xmm2, [rax]{1to2}
+	vpermt2ps zmm1, zmm2, [rax]{1to16}
+	vpternlogd zmm1, zmm2, [rax]{1to16}, 0xAA
+	valignq zmm1, zmm2, [rax]{1to8}, 2
+	vpabsq zmm1, [rax]{1to8}
+	vpminuq zmm1, zmm2, [rax]{1to8}
+
+	vaddps zmm0, zmm1, zmm2, {rn-sae}
+	vaddps zmm0, zmm1, zmm2, {rd-sae}
+	vaddps zmm0, zmm1, zmm2, {ru-sae}
+	vaddps zmm0, zmm1, zmm2, {rz-sae}
+	vaddpd zmm16{k1}{z}, zmm17, zmm31, {rn-sae}
+	vsubps zmm0, zmm1, zmm2, {rz-sae}
+	vmulpd zmm0, zmm1, zmm2, {ru-sae}
+	vdivps zmm0, zmm1, zmm2, {rd-sae}
+	vsqrtps zmm0, zmm1, {rn-sae}
+	vsqrtpd zmm0, zmm1, {rz-sae}
+	vminps zmm0, zmm1, zmm2, {sae}
+	vmaxpd zmm0, zmm1, zmm2, {sae}
@@ -0,0 +1,29 @@
+section .text
+	bits 64
+	global _start
+_start:
+	vaddps zmm0, zmm1, zword [rax + 64]
+	vaddps zmm0, zmm1, zword [rax + 128]
+	vaddps zmm0, zmm1, zword [rax - 8192]
+	vaddps zmm0, zmm1, zword [rax + 8128]
+	vaddps zmm0, zmm1, zword [rax + 8192]
+	vaddps zmm0, zmm1, zword [rax + 32]
+	vaddps zmm0, zmm1, zword [rax + 1]
+	vaddps ymm16, ymm1, yword [rax + 32]
+	vaddps ymm16, ymm1, yword [rax + 64]
+	vaddps xmm16, xmm1, oword [rax + 16]
+	vaddps xmm16, xmm1, oword [rax + 8]
+	vaddps zmm0, zmm1, [rax + 4]{1to16}
+	vaddps zmm0, zmm1, [rax + 64]{1to16}
+	vaddpd zmm0, zmm1, [rax + 8]{1to8}
+	vaddpd zmm0, zmm1, [rax + 4]{1to8}
+	vaddpd zmm0, zmm1, zword [rax + rcx * 4 + 256]
+	vmovups zword [rax + 64], zmm1
+	vmovups zmm1, zword [rsi + 128]
+	vmovdqu64 zmm1, zword [rsi - 64]
+	vpternlogd zmm1, zmm2, zword [rax + 192], 0x11
+	vpabsq zmm1, [rax + 1016]{1to8}
+
+	vaddps xmm0, xmm1, oword [rax + 8]
+	vaddps ymm0, ymm1, yword [rax + 256]
+	vpaddd xmm0, xmm1, oword [rax + rcx * 2 + 16]
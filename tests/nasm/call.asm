@@ -0,0 +1,20 @@
+section .text
+	bits 64
+	global _start
+_start:
+top:
+	call fwd
+	call back
+	call qword [rax]
+	call rbx
+	jmp near fwd+1
+	nop
+back:
+	call back
+	call fwd
+	ret
+fwd:
+	call back
+	call top
+	nop
+	ret
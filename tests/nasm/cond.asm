@@ -0,0 +1,7 @@
+section .text
+	bits 64
+	global _start
+_start:
+	mov eax, 3
+	xor ecx, ecx
+	inc eax
@@ -0,0 +1,19 @@
+section .text
+	bits 64
+	global _start
+_start:
+	xor eax, eax
+	add eax, 1
+	add eax, 1
+	inc rbx
+	inc rbx
+	mov ecx, 3
+wait1:
+	dec ecx
+	jnz wait1
+	mov ecx, 5
+wait2:
+	dec ecx
+	jnz wait2
+	xor ecx, ecx
+	xor edx, edx
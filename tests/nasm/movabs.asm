@@ -0,0 +1,25 @@
+section .text
+	bits 64
+	global _start
+_start:
+	mov rax, 1
+	mov r8, 1
+	mov rax, -1
+	mov r9, -200
+	mov rcx, 0xFFFFFFFF
+	mov rax, 0x1122334455667788
+	mov sil, 1
+	mov r9w, 0x1234
+	mov r10d, 0x12345678
+
+	mov rax, strict qword 1
+	mov r15, 0x1122334455667788
+	mov rax, strict qword -2
+	mov al, [qword 0x1000]
+	mov ax, [qword 0x1000]
+	mov eax, [qword 0x1000]
+	mov rax, [qword 0x1000]
+	mov [qword 0x1000], al
+	mov [qword 0x1000], ax
+	mov [qword 0x1000], eax
+	mov [qword 0x1000], rax
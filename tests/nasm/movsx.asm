@@ -0,0 +1,28 @@
+section .text
+	bits 64
+	global _start
+_start:
+	movsx ax, bl
+	movsx eax, bl
+	movsx eax, bx
+	movsx rax, bl
+	movsx rax, bx
+	movsx r8, sil
+	movsx ax, byte [rax]
+	movsx eax, byte [rax]
+	movsx eax, word [rax]
+	movsx rax, byte [rax]
+	movsx r9, word [r10 + rcx * 2 + 8]
+
+	movsxd rax, ebx
+	movsxd r8, r9d
+	movsxd rax, dword [rax]
+	movsxd r11, dword [rbx + r12 * 4 + 16]
+
+	tzcnt ax, bx
+	tzcnt eax, ebx
+	tzcnt rax, rbx
+	tzcnt r8, r9
+	tzcnt ax, word [rax]
+	tzcnt eax, dword [rax]
+	tzcnt rax, qword [rax]
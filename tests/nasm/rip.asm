@@ -0,0 +1,21 @@
+section .text
+	bits 64
+	global _start
+_start:
+top:
+	mov rax, qword [rel data]
+	mov dword [rel data], 5
+	mov byte [rel data], cl
+	lea rax, [rel data]
+	lea r12, [rel data+8]
+	add ecx, dword [rel data+4]
+	sub qword [rel data], 0x10
+	cmp byte [rel data], 1
+	cmp rdx, qword [rel top]
+	xor r8, qword [rel data]
+	vmovaps xmm1, oword [rel data]
+	vaddps ymm0, ymm1, yword [rel data]
+	mov rax, qword [rel data]
+	mov word [rel data-2], 0x1234
+data:
+	db 1
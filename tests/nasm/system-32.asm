@@ -0,0 +1,18 @@
+[bits 32]
+_start:
+	lgdt [eax]
+	lidt [ebx + 8]
+	sgdt [ecx]
+	sidt [edx]
+	pusha
+	pushad
+	popa
+	popad
+	jecxz _start
+	jmp 0x08:pm_entry
+	jmp 0x10:0x7C00
+	call 0x08:pm_entry
+	call 0x18:0x12345678
+	retf 4
+pm_entry:
+	retf
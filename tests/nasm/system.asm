@@ -0,0 +1,19 @@
+section .text
+	bits 64
+	global _start
+_start:
+	lgdt [rax]
+	lidt [rbx + 8]
+	sgdt [rcx]
+	sidt [r8]
+	swapgs
+	rdfsbase rax
+	rdfsbase r9d
+	rdgsbase rbx
+	rdgsbase eax
+	wrfsbase rcx
+	wrgsbase r10
+	jrcxz _start
+	jecxz _start
+	retf
+	retf 8
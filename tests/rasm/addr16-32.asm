@@ -0,0 +1,6 @@
+#(bits=32)
+_start:
+	mov %ax, .word (%bx + %si + $4)
+	mov %eax, .dword (%bp + %di)
+	mov %eax, .dword (%bp)
+	add .dword (%si + $0x200), %ecx
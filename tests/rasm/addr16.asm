@@ -0,0 +1,19 @@
+.bits $16
+_start:
+	mov %ax, .word (%bx + %si + $4)
+	mov %ax, .word (%bx + %di)
+	mov %ax, .word (%bp + %si - $8)
+	mov %ax, .word (%bp + %di)
+	mov %ax, .word (%si)
+	mov %ax, .word (%di + $0x1234)
+	mov %ax, .word (%bp)
+	mov %ax, .word (%bx)
+	mov %ax, .word (%si + %bx)
+	mov .word (%bp + $0x80), %cx
+	mov .byte (%di), %dl
+	add %ax, .word ($0x7C00)
+	lea %si, (%bp + %di + $0x100)
+	jmp .word (%bx)
+	inc .byte (%bx + %si)
+	mov %eax, .dword (%bx)
+	mov %eax, .dword (%ebx + %ecx * $4)
@@ -0,0 +1,19 @@
+.section ".text"
+.exec
+.bits $64
+_start:
+	nop
+#(align=16)
+_a:
+	ret
+#(align=16)
+_b:
+	mov %eax, %ebx
+	add %eax, %ecx
+	xor %eax, %eax
+#(align=8:0xCC)
+_c:
+	ret
+#(align=32)
+_d:
+	ret
@@ -0,0 +1,50 @@
+.bits $64
+_start:
+	vpermd %ymm0, %ymm1, %ymm2
+	vpermd %ymm8, %ymm9, .yword (%rax)
+	vpermps %ymm0, %ymm1, %ymm2
+	vpermps %ymm3, %ymm4, .yword (%rax + %rcx * $4 + $8)
+	vpermq %ymm0, %ymm1, $0x1B
+	vpermq %ymm10, .yword (%r8), $0xFF
+	vpermpd %ymm0, %ymm1, $0x4E
+	vpermpd %ymm2, .yword (%rax), $1
+
+	vpbroadcastb %xmm0, %xmm1
+	vpbroadcastb %ymm0, %xmm1
+	vpbroadcastb %xmm2, .byte (%rax)
+	vpbroadcastb %ymm12, .byte (%r9)
+	vpbroadcastw %xmm0, %xmm1
+	vpbroadcastw %ymm0, .word (%rax)
+	vpbroadcastd %xmm0, %xmm1
+	vpbroadcastd %ymm3, .dword (%rax)
+	vpbroadcastq %xmm0, %xmm1
+	vpbroadcastq %ymm15, .qword (%rax)
+	vbroadcasti128 %ymm0, .xword (%rax)
+	vbroadcasti128 %ymm9, .xword (%r10 + $16)
+
+	vinserti128 %ymm0, %ymm1, %xmm2, $1
+	vinserti128 %ymm8, %ymm9, .xword (%rax), $0
+	vextracti128 %xmm0, %ymm1, $1
+	vextracti128 .xword (%rax), %ymm9, $0
+
+	vpsllvd %xmm0, %xmm1, %xmm2
+	vpsllvd %ymm0, %ymm1, .yword (%rax)
+	vpsllvq %xmm0, %xmm1, .xword (%rax)
+	vpsllvq %ymm8, %ymm9, %ymm10
+	vpsrlvd %xmm0, %xmm1, %xmm2
+	vpsrlvd %ymm0, %ymm1, %ymm2
+	vpsrlvq %xmm0, %xmm1, %xmm2
+	vpsrlvq %ymm0, %ymm1, .yword (%rax)
+	vpsravd %xmm0, %xmm1, %xmm2
+	vpsravd %ymm11, %ymm12, .yword (%rax)
+
+	vpmaskmovd %xmm0, %xmm1, .xword (%rax)
+	vpmaskmovd %ymm0, %ymm1, .yword (%rax)
+	vpmaskmovd .xword (%rax), %xmm1, %xmm2
+	vpmaskmovd .yword (%r8), %ymm9, %ymm10
+	vpmaskmovq %xmm0, %xmm1, .xword (%rax)
+	vpmaskmovq .yword (%rax), %ymm1, %ymm2
+	vmaskmovps %xmm0, %xmm1, .xword (%rax)
+	vmaskmovps %ymm0, %ymm1, .yword (%rax)
+	vmaskmovps .xword (%rax), %xmm1, %xmm2
+	vmaskmovps .yword (%rax), %ymm1, %ymm2
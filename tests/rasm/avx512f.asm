@@ -0,0 +1,70 @@
+.bits $64
+_start:
+	vaddps %zmm0, %zmm1, %zmm2
+	vaddps %zmm0:k1, %zmm1, %zmm2
+	vaddps %zmm0:k1:z, %zmm1, %zmm2
+	vaddpd %zmm8, %zmm17, %zmm31
+	vaddps %xmm16, %xmm1, %xmm2
+	vaddpd %ymm1, %ymm20, .yword (%rax)
+	vsubps %zmm3, %zmm4, .zword (%rax)
+	vsubpd %zmm3:k2, %zmm4, %zmm5
+	vmulps %zmm10, %zmm11, %zmm12
+	vmulpd %zmm10, %zmm11, .zword (%r8 + %rcx * 4)
+	vdivps %zmm1, %zmm2, %zmm3
+	vdivpd %zmm1, %zmm2, %zmm3
+	vminps %zmm1, %zmm2, %zmm3
+	vminpd %zmm1, %zmm2, %zmm3
+	vmaxps %zmm1, %zmm2, %zmm3
+	vmaxpd %zmm1, %zmm2, %zmm3
+	vsqrtps %zmm1, %zmm2
+	vsqrtpd %zmm1:k3:z, .zword (%rax)
+	vpaddd %zmm1, %zmm2, %zmm3
+	vpaddq %zmm1, %zmm2, %zmm30
+	vpsubd %zmm1, %zmm2, %zmm3
+	vpsubq %zmm24, %zmm2, %zmm3
+	vpmulld %zmm1, %zmm2, %zmm3
+	vmovups %zmm1, .zword (%rax)
+	vmovups .zword (%rax), %zmm1
+	vmovupd %zmm1:k1, %zmm2
+	vmovaps .zword (%rax):k1, %zmm21
+	vmovapd %zmm1, %zmm2
+	vmovdqa32 %zmm1, %zmm2
+	vmovdqa64 %zmm1, .zword (%rax)
+	vmovdqu32 %zmm1:k4:z, .zword (%rax)
+	vmovdqu64 .zword (%rax), %zmm17
+	vpandd %zmm1, %zmm2, %zmm3
+	vpandq %zmm1, %zmm2, %zmm3
+	vpandnd %ymm1, %ymm2, %ymm3
+	vpandnq %xmm1, %xmm2, %xmm3
+	vpord %zmm1, %zmm2, %zmm3
+	vporq %zmm1, %zmm2, %zmm3
+	vpxord %zmm1, %zmm2, %zmm3
+	vpxorq %zmm1, %zmm2, %zmm3
+	vpermt2d %zmm1, %zmm2, %zmm3
+	vpermt2q %zmm1, %zmm2, %zmm3
+	vpermt2ps %zmm1, %zmm2, %zmm3
+	vpermt2pd %zmm1, %zmm2, .zword (%rax)
+	vpermi2d %zmm1, %zmm2, %zmm3
+	vpermi2q %zmm1, %zmm2, %zmm3
+	vpermi2ps %zmm1, %zmm2, %zmm3
+	vpermi2pd %zmm1, %zmm2, %zmm3
+	vpternlogd %zmm1, %zmm2, %zmm3, $0xFF
+	vpternlogq %zmm1:k5, %zmm2, %zmm3, $0x96
+	valignd %zmm1, %zmm2, %zmm3, $3
+	valignq %zmm1, %zmm2, .zword (%rax), $1
+	vpmaxsq %zmm1, %zmm2, %zmm3
+	vpminsq %zmm1, %zmm2, %zmm3
+	vpmaxuq %zmm1, %zmm2, %zmm3
+	vpminuq %zmm1, %zmm2, %zmm3
+	vpabsq %zmm1, %zmm2
+	vpabsq %zmm1, .zword (%rax)
+	vpcmpd %k1, %zmm0, %zmm1, $0
+	vpcmpd %k1:k2, %zmm30, .zword (%rax + $64), $4
+	vpcmpud %k3, %ymm1, %ymm17, $1
+	vpcmpq %k4, %xmm1, %xmm2, $2
+	vpcmpuq %k5, %zmm8, .qword (%rbx){1to8}, $6
+	kmovw %k1, %k2
+	kmovw %k1, .word (%rax)
+	kmovw .word (%rax), %k7
+	kmovw %k3, %r9d
+	kmovw %r10d, %k4
@@ -0,0 +1,18 @@
+.bits $64
+_start:
+top:
+	call @fwd
+	call @back
+	call .qword (%rax)
+	call %rbx
+	jmp @(fwd:rel:+1)
+	nop
+back:
+	call @back
+	call @fwd
+	ret
+fwd:
+	call @back
+	call @top
+	nop
+	ret
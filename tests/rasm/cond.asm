@@ -0,0 +1,32 @@
+.bits $64
+.math level $3
+.macro clear r
+.if @level >= $3
+	xor \r, \r
+.else
+	mov \r, $0
+.endif
+.endm
+_start:
+.if @level == $1
+	mov %eax, $1
+.elif @level == $3
+	mov %eax, $3
+.else
+	mov %eax, $0
+.endif
+.ifdef level
+	clear %ecx
+.endif
+.ifndef level
+	nop
+.endif
+.if $(@level * 2) != $6
+	nop
+.else
+	.if $0
+		nop
+	.else
+		inc %eax
+	.endif
+.endif
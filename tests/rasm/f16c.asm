@@ -0,0 +1,10 @@
+.bits $64
+_start:
+	vcvtph2ps %xmm0, %xmm1
+	vcvtph2ps %ymm2, %xmm3
+	vcvtph2ps %xmm8, .qword (%rax)
+	vcvtph2ps %ymm9, .xword (%rax + %rcx * 4 + 16)
+	vcvtps2ph %xmm1, %xmm0, $0
+	vcvtps2ph %xmm3, %ymm2, $4
+	vcvtps2ph .qword (%rax), %xmm8, $3
+	vcvtps2ph .xword (%rax + %rcx * 4 + 16), %ymm9, $7
@@ -0,0 +1,145 @@
+.bits $64
+_start:
+	fld .dword (%rax)
+	fld .qword (%rbx + 8)
+	fld .tword (%rcx + %rdx * 4)
+	fld .qword (%r8)
+	fld %st3
+	fst .dword (%rax)
+	fst .qword (%rax)
+	fst %st1
+	fstp .dword (%rax)
+	fstp .qword (%r9 + 16)
+	fstp .tword (%rax)
+	fstp %st2
+
+	fild .word (%rax)
+	fild .dword (%rax)
+	fild .qword (%rax)
+	fist .word (%rax)
+	fist .dword (%rax)
+	fistp .word (%rax)
+	fistp .dword (%rax)
+	fistp .qword (%rax)
+	fisttp .word (%rax)
+	fisttp .dword (%rax)
+	fisttp .qword (%rax)
+	fbld .tword (%rax)
+	fbstp .tword (%rax)
+
+	fadd .dword (%rax)
+	fadd .qword (%rax)
+	fadd %st0, %st1
+	fadd %st2, %st0
+	fmul .dword (%rax)
+	fmul %st0, %st7
+	fmul %st7, %st0
+	fsub .qword (%rax)
+	fsub %st0, %st3
+	fsub %st3, %st0
+	fsubr .dword (%rax)
+	fsubr %st0, %st3
+	fsubr %st3, %st0
+	fdiv .qword (%rax)
+	fdiv %st0, %st4
+	fdiv %st4, %st0
+	fdivr .dword (%rax)
+	fdivr %st0, %st4
+	fdivr %st4, %st0
+
+	faddp
+	faddp %st1, %st0
+	fmulp %st2, %st0
+	fsubp %st3, %st0
+	fsubrp %st3, %st0
+	fdivp %st5, %st0
+	fdivrp %st5, %st0
+
+	fiadd .word (%rax)
+	fiadd .dword (%rax)
+	fimul .dword (%rax)
+	fisub .word (%rax)
+	fisubr .dword (%rax)
+	fidiv .word (%rax)
+	fidivr .dword (%rax)
+	ficom .word (%rax)
+	ficomp .dword (%rax)
+
+	fcom .dword (%rax)
+	fcom .qword (%rax)
+	fcom %st2
+	fcom
+	fcomp .qword (%rax)
+	fcomp %st2
+	fcompp
+	fucom %st1
+	fucomp %st3
+	fucompp
+	fcomi %st0, %st1
+	fcomip %st0, %st2
+	fucomi %st0, %st3
+	fucomip %st0, %st4
+	fxch
+	fxch %st3
+	ffree %st6
+
+	fcmovb %st0, %st1
+	fcmove %st0, %st2
+	fcmovbe %st0, %st3
+	fcmovu %st0, %st4
+	fcmovnb %st0, %st5
+	fcmovne %st0, %st6
+	fcmovnbe %st0, %st7
+	fcmovnu %st0, %st1
+
+	fldcw .word (%rax)
+	fstcw .word (%rax)
+	fnstcw .word (%rax)
+	fstsw .word (%rax)
+	fnstsw .word (%rax)
+	fstsw %ax
+	fnstsw %ax
+	fldenv (%rax)
+	fstenv (%rax)
+	fnstenv (%rax)
+	frstor (%rax)
+	fsave (%rax)
+	fnsave (%rax)
+	fxsave (%rax)
+	fxsave64 (%rax)
+	fxrstor (%r10)
+	fxrstor64 (%rax)
+
+	f2xm1
+	fabs
+	fchs
+	fclex
+	fnclex
+	fcos
+	fdecstp
+	fincstp
+	finit
+	fninit
+	fld1
+	fldl2t
+	fldl2e
+	fldpi
+	fldlg2
+	fldln2
+	fldz
+	fnop
+	fpatan
+	fprem
+	fprem1
+	fptan
+	frndint
+	fscale
+	fsin
+	fsincos
+	fsqrt
+	ftst
+	fxam
+	fxtract
+	fyl2x
+	fyl2xp1
+	fwait
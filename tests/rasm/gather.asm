@@ -0,0 +1,18 @@
+.bits $64
+_start:
+	vpgatherdd %xmm0, (%rax + %xmm1 * $4), %xmm2
+	vpgatherdd %ymm0, (%rax + %ymm1 * $4 + $8), %ymm2
+	vpgatherdq %xmm3, (%rdi + %xmm4 * $8), %xmm5
+	vpgatherdq %ymm3, (%rdi + %xmm4 * $8), %ymm5
+	vpgatherqd %xmm6, (%rsi + %xmm7 * $4), %xmm8
+	vpgatherqd %xmm6, (%rsi + %ymm7 * $4), %xmm8
+	vpgatherqq %xmm9, (%r8 + %xmm10 * $8), %xmm11
+	vpgatherqq %ymm9, (%r8 + %ymm10 * $8 + $0x100), %ymm11
+	vgatherdps %xmm12, (%r13 + %xmm14), %xmm15
+	vgatherdps %ymm12, (%rbp + %ymm14 * $2), %ymm15
+	vgatherdpd %xmm0, (%rsp + %xmm1 * $8), %xmm2
+	vgatherdpd %ymm0, (%r12 + %xmm1 * $8 - $16), %ymm2
+	vgatherqps %xmm1, (%rax + %xmm2 * $4), %xmm3
+	vgatherqps %xmm1, (%rax + %ymm2 * $4), %xmm3
+	vgatherqpd %xmm4, (%rcx + %xmm5 * $8), %xmm6
+	vgatherqpd %ymm4, (%ymm5 * $8 + $64), %ymm6
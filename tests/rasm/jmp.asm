@@ -0,0 +1,49 @@
+.bits $64
+_start:
+jcc:
+	ja @back
+	jae @back
+	jb @back
+	jbe @back
+	jc @back
+	je @back
+	jg @back
+	jge @back
+	jl @back
+	jle @back
+	jna @back
+	jnae @back
+	jnb @back
+	jnbe @back
+	jnc @back
+	jne @back
+	jng @back
+	jnge @back
+	jnl @back
+	jnle @back
+	jno @back
+	jnp @back
+	jns @back
+	jnz @back
+	jo @back
+	jp @back
+	jpe @back
+	jpo @back
+	js @back
+	jz @back
+	jmp @fwd
+	je @fwd
+	jmp .near @back
+	jne .near @back
+back:
+	loop @back
+	loope @back
+	loopne @back
+	jmp .short @back
+	jz .short @fwd
+	empty $100
+fwd:
+	jmp @jcc
+	jl @back
+	jmp @fwd
+	ret
@@ -0,0 +1,25 @@
+.bits $64
+.macro zero r
+	xor \r, \r
+.endm
+.macro twice ins, ops:vararg
+	\ins \ops
+	\ins \ops
+.endm
+.macro spin cnt=$3
+	mov %ecx, \cnt
+wait\@:
+	dec %ecx
+	jnz @wait\@
+.endm
+.macro clear a, b
+	zero \a
+	zero \b
+.endm
+_start:
+	zero %eax
+	twice add, %eax, $1
+	twice ops=%rbx, ins=inc
+	spin
+	spin $5
+	clear %ecx, b=%edx
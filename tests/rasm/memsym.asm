@@ -0,0 +1,15 @@
+.bits $64
+_start:
+top:
+	mov %rax, .qword (%rbx + %rcx * $8 + @table)
+	jmp .qword (%rcx * $8 + @table)
+	jmp .qword (%rcx * $8 + @(table:+16))
+	mov .dword (%rax + @table), $7
+	lea %rsi, (%rdx + @table)
+	add %r9, .qword (%r10 + %r11 * $4 + @table + $8)
+	cmp .byte (%rsi + @table - $1), $0
+	mov %rdx, .qword (%rcx * $4 + @top)
+	vmovups %ymm3, .yword (%rax + %rdi * $2 + @table)
+	mov %rax, .qword (%rcx * $8 + $16)
+table:
+	byte $1
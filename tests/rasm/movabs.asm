@@ -0,0 +1,23 @@
+.bits $64
+_start:
+	mov %rax, $1
+	mov %r8, $1
+	mov %rax, $-1
+	mov %r9, $-200
+	mov %rcx, $0xFFFFFFFF
+	mov %rax, $0x1122334455667788
+	mov %sil, $1
+	mov %r9w, $0x1234
+	mov %r10d, $0x12345678
+
+	movabs %rax, $1
+	movabs %r15, $0x1122334455667788
+	movabs %rax, $-2
+	movabs %al, .byte ($0x1000)
+	movabs %ax, .word ($0x1000)
+	movabs %eax, .dword ($0x1000)
+	movabs %rax, .qword ($0x1000)
+	movabs .byte ($0x1000), %al
+	movabs .word ($0x1000), %ax
+	movabs .dword ($0x1000), %eax
+	movabs .qword ($0x1000), %rax
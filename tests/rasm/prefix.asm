@@ -0,0 +1,43 @@
+.bits $64
+_start:
+	lock add .dword (%rax), %ecx
+	lock add .qword (%rax + 8), $1
+	lock adc .byte (%rbx), %al
+	lock and .dword (%rax), $0xFF
+	lock or .qword (%r8), %rax
+	lock xor .dword (%rax), %edx
+	lock sub .qword (%rax), %rcx
+	lock sbb .dword (%rax), %ecx
+	lock inc .dword (%rax)
+	lock dec .qword (%rax)
+	lock not .byte (%rax)
+	lock neg .qword (%rax)
+	lock btc .dword (%rax), $3
+	lock btr .qword (%rax), %rcx
+	lock bts .dword (%rax), %ecx
+	lock xadd .qword (%rax), %rcx
+	lock xchg .dword (%rax), %ecx
+	lock cmpxchg .qword (%rax), %rcx
+	lock cmpxchg8b .qword (%rax)
+	lock cmpxchg16b .xword (%r8)
+
+	rep movstrb
+	rep movstrw
+	rep movstrd
+	rep movstrq
+	rep stosb
+	rep stosw
+	rep stosd
+	rep stosq
+	rep lodsb
+	rep lodsq
+	rep insb
+	rep insd
+	rep outsb
+	rep outsw
+	repe cmpstrb
+	repz cmpstrq
+	repne scasb
+	repnz scasd
+	repe scasq
+	repne cmpstrw
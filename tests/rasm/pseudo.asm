@@ -0,0 +1,27 @@
+.bits $64
+_start:
+	{vex} addps %xmm0, %xmm1
+	{vex} movaps %xmm0, %xmm1
+	{vex} shufps %xmm2, %xmm3, $1
+	{vex} sqrtps %xmm2, %xmm3
+	{vex3} vaddps %xmm0, %xmm1, %xmm2
+	{vex3} addps %xmm0, (%rax)
+
+	{rex} add %eax, %ebx
+	{rex} mov %al, $1
+	{rex} addps %xmm0, %xmm1
+
+	{disp32} mov %eax, (%rax)
+	{disp32} mov %eax, (%rax+$8)
+	{disp8} mov %eax, (%rax)
+	{disp8} mov %eax, (%rax+%rcx*4)
+	{disp32} vaddps %zmm0, %zmm1, (%rax+$64)
+	{rex} {disp32} add %eax, (%rbx)
+
+	{load} mov %eax, %ebx
+	{store} mov %eax, %ebx
+	{load} add %al, %bl
+	{load} cmp %rax, %r9
+	{store} movaps %xmm0, %xmm9
+	{store} vmovdqa %ymm0, %ymm1
+	{load} vmovdqa %ymm0, %ymm1
@@ -0,0 +1,19 @@
+.bits $64
+_start:
+top:
+	mov %rax, .qword (%rip + @data)
+	mov .dword (%rip + @data), $5
+	mov .byte (%rip + @data), %cl
+	lea %rax, (%rip + @data)
+	lea %r12, (%rip + @(data:+8))
+	add %ecx, .dword (%rip + @data + $4)
+	sub .qword (%rip + @data), $0x10
+	cmp .byte (%rip + @data), $1
+	cmp %rdx, .qword (%rip + @top)
+	xor %r8, .qword (%rip + @data)
+	vmovaps %xmm1, .xword (%rip + @data)
+	vaddps %ymm0, %ymm1, .yword (%rip + @data)
+	mov %rax, .qword (@data)
+	mov .word (%rip + @data - $2), $0x1234
+data:
+	byte $1
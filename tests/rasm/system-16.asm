@@ -0,0 +1,8 @@
+.bits $16
+_start:
+	jmp .far $0x08:@pm_entry
+	jmp .far $0x10:$0x7C00
+	call .far $0x08:@pm_entry
+	call .far $0x18:$0x12345678
+pm_entry:
+	retf
@@ -0,0 +1,17 @@
+.bits $64
+_start:
+	lgdt (%rax)
+	lidt .tword (%rbx + 8)
+	sgdt (%rcx)
+	sidt .tword (%r8)
+	swapgs
+	rdfsbase %rax
+	rdfsbase %r9d
+	rdgsbase %rbx
+	rdgsbase %eax
+	wrfsbase %rcx
+	wrgsbase %r10
+	jrcxz @_start
+	jecxz @_start
+	retf
+	retf $8